@@ -0,0 +1,8 @@
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'create:on-call', 'Create On Call', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'update:on-call', 'Update On Call', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'delete:on-call', 'Delete On Call', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'list:on-call', 'List On Call', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+
+WITH superadmin_role AS (SELECT "id" FROM "role" WHERE "name" = 'superadmin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), superadmin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid  FROM superadmin_role, "permission" WHERE "permission"."name" IN ('create:on-call', 'update:on-call', 'delete:on-call', 'list:on-call');
+WITH admin_role AS (SELECT "id" FROM "role" WHERE "name" = 'admin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by")SELECT uuid_generate_v4(), admin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(),'00000000-0000-0000-0000-000000000000'::uuid FROM admin_role, "permission" WHERE "permission"."name" IN ('create:on-call', 'update:on-call', 'delete:on-call', 'list:on-call');
+WITH user_role AS (SELECT "id" FROM "role" WHERE "name" = 'user' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), user_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid FROM user_role, "permission" WHERE "permission"."name" IN ('list:on-call');
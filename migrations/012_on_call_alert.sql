@@ -0,0 +1,3 @@
+ALTER TABLE on_call ADD COLUMN IF NOT EXISTS start_alert_status alert_status DEFAULT 'pending'::alert_status NOT NULL;
+ALTER TABLE on_call ADD COLUMN IF NOT EXISTS end_alert_status alert_status DEFAULT 'pending'::alert_status NOT NULL;
+CREATE INDEX IF NOT EXISTS on_call_department_time_idx ON on_call (department_id, start_time, end_time) WHERE is_deleted = false;
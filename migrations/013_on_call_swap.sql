@@ -0,0 +1,38 @@
+CREATE TYPE on_call_swap_status AS ENUM (
+  'requested',
+  'accepted',
+  'approved',
+  'rejected',
+  'cancelled'
+);
+
+CREATE TABLE IF NOT EXISTS on_call_swap_request(
+    id uuid PRIMARY KEY,
+    on_call_id uuid NOT NULL,
+    target_on_call_id uuid NOT NULL,
+    department_id uuid NOT NULL,
+    requested_by uuid NOT NULL,
+    target_user_id uuid NOT NULL,
+    status on_call_swap_status NOT NULL,
+    reason TEXT,
+    accepted_on TIMESTAMPTZ,
+    approved_by uuid,
+    approved_on TIMESTAMPTZ,
+    rejected_by uuid,
+    rejected_on TIMESTAMPTZ,
+    updated_by uuid,
+    updated_on TIMESTAMPTZ,
+    created_by uuid NOT NULL,
+    created_on TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE on_call_swap_request ADD CONSTRAINT fk_on_call_id FOREIGN KEY (on_call_id) REFERENCES on_call(id) ON DELETE CASCADE;
+ALTER TABLE on_call_swap_request ADD CONSTRAINT fk_target_on_call_id FOREIGN KEY (target_on_call_id) REFERENCES on_call(id) ON DELETE CASCADE;
+CREATE INDEX IF NOT EXISTS on_call_swap_department_idx ON on_call_swap_request (department_id, status);
+
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'request:on-call-swap', 'Request On Call Swap', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'approve:on-call-swap', 'Approve On Call Swap', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, false, true, false);
+
+WITH superadmin_role AS (SELECT "id" FROM "role" WHERE "name" = 'superadmin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), superadmin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid  FROM superadmin_role, "permission" WHERE "permission"."name" IN ('request:on-call-swap', 'approve:on-call-swap');
+WITH admin_role AS (SELECT "id" FROM "role" WHERE "name" = 'admin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by")SELECT uuid_generate_v4(), admin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(),'00000000-0000-0000-0000-000000000000'::uuid FROM admin_role, "permission" WHERE "permission"."name" IN ('request:on-call-swap', 'approve:on-call-swap');
+WITH user_role AS (SELECT "id" FROM "role" WHERE "name" = 'user' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), user_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid FROM user_role, "permission" WHERE "permission"."name" IN ('request:on-call-swap');
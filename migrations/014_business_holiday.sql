@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS business_holiday(
+    id uuid PRIMARY KEY,
+    business_id uuid NOT NULL,
+    label TEXT NOT NULL,
+    date DATE NOT NULL,
+    created_on TIMESTAMPTZ NOT NULL,
+    created_by uuid NOT NULL,
+    updated_on TIMESTAMPTZ,
+    updated_by uuid
+);
+
+ALTER TABLE business_holiday ADD CONSTRAINT business_holiday_date_uq UNIQUE(business_id, date);
+ALTER TABLE business_holiday ADD CONSTRAINT fk_business_id FOREIGN KEY ("business_id") REFERENCES business_account ("id") ON DELETE CASCADE;
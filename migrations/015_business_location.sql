@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS business_location (
+  id uuid PRIMARY KEY,
+  business_id uuid NOT NULL,
+  name TEXT NOT NULL,
+  time_zone TEXT NOT NULL,
+  work_week jsonb NOT NULL,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE business_location ADD CONSTRAINT fk_business_location_business_id FOREIGN KEY ("business_id") REFERENCES business_account ("id") ON DELETE CASCADE;
+ALTER TABLE business_location ADD CONSTRAINT business_location_name UNIQUE (business_id, name);
+
+ALTER TABLE business_user_relationship ADD COLUMN IF NOT EXISTS location_id uuid;
+ALTER TABLE business_user_relationship ADD CONSTRAINT fk_business_user_location_id FOREIGN KEY ("location_id") REFERENCES business_location ("id") ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_business_user_location_id ON business_user_relationship (location_id);
@@ -0,0 +1,38 @@
+ALTER TYPE leave_status ADD VALUE IF NOT EXISTS 'in_progress';
+
+CREATE TYPE leave_approval_status AS ENUM (
+  'pending',
+  'approved',
+  'rejected'
+);
+
+CREATE TABLE IF NOT EXISTS leave_approval_stage (
+  id uuid PRIMARY KEY,
+  business_id uuid NOT NULL,
+  leave_type_id uuid,
+  stage_order INT NOT NULL,
+  label TEXT NOT NULL,
+  approver_role_id uuid NOT NULL,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL
+);
+
+ALTER TABLE leave_approval_stage ADD CONSTRAINT fk_leave_approval_stage_business_id FOREIGN KEY ("business_id") REFERENCES business_account ("id") ON DELETE CASCADE;
+ALTER TABLE leave_approval_stage ADD CONSTRAINT fk_leave_approval_stage_leave_type_id FOREIGN KEY ("leave_type_id") REFERENCES leave_type ("id") ON DELETE CASCADE;
+ALTER TABLE leave_approval_stage ADD CONSTRAINT fk_leave_approval_stage_role_id FOREIGN KEY ("approver_role_id") REFERENCES role ("id") ON DELETE CASCADE;
+CREATE INDEX IF NOT EXISTS idx_leave_approval_stage_business_type ON leave_approval_stage (business_id, leave_type_id);
+
+CREATE TABLE IF NOT EXISTS leave_request_approval (
+  id uuid PRIMARY KEY,
+  leave_id uuid NOT NULL,
+  stage_order INT NOT NULL,
+  label TEXT NOT NULL,
+  approver_role_id uuid NOT NULL,
+  status leave_approval_status DEFAULT 'pending'::leave_approval_status NOT NULL,
+  acted_by uuid,
+  acted_on TIMESTAMPTZ,
+  created_on TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE leave_request_approval ADD CONSTRAINT fk_leave_request_approval_leave_id FOREIGN KEY ("leave_id") REFERENCES leave_request ("id") ON DELETE CASCADE;
+ALTER TABLE leave_request_approval ADD CONSTRAINT leave_request_approval_stage_uq UNIQUE (leave_id, stage_order);
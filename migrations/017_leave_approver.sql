@@ -0,0 +1,12 @@
+ALTER TABLE department_account ADD COLUMN IF NOT EXISTS head_id uuid;
+ALTER TABLE department_account ADD CONSTRAINT fk_department_head_id FOREIGN KEY ("head_id") REFERENCES user_account ("id") ON DELETE SET NULL;
+
+ALTER TABLE business_user_relationship ADD COLUMN IF NOT EXISTS reporting_manager_id uuid;
+ALTER TABLE business_user_relationship ADD CONSTRAINT fk_business_user_reporting_manager_id FOREIGN KEY ("reporting_manager_id") REFERENCES user_account ("id") ON DELETE SET NULL;
+
+INSERT INTO setting(id, label, key, value_type,  is_deleted, created_on, created_by, is_editable, is_global, is_user, is_business, is_user_business, description, cluster_id) VALUES(uuid_generate_v4(), 'Leave Fallback Approver', 'leave_fallback_approver', 'string', false, CURRENT_TIMESTAMP,  '00000000-0000-0000-0000-000000000000', true, false, false, true, false,  'User id of the approver for leave requests when the requester has no department head or reporting manager.', null);
+
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'override:leave-approver', 'Override Leave Approver', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, true, false, false);
+
+WITH superadmin_role AS (SELECT "id" FROM "role" WHERE "name" = 'superadmin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), superadmin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid  FROM superadmin_role, "permission" WHERE "permission"."name" IN ('override:leave-approver');
+WITH admin_role AS (SELECT "id" FROM "role" WHERE "name" = 'admin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by")SELECT uuid_generate_v4(), admin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(),'00000000-0000-0000-0000-000000000000'::uuid FROM admin_role, "permission" WHERE "permission"."name" IN ('override:leave-approver');
@@ -0,0 +1,27 @@
+CREATE TYPE leave_ledger_entry_type AS ENUM (
+  'allocation',
+  'usage',
+  'reversal',
+  'adjustment',
+  'carry_forward',
+  'encashment'
+);
+
+CREATE TABLE IF NOT EXISTS leave_ledger (
+  id uuid PRIMARY KEY,
+  user_leave_id uuid NOT NULL,
+  entry_type leave_ledger_entry_type NOT NULL,
+  allocated_change DECIMAL(20, 1) NOT NULL DEFAULT 0.0,
+  used_change DECIMAL(20, 1) NOT NULL DEFAULT 0.0,
+  leave_id uuid,
+  reason TEXT,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL
+);
+
+ALTER TABLE leave_ledger ADD CONSTRAINT fk_leave_ledger_user_leave_id FOREIGN KEY ("user_leave_id") REFERENCES user_leave_relationship ("id") ON DELETE CASCADE;
+ALTER TABLE leave_ledger ADD CONSTRAINT fk_leave_ledger_leave_id FOREIGN KEY ("leave_id") REFERENCES leave_request ("id") ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_leave_ledger_user_leave_id ON leave_ledger (user_leave_id, created_on);
+
+INSERT INTO leave_ledger (id, user_leave_id, entry_type, allocated_change, used_change, reason, created_on, created_by) SELECT uuid_generate_v4(), ulr.id, 'allocation', ulr.allocated_count, 0, 'Opening balance', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid FROM user_leave_relationship ulr WHERE ulr.allocated_count <> 0 AND NOT EXISTS (SELECT 1 FROM leave_ledger ll WHERE ll.user_leave_id = ulr.id);
+INSERT INTO leave_ledger (id, user_leave_id, entry_type, allocated_change, used_change, reason, created_on, created_by) SELECT uuid_generate_v4(), ulr.id, 'usage', 0, ulr.used_count, 'Opening balance', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid FROM user_leave_relationship ulr WHERE ulr.used_count <> 0 AND NOT EXISTS (SELECT 1 FROM leave_ledger ll WHERE ll.user_leave_id = ulr.id AND ll.entry_type = 'usage');
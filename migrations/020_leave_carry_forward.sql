@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS leave_carry_forward_rule (
+  id uuid PRIMARY KEY,
+  leave_type_id uuid NOT NULL,
+  max_carry_forward DECIMAL(20, 1) NOT NULL,
+  expiry_days INT,
+  lapse_remaining BOOLEAN NOT NULL DEFAULT true,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE leave_carry_forward_rule ADD CONSTRAINT fk_leave_carry_forward_rule_leave_type_id FOREIGN KEY ("leave_type_id") REFERENCES leave_type ("id") ON DELETE CASCADE;
+ALTER TABLE leave_carry_forward_rule ADD CONSTRAINT leave_carry_forward_rule_leave_type_uq UNIQUE (leave_type_id);
+
+ALTER TABLE user_leave_relationship ADD COLUMN IF NOT EXISTS carried_count DECIMAL(20, 1) NOT NULL DEFAULT 0.0;
+ALTER TABLE user_leave_relationship ADD COLUMN IF NOT EXISTS carry_forward_expire_on TIMESTAMPTZ;
+ALTER TABLE user_leave_relationship ADD COLUMN IF NOT EXISTS carried_from_id uuid;
+ALTER TABLE user_leave_relationship ADD CONSTRAINT fk_user_leave_carried_from_id FOREIGN KEY ("carried_from_id") REFERENCES user_leave_relationship ("id") ON DELETE SET NULL;
@@ -0,0 +1,35 @@
+CREATE TYPE leave_accrual_frequency AS ENUM (
+  'monthly',
+  'quarterly',
+  'half_yearly',
+  'yearly'
+);
+
+CREATE TABLE IF NOT EXISTS leave_accrual_policy (
+  id uuid PRIMARY KEY,
+  leave_type_id uuid NOT NULL,
+  amount DECIMAL(20, 1) NOT NULL,
+  frequency leave_accrual_frequency NOT NULL,
+  max_balance DECIMAL(20, 1),
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE leave_accrual_policy ADD CONSTRAINT fk_leave_accrual_policy_leave_type_id FOREIGN KEY ("leave_type_id") REFERENCES leave_type ("id") ON DELETE CASCADE;
+ALTER TABLE leave_accrual_policy ADD CONSTRAINT leave_accrual_policy_leave_type_uq UNIQUE (leave_type_id);
+
+CREATE TABLE IF NOT EXISTS leave_accrual (
+  id uuid PRIMARY KEY,
+  user_leave_id uuid NOT NULL,
+  accrual_on TIMESTAMPTZ NOT NULL,
+  amount DECIMAL(20, 1) NOT NULL,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL
+);
+
+ALTER TABLE leave_accrual ADD CONSTRAINT fk_leave_accrual_user_leave_id FOREIGN KEY ("user_leave_id") REFERENCES user_leave_relationship ("id") ON DELETE CASCADE;
+ALTER TABLE leave_accrual ADD CONSTRAINT leave_accrual_user_leave_date_uq UNIQUE (user_leave_id, accrual_on);
+
+ALTER TABLE user_leave_relationship ADD COLUMN IF NOT EXISTS accrued_count DECIMAL(20, 1) NOT NULL DEFAULT 0.0;
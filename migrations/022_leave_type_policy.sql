@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS leave_type_policy (
+  id uuid PRIMARY KEY,
+  leave_type_id uuid NOT NULL,
+  min_notice_days INT,
+  max_consecutive_days INT,
+  max_requests_per_month INT,
+  allow_negative_balance BOOLEAN NOT NULL DEFAULT false,
+  max_negative_balance DECIMAL(20, 1),
+  allow_backdated BOOLEAN NOT NULL DEFAULT true,
+  require_attachment BOOLEAN NOT NULL DEFAULT false,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE leave_type_policy ADD CONSTRAINT fk_leave_type_policy_leave_type_id FOREIGN KEY ("leave_type_id") REFERENCES leave_type ("id") ON DELETE CASCADE;
+ALTER TABLE leave_type_policy ADD CONSTRAINT leave_type_policy_leave_type_uq UNIQUE (leave_type_id);
+
+ALTER TABLE leave_request ADD COLUMN IF NOT EXISTS attachment_url TEXT;
@@ -0,0 +1 @@
+ALTER TABLE leave_type ADD COLUMN IF NOT EXISTS eligibility JSONB;
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS leave_blackout (
+  id uuid PRIMARY KEY,
+  business_id uuid NOT NULL,
+  department_id uuid,
+  reason TEXT NOT NULL,
+  start_date DATE NOT NULL,
+  end_date DATE NOT NULL,
+  leave_type_id_list uuid[] NOT NULL DEFAULT '{}',
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE leave_blackout ADD CONSTRAINT fk_leave_blackout_business_id FOREIGN KEY ("business_id") REFERENCES business_account ("id") ON DELETE CASCADE;
+ALTER TABLE leave_blackout ADD CONSTRAINT fk_leave_blackout_department_id FOREIGN KEY ("department_id") REFERENCES department_account ("id") ON DELETE CASCADE;
+CREATE INDEX IF NOT EXISTS idx_leave_blackout_business_date ON leave_blackout (business_id, start_date, end_date);
+
+INSERT INTO permission(id, name, description, created_on, created_by,  is_business, is_department, is_user)VALUES(uuid_generate_v4(), 'override:leave-blackout', 'Override Leave Blackout', CURRENT_TIMESTAMP, '00000000-0000-0000-0000-000000000000'::uuid, true, false, false);
+
+WITH superadmin_role AS (SELECT "id" FROM "role" WHERE "name" = 'superadmin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by") SELECT uuid_generate_v4(), superadmin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(), '00000000-0000-0000-0000-000000000000'::uuid  FROM superadmin_role, "permission" WHERE "permission"."name" IN ('override:leave-blackout');
+WITH admin_role AS (SELECT "id" FROM "role" WHERE "name" = 'admin' LIMIT 1) INSERT INTO "role_permission" ("id", "role_id", "permission_id", "created_on", "created_by")SELECT uuid_generate_v4(), admin_role."id" AS "role_id", "permission"."id" AS "permission_id", NOW(),'00000000-0000-0000-0000-000000000000'::uuid FROM admin_role, "permission" WHERE "permission"."name" IN ('override:leave-blackout');
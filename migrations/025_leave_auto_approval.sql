@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS leave_auto_approval_rule (
+  id uuid PRIMARY KEY,
+  business_id uuid NOT NULL,
+  label TEXT NOT NULL,
+  leave_type_id uuid,
+  max_days DECIMAL(20, 1),
+  min_notice_days INTEGER,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  updated_on TIMESTAMPTZ,
+  updated_by uuid
+);
+
+ALTER TABLE leave_auto_approval_rule ADD CONSTRAINT fk_leave_auto_approval_rule_business_id FOREIGN KEY ("business_id") REFERENCES business_account ("id") ON DELETE CASCADE;
+ALTER TABLE leave_auto_approval_rule ADD CONSTRAINT fk_leave_auto_approval_rule_leave_type_id FOREIGN KEY ("leave_type_id") REFERENCES leave_type ("id") ON DELETE CASCADE;
+CREATE INDEX IF NOT EXISTS idx_leave_auto_approval_rule_business_id ON leave_auto_approval_rule (business_id);
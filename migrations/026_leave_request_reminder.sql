@@ -0,0 +1,20 @@
+ALTER TYPE leave_status ADD VALUE IF NOT EXISTS 'expired';
+
+CREATE TYPE leave_reminder_type AS ENUM (
+  'reminder',
+  'escalation',
+  'expiry'
+);
+
+CREATE TABLE IF NOT EXISTS leave_request_reminder (
+  id uuid PRIMARY KEY,
+  leave_id uuid NOT NULL,
+  reminder_type leave_reminder_type NOT NULL,
+  created_on TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE leave_request_reminder ADD CONSTRAINT fk_leave_request_reminder_leave_id FOREIGN KEY ("leave_id") REFERENCES leave_request ("id") ON DELETE CASCADE;
+ALTER TABLE leave_request_reminder ADD CONSTRAINT uq_leave_request_reminder UNIQUE (leave_id, reminder_type);
+
+INSERT INTO setting(id, label, key, value_type,  is_deleted, created_on, created_by, is_editable, is_global, is_user, is_business, is_user_business, description, cluster_id) VALUES(uuid_generate_v4(), 'Leave Reminder Hours', 'leave_reminder_hours', 'string', false, CURRENT_TIMESTAMP,  '00000000-0000-0000-0000-000000000000', true, false, false, true, false,  'Hours after which the approver is reminded of a leave request pending their action.', null);
+INSERT INTO setting(id, label, key, value_type,  is_deleted, created_on, created_by, is_editable, is_global, is_user, is_business, is_user_business, description, cluster_id) VALUES(uuid_generate_v4(), 'Leave Escalation Hours', 'leave_escalation_hours', 'string', false, CURRENT_TIMESTAMP,  '00000000-0000-0000-0000-000000000000', true, false, false, true, false,  'Hours after which a pending leave request is escalated to the leave fallback approver.', null);
@@ -0,0 +1 @@
+ALTER TYPE leave_status ADD VALUE IF NOT EXISTS 'cancellation_requested';
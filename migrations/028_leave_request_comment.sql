@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS leave_request_comment (
+  id uuid PRIMARY KEY,
+  leave_id uuid NOT NULL,
+  comment TEXT NOT NULL,
+  created_on TIMESTAMPTZ NOT NULL,
+  created_by uuid NOT NULL,
+  deleted_on TIMESTAMPTZ,
+  deleted_by uuid,
+  is_deleted BOOLEAN NOT NULL DEFAULT false
+);
+
+ALTER TABLE leave_request_comment ADD CONSTRAINT fk_leave_request_comment_leave_id FOREIGN KEY ("leave_id") REFERENCES leave_request ("id") ON DELETE CASCADE;
+ALTER TABLE leave_request_comment ADD CONSTRAINT fk_leave_request_comment_created_by FOREIGN KEY ("created_by") REFERENCES user_account ("id") ON DELETE CASCADE;
+CREATE INDEX IF NOT EXISTS idx_leave_request_comment_leave_id ON leave_request_comment (leave_id) WHERE is_deleted = false;
@@ -1,8 +1,19 @@
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
 use secrecy::ExposeSecret;
+use uuid::Uuid;
 
 use crate::{
-    configuration::Config, configuration::get_configuration,
-    routes::user::utils::generate_jwt_token_for_user, utils::configure_database,
+    configuration::Config,
+    configuration::get_configuration,
+    routes::{
+        leave::utils::{
+            accrue_user_leave, expire_carried_forward_leave, get_leave_group, rollover_leave_group,
+        },
+        user::utils::generate_jwt_token_for_user,
+    },
+    startup::get_connection_pool,
+    utils::configure_database,
 };
 
 #[tracing::instrument(name = "Default Migration")]
@@ -26,6 +37,83 @@ pub async fn generate_user_token(configuration: &Config) {
     )
 }
 
+#[tracing::instrument(name = "Leave group rollover")]
+pub async fn run_leave_rollover(
+    configuration: &Config,
+    business_id: Uuid,
+    source_group_id: Uuid,
+    target_group_id: Uuid,
+) -> Result<(), anyhow::Error> {
+    let pool = get_connection_pool(&configuration.database);
+    let group_id_list = vec![source_group_id, target_group_id];
+    let group_list = get_leave_group(
+        &pool,
+        business_id,
+        Some(group_id_list.as_slice()),
+        None,
+        None,
+        None,
+    )
+    .await?;
+    let source_group = group_list
+        .iter()
+        .find(|a| a.id == source_group_id)
+        .ok_or_else(|| anyhow!("Source leave group not found"))?;
+    let target_group = group_list
+        .iter()
+        .find(|a| a.id == target_group_id)
+        .ok_or_else(|| anyhow!("Target leave group not found"))?;
+    if target_group.start_date < source_group.end_date {
+        return Err(anyhow!(
+            "Target leave group must start after the source leave group ends"
+        ));
+    }
+    let summary = rollover_leave_group(
+        &pool,
+        business_id,
+        source_group,
+        target_group,
+        configuration.application.service_id,
+    )
+    .await?;
+    eprintln!(
+        "Rolled over {} to {}: {} carried forward, {} encashed, {} lapsed",
+        source_group.label,
+        target_group.label,
+        summary.carried_count,
+        summary.encashed_count,
+        summary.lapsed_count
+    );
+    Ok(())
+}
+
+#[tracing::instrument(name = "Leave carry forward expiry")]
+pub async fn run_leave_carry_forward_expiry(configuration: &Config) -> Result<(), anyhow::Error> {
+    let pool = get_connection_pool(&configuration.database);
+    let expired_count =
+        expire_carried_forward_leave(&pool, Utc::now(), configuration.application.service_id)
+            .await?;
+    eprintln!(
+        "Expired carried forward days of {} user leave(s)",
+        expired_count
+    );
+    Ok(())
+}
+
+#[tracing::instrument(name = "Leave accrual")]
+pub async fn run_leave_accrual(
+    configuration: &Config,
+    date: DateTime<Utc>,
+) -> Result<(), anyhow::Error> {
+    let pool = get_connection_pool(&configuration.database);
+    let summary = accrue_user_leave(&pool, date, configuration.application.service_id).await?;
+    eprintln!(
+        "Processed {} accrual/s, credited {} user leave/s",
+        summary.accrual_count, summary.credited_count
+    );
+    Ok(())
+}
+
 #[tracing::instrument(name = "Run custom command")]
 pub async fn run_custom_commands(
     configuration: &Config,
@@ -36,6 +124,28 @@ pub async fn run_custom_commands(
             run_migrations().await;
         } else if args[1] == "generate_token" {
             generate_user_token(configuration).await;
+        } else if args[1] == "leave_rollover" {
+            if args.len() < 5 {
+                eprintln!(
+                    "Usage: leave_rollover <business_id> <source_group_id> <target_group_id>"
+                );
+                return Ok(());
+            }
+            run_leave_rollover(
+                configuration,
+                Uuid::parse_str(&args[2])?,
+                Uuid::parse_str(&args[3])?,
+                Uuid::parse_str(&args[4])?,
+            )
+            .await?;
+        } else if args[1] == "leave_carry_forward_expiry" {
+            run_leave_carry_forward_expiry(configuration).await?;
+        } else if args[1] == "leave_accrual" {
+            let date = match args.get(2) {
+                Some(date) => DateTime::parse_from_rfc3339(date)?.with_timezone(&Utc),
+                None => Utc::now(),
+            };
+            run_leave_accrual(configuration, date).await?;
         }
     } else {
         eprintln!("Invalid command. Please enter a valid command.");
@@ -134,6 +134,7 @@ impl EmailClientConfig {
 #[allow(dead_code)]
 pub struct SlackChannel {
     pub leave: SecretString,
+    pub on_call: SecretString,
 }
 
 #[derive(Debug, Deserialize, Clone)]
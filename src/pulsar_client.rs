@@ -1,7 +1,11 @@
 use crate::{
     // routes::leave::utils::send_slack_notification_for_approved_leave,
     routes::{
-        leave::utils::send_slack_notification_for_approved_leave,
+        leave::{
+            schemas::LeaveReminderType,
+            utils::{send_leave_reminder, send_slack_notification_for_approved_leave},
+        },
+        on_call::utils::send_on_call_handoff_notification,
         web_socket::utils::{
             delete_notifications_by_connection_id, fetch_notifications_by_connection_id,
         },
@@ -22,6 +26,7 @@ use pulsar::{
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use std::{fmt, time::Duration};
+use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -64,10 +69,27 @@ impl DeserializeMessage for WSMessageData {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SchedulerMessageType {
+    #[default]
+    LeaveAlert,
+    OnCallHandoff,
+    LeaveReminder,
+    LeaveEscalation,
+    LeaveExpiry,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SchedulerMessageData {
     pub partition_key: Option<String>,
     pub date: DateTime<Utc>,
+    #[serde(default)]
+    pub message_type: SchedulerMessageType,
+    #[serde(default)]
+    pub department_id: Option<Uuid>,
+    #[serde(default)]
+    pub leave_id_list: Vec<Uuid>,
 }
 
 impl SerializeMessage for SchedulerMessageData {
@@ -237,6 +259,7 @@ impl PulsarClient {
         });
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn start_scheduler_consumer(
         &self,
         consumer_name: &str,
@@ -244,6 +267,8 @@ impl PulsarClient {
         pool: web::Data<PgPool>,
         topic_name: &str,
         slack_client: Data<SlackClient>,
+        websocket_srv: Data<Addr<Server>>,
+        producer_client: Data<PulsarClient>,
     ) {
         let mut consumer = self
             .initiate_cosumer::<SchedulerMessageData>(consumer_name, subscription, topic_name)
@@ -254,14 +279,67 @@ impl PulsarClient {
                 match result {
                     Ok(msg) => match msg.deserialize() {
                         Ok(data) => {
-                            if let Err(e) = send_slack_notification_for_approved_leave(
-                                &pool,
-                                &slack_client,
-                                data.date,
-                            )
-                            .await
-                            {
-                                eprintln!("Failed to send Slack message: {:?}", e);
+                            let result = match data.message_type {
+                                SchedulerMessageType::LeaveAlert => {
+                                    send_slack_notification_for_approved_leave(
+                                        &pool,
+                                        &slack_client,
+                                        data.date,
+                                    )
+                                    .await
+                                    .context("Failed to send Slack message")
+                                }
+                                SchedulerMessageType::OnCallHandoff => match data.department_id {
+                                    Some(department_id) => send_on_call_handoff_notification(
+                                        &pool,
+                                        &slack_client,
+                                        &websocket_srv,
+                                        &producer_client,
+                                        department_id,
+                                        data.date,
+                                    )
+                                    .await
+                                    .context("Failed to send on-call handoff notification"),
+                                    None => {
+                                        // Nothing to notify, drop the message
+                                        eprintln!("On-call handoff message has no department id");
+                                        Ok(())
+                                    }
+                                },
+                                SchedulerMessageType::LeaveReminder => send_leave_reminder(
+                                    &pool,
+                                    &websocket_srv,
+                                    &producer_client,
+                                    LeaveReminderType::Reminder,
+                                    &data.leave_id_list,
+                                    None,
+                                )
+                                .await
+                                .context("Failed to send leave reminder"),
+                                SchedulerMessageType::LeaveEscalation => send_leave_reminder(
+                                    &pool,
+                                    &websocket_srv,
+                                    &producer_client,
+                                    LeaveReminderType::Escalation,
+                                    &data.leave_id_list,
+                                    None,
+                                )
+                                .await
+                                .context("Failed to send leave escalation"),
+                                // Expiry is scheduled at the end of the leave date
+                                SchedulerMessageType::LeaveExpiry => send_leave_reminder(
+                                    &pool,
+                                    &websocket_srv,
+                                    &producer_client,
+                                    LeaveReminderType::Expiry,
+                                    &data.leave_id_list,
+                                    Some(data.date - chrono::Duration::days(1)),
+                                )
+                                .await
+                                .context("Failed to expire pending leave requests"),
+                            };
+                            if let Err(e) = result {
+                                eprintln!("{:?}", e);
                             } else if let Err(e) = consumer.ack(&msg).await {
                                 eprintln!("Failed to acknowledge message: {:?}", e);
                             }
@@ -5,6 +5,7 @@ use crate::routes::business::routes::business_routes;
 use crate::routes::department::routes::department_routes;
 // use crate::routes::department::routes::department_routes;
 use crate::routes::leave::routes::leave_routes;
+use crate::routes::on_call::routes::on_call_routes;
 use crate::routes::permission::routes::permission_routes;
 use crate::routes::role::routes::role_routes;
 use crate::routes::setting::routes::setting_routes;
@@ -57,6 +58,15 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
                 })
                 .wrap(HeaderValidation),
         )
+        .service(
+            web::scope("/on-call")
+                .configure(on_call_routes)
+                .wrap(BusinessAccountValidation)
+                .wrap(RequireAuth {
+                    allow_deleted_user: false,
+                })
+                .wrap(HeaderValidation),
+        )
         .service(
             web::scope("/role")
                 .configure(role_routes)
@@ -30,15 +30,19 @@ use crate::{
 use super::{
     schemas::{
         BasicBusinessAccount, BusinessAccount, BusinessFetchRequest, BusinessInviteRequest,
-        BusinessPermissionRequest, BusinessUserAssociationRequest, CreateBusinessAccount,
-        UpdateBusinessAccount, UserBusinessDeassociationRequest, UserBusinessInvitation,
+        BusinessLocation, BusinessLocationCreationRequest, BusinessLocationUserAssignmentRequest,
+        BusinessPermissionRequest, BusinessUserAssociationRequest,
+        BusinessUserManagerUpdateRequest, CreateBusinessAccount, UpdateBusinessAccount,
+        UserBusinessDeassociationRequest, UserBusinessInvitation,
     },
     utils::{
-        associate_user_to_business, create_business_account, delete_invite_by_id,
-        delete_user_business_relationship, fetch_business_invite, get_basic_business_accounts,
-        get_basic_business_accounts_by_user_id, get_business_account, mark_invite_as_verified,
-        save_business_invite_request, save_user_business_relation, soft_delete_business_account,
-        update_business_account, validate_user_business_permission,
+        assign_business_location, associate_user_to_business, create_business_account,
+        delete_business_location, delete_invite_by_id, delete_user_business_relationship,
+        fetch_business_invite, get_basic_business_accounts, get_basic_business_accounts_by_user_id,
+        get_business_account, get_business_locations, get_user_business_role_id,
+        mark_invite_as_verified, save_business_invite_request, save_business_location,
+        save_user_business_relation, soft_delete_business_account, update_business_account,
+        update_reporting_manager, validate_business_location, validate_user_business_permission,
     },
 };
 
@@ -825,3 +829,317 @@ pub async fn business_account_updation_req(
         (),
     )))
 }
+
+#[utoipa::path(
+    post,
+    path = "/business/location/save",
+    tag = "Business Account",
+    description = "API for creating / updating business locations with their work week and time zone",
+    summary = "Business Location Save API",
+    request_body(content = BusinessLocationCreationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved business location.", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+      )
+)]
+#[tracing::instrument(err, name = "business location save", skip(pool), fields())]
+pub async fn business_location_save_req(
+    pool: web::Data<PgPool>,
+    user_account: UserAccount,
+    business_account: BusinessAccount,
+    req: BusinessLocationCreationRequest,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    validate_business_location(&req).map_err(GenericError::ValidationError)?;
+    if let Some(id) = req.id {
+        get_business_locations(&pool, business_account.id, Some(&vec![id]))
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching business location".to_owned(),
+                    e,
+                )
+            })?
+            .first()
+            .ok_or_else(|| GenericError::DataNotFound("Invalid location id".to_string()))?;
+    }
+    save_business_location(&pool, &req, business_account.id, user_account.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving business location".to_owned(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved business location.",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/business/location/list",
+    tag = "Business Account",
+    description = "API for listing business locations",
+    summary = "Business Location List API",
+    responses(
+        (status=200, description= "sucessfully fetched business location.", body= GenericResponse<Vec<BusinessLocation>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+      )
+)]
+#[tracing::instrument(err, name = "business location list", skip(pool), fields())]
+pub async fn business_location_list_req(
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<BusinessLocation>>>, GenericError> {
+    let location_list = get_business_locations(&pool, business_account.id, None)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching business location".to_owned(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched business location.",
+        location_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/business/location/delete/{id}",
+    tag = "Business Account",
+    description = "API for deleting business location, users assigned to it are left without a location",
+    summary = "Business Location Delete API",
+    responses(
+        (status=200, description= "sucessfully deleted business location.", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+        ("id" = String, Path, description = "Location ID"),
+      )
+)]
+#[tracing::instrument(err, name = "business location delete", skip(pool), fields())]
+pub async fn business_location_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let id = path.into_inner();
+    get_business_locations(&pool, business_account.id, Some(&vec![id]))
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching business location".to_owned(),
+                e,
+            )
+        })?
+        .first()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid location id".to_string()))?;
+    delete_business_location(&pool, id).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while deleting business location".to_owned(),
+            e,
+        )
+    })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted business location.",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/business/location/user/assign",
+    tag = "Business Account",
+    description = "API for assigning users to a business location, a null location id removes the assignment",
+    summary = "Business Location User Assignment API",
+    request_body(content = BusinessLocationUserAssignmentRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully assigned business location.", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+      )
+)]
+#[tracing::instrument(err, name = "business location user assignment", skip(pool), fields())]
+pub async fn business_location_assign_req(
+    pool: web::Data<PgPool>,
+    user_account: UserAccount,
+    business_account: BusinessAccount,
+    req: BusinessLocationUserAssignmentRequest,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    if req.user_id_list.is_empty() {
+        return Err(GenericError::ValidationError(
+            "User list cannot be empty".to_string(),
+        ));
+    }
+    if let Some(location_id) = req.location_id {
+        get_business_locations(&pool, business_account.id, Some(&vec![location_id]))
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching business location".to_owned(),
+                    e,
+                )
+            })?
+            .first()
+            .ok_or_else(|| GenericError::DataNotFound("Invalid location id".to_string()))?;
+    }
+    let mut user_id_list = req.user_id_list.clone();
+    user_id_list.sort();
+    user_id_list.dedup();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let updated_count = assign_business_location(
+        &mut transaction,
+        business_account.id,
+        req.location_id,
+        &user_id_list,
+        user_account.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while assigning business location".to_owned(),
+            e,
+        )
+    })?;
+    // Dropping the transaction rolls the assignment back
+    if updated_count as usize != user_id_list.len() {
+        return Err(GenericError::ValidationError(
+            "Some users are not associated with the business".to_string(),
+        ));
+    }
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to assign business location")?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully assigned business location.",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/business/user/manager/update",
+    tag = "Business Account",
+    description = "API for setting the reporting manager of business users, a null manager id removes it",
+    summary = "Business User Reporting Manager Updation API",
+    request_body(content = BusinessUserManagerUpdateRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated reporting manager.", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+      )
+)]
+#[tracing::instrument(err, name = "business user manager updation", skip(pool), fields())]
+pub async fn business_user_manager_update_req(
+    pool: web::Data<PgPool>,
+    user_account: UserAccount,
+    business_account: BusinessAccount,
+    req: BusinessUserManagerUpdateRequest,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    if req.user_id_list.is_empty() {
+        return Err(GenericError::ValidationError(
+            "User list cannot be empty".to_string(),
+        ));
+    }
+    if let Some(manager_id) = req.manager_id {
+        if req.user_id_list.contains(&manager_id) {
+            return Err(GenericError::ValidationError(
+                "A user cannot be their own reporting manager".to_string(),
+            ));
+        }
+        get_user_business_role_id(&pool, business_account.id, manager_id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching reporting manager".to_owned(),
+                    e,
+                )
+            })?
+            .ok_or_else(|| {
+                GenericError::ValidationError(
+                    "Reporting manager is not associated with the business".to_string(),
+                )
+            })?;
+    }
+    let updated_count = update_reporting_manager(
+        &pool,
+        business_account.id,
+        req.manager_id,
+        &req.user_id_list,
+        user_account.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while updating reporting manager".to_owned(),
+            e,
+        )
+    })?;
+    if updated_count as usize != req.user_id_list.len() {
+        return Err(GenericError::ValidationError(
+            "Some users are not associated with the business".to_string(),
+        ));
+    }
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated reporting manager.",
+        (),
+    )))
+}
@@ -4,7 +4,10 @@ use uuid::Uuid;
 
 use crate::{email::EmailObject, routes::user::schemas::UserVector, schemas::Status};
 
-use super::schemas::{BasicBusinessAccount, BusinessAccount, UserBusinessInvitation};
+use super::schemas::{
+    BasicBusinessAccount, BusinessAccount, BusinessLocation, BusinessUserMembership,
+    UserBusinessInvitation, WeekDay,
+};
 
 #[derive(Debug, FromRow)]
 pub struct BusinessAccountModel {
@@ -87,3 +90,40 @@ impl UserBusinessInvitationModel {
         }
     }
 }
+
+#[derive(Debug, FromRow)]
+pub struct BusinessLocationModel {
+    pub id: Uuid,
+    pub name: String,
+    pub time_zone: String,
+    pub work_week: Json<Vec<WeekDay>>,
+}
+
+impl BusinessLocationModel {
+    pub fn into_schema(self) -> BusinessLocation {
+        BusinessLocation {
+            id: self.id,
+            name: self.name,
+            time_zone: self.time_zone,
+            work_week: self.work_week.0,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct BusinessUserMembershipModel {
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    pub department_id_list: Vec<Uuid>,
+    pub joined_on: DateTime<Utc>,
+}
+
+impl BusinessUserMembershipModel {
+    pub fn into_schema(self) -> BusinessUserMembership {
+        BusinessUserMembership {
+            role_id: self.role_id,
+            department_id_list: self.department_id_list,
+            joined_on: self.joined_on,
+        }
+    }
+}
@@ -8,11 +8,12 @@ use crate::{
 };
 
 use super::handlers::{
-    business_account_deletion_req, business_account_updation_req, business_permission_validation,
-    business_user_invite_request, business_user_list_req, delete_business_user_invite,
-    fetch_business_req, list_business_req, list_business_user_invite,
-    register_business_account_req, user_business_association_req, user_business_deassociation_req,
-    verify_business_user_invite,
+    business_account_deletion_req, business_account_updation_req, business_location_assign_req,
+    business_location_delete_req, business_location_list_req, business_location_save_req,
+    business_permission_validation, business_user_invite_request, business_user_list_req,
+    business_user_manager_update_req, delete_business_user_invite, fetch_business_req,
+    list_business_req, list_business_user_invite, register_business_account_req,
+    user_business_association_req, user_business_deassociation_req, verify_business_user_invite,
 };
 
 pub fn business_routes(cfg: &mut web::ServiceConfig) {
@@ -108,5 +109,47 @@ pub fn business_routes(cfg: &mut web::ServiceConfig) {
                     permission_list: vec![PermissionType::UpdateBusiness.to_string()],
                 })
                 .wrap(BusinessAccountValidation),
+        )
+        .route(
+            "/location/save",
+            web::post()
+                .to(business_location_save_req)
+                .wrap(BusinessPermissionValidation {
+                    permission_list: vec![PermissionType::UpdateBusiness.to_string()],
+                })
+                .wrap(BusinessAccountValidation),
+        )
+        .route(
+            "/location/list",
+            web::get()
+                .to(business_location_list_req)
+                .wrap(BusinessAccountValidation),
+        )
+        .route(
+            "/location/delete/{id}",
+            web::delete()
+                .to(business_location_delete_req)
+                .wrap(BusinessPermissionValidation {
+                    permission_list: vec![PermissionType::UpdateBusiness.to_string()],
+                })
+                .wrap(BusinessAccountValidation),
+        )
+        .route(
+            "/user/manager/update",
+            web::post()
+                .to(business_user_manager_update_req)
+                .wrap(BusinessPermissionValidation {
+                    permission_list: vec![PermissionType::UpdateBusiness.to_string()],
+                })
+                .wrap(BusinessAccountValidation),
+        )
+        .route(
+            "/location/user/assign",
+            web::post()
+                .to(business_location_assign_req)
+                .wrap(BusinessPermissionValidation {
+                    permission_list: vec![PermissionType::UpdateBusiness.to_string()],
+                })
+                .wrap(BusinessAccountValidation),
         );
 }
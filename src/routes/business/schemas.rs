@@ -1,6 +1,6 @@
 use actix_http::Payload;
 use actix_web::{FromRequest, HttpMessage, HttpRequest, web};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
 use futures::future::LocalBoxFuture;
 use serde::{Deserialize, Serialize};
 use std::future::{Ready, ready};
@@ -253,3 +253,123 @@ impl FromRequest for UserBusinessDeassociationRequest {
         })
     }
 }
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum WeekDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<Weekday> for WeekDay {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Mon => WeekDay::Monday,
+            Weekday::Tue => WeekDay::Tuesday,
+            Weekday::Wed => WeekDay::Wednesday,
+            Weekday::Thu => WeekDay::Thursday,
+            Weekday::Fri => WeekDay::Friday,
+            Weekday::Sat => WeekDay::Saturday,
+            Weekday::Sun => WeekDay::Sunday,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessLocationCreationRequest {
+    pub id: Option<Uuid>,
+    pub name: String,
+    pub time_zone: String,
+    pub work_week: Vec<WeekDay>,
+}
+
+impl FromRequest for BusinessLocationCreationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessLocationUserAssignmentRequest {
+    pub location_id: Option<Uuid>,
+    pub user_id_list: Vec<Uuid>,
+}
+
+impl FromRequest for BusinessLocationUserAssignmentRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessLocation {
+    pub id: Uuid,
+    pub name: String,
+    pub time_zone: String,
+    pub work_week: Vec<WeekDay>,
+}
+
+impl BusinessLocation {
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.work_week.contains(&WeekDay::from(date.weekday()))
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessUserManagerUpdateRequest {
+    pub manager_id: Option<Uuid>,
+    pub user_id_list: Vec<Uuid>,
+}
+
+impl FromRequest for BusinessUserManagerUpdateRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+/// Standing of a user within a business, used to decide what the user is entitled to.
+#[derive(Debug)]
+pub struct BusinessUserMembership {
+    pub role_id: Uuid,
+    pub department_id_list: Vec<Uuid>,
+    pub joined_on: DateTime<Utc>,
+}
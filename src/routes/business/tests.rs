@@ -3,15 +3,18 @@ pub mod tests {
     use crate::constants::DUMMY_INTERNATIONAL_DIALING_CODE;
     use crate::email::EmailObject;
     use crate::routes::business::schemas::{
-        BusinessAccount, CreateBusinessAccount, UpdateBusinessAccount,
+        BusinessAccount, BusinessLocationCreationRequest, CreateBusinessAccount,
+        UpdateBusinessAccount, WeekDay,
     };
     use crate::routes::business::utils::{
-        associate_user_to_business, create_business_account, delete_invite_by_id,
-        delete_user_business_relationship, fetch_associated_business_account_model,
-        fetch_business_account_model_by_id, fetch_business_invite, get_basic_business_accounts,
-        get_basic_business_accounts_by_user_id, get_business_account, mark_invite_as_verified,
-        save_business_invite_request, save_user_business_relation, soft_delete_business_account,
-        update_business_account, validate_business_account_active,
+        assign_business_location, associate_user_to_business, create_business_account,
+        delete_business_location, delete_invite_by_id, delete_user_business_relationship,
+        fetch_associated_business_account_model, fetch_business_account_model_by_id,
+        fetch_business_invite, get_basic_business_accounts, get_basic_business_accounts_by_user_id,
+        get_business_account, get_business_locations, get_user_business_location,
+        mark_invite_as_verified, save_business_invite_request, save_business_location,
+        save_user_business_relation, soft_delete_business_account, update_business_account,
+        validate_business_account_active, validate_business_location,
         validate_user_business_permission,
     };
 
@@ -27,7 +30,7 @@ pub mod tests {
 
     use crate::tests::tests::get_test_pool;
     use anyhow::Context;
-    use chrono::Utc;
+    use chrono::{NaiveDate, Utc};
     use sqlx::PgPool;
     use tokio::join;
     use uuid::Uuid;
@@ -529,4 +532,85 @@ pub mod tests {
         assert!(delete_business_account_res.is_ok());
         assert!(delete_user_account_res.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_business_location() {
+        let pool = get_test_pool().await;
+
+        let mobile_no = "3100000071";
+
+        let user_res = setup_user(
+            &pool,
+            "testuser71",
+            "testuser71@example.com",
+            mobile_no,
+            "testuser@123",
+        )
+        .await;
+
+        let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
+        let user_id = user_res.unwrap();
+        let business_id = business_res.unwrap();
+
+        let mut req = BusinessLocationCreationRequest {
+            id: None,
+            name: "Dubai".to_string(),
+            time_zone: "Invalid/Zone".to_string(),
+            work_week: vec![WeekDay::Monday, WeekDay::Tuesday],
+        };
+        assert!(validate_business_location(&req).is_err());
+        req.time_zone = "Asia/Dubai".to_string();
+        assert!(validate_business_location(&req).is_ok());
+
+        let save_res = save_business_location(&pool, &req, business_id, user_id).await;
+        assert!(save_res.is_ok());
+        let location_list = get_business_locations(&pool, business_id, None)
+            .await
+            .unwrap();
+        assert!(location_list.len() == 1);
+        let location_id = location_list[0].id;
+
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to create transaction.")
+            .unwrap();
+        let assign_res = assign_business_location(
+            &mut transaction,
+            business_id,
+            Some(location_id),
+            &vec![user_id],
+            user_id,
+        )
+        .await;
+        assert!(assign_res.unwrap() == 1);
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction.")
+            .unwrap();
+        let location = get_user_business_location(&pool, business_id, user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(location.id == location_id);
+        // 2025-07-07 is a Monday and 2025-07-09 is a Wednesday
+        assert!(location.is_working_day(NaiveDate::from_ymd_opt(2025, 7, 7).unwrap()));
+        assert!(!location.is_working_day(NaiveDate::from_ymd_opt(2025, 7, 9).unwrap()));
+
+        let delete_res = delete_business_location(&pool, location_id).await;
+        assert!(delete_res.is_ok());
+        let location = get_user_business_location(&pool, business_id, user_id)
+            .await
+            .unwrap();
+        assert!(location.is_none());
+
+        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &delete_mobile),
+        );
+        assert!(delete_business_account_res.is_ok());
+        assert!(delete_user_account_res.is_ok());
+    }
 }
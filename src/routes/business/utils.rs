@@ -1,6 +1,11 @@
-use super::models::UserBusinessRelationAccountModel;
+use std::collections::{HashMap, HashSet};
+
+use super::models::{
+    BusinessLocationModel, BusinessUserMembershipModel, UserBusinessRelationAccountModel,
+};
 use super::schemas::{
-    BasicBusinessAccount, BusinessAccount, UpdateBusinessAccount, UserBusinessInvitation,
+    BasicBusinessAccount, BusinessAccount, BusinessLocation, BusinessLocationCreationRequest,
+    BusinessUserMembership, UpdateBusinessAccount, UserBusinessInvitation, WeekDay,
 };
 use super::{
     errors::BusinessAccountError, models::BusinessAccountModel, schemas::CreateBusinessAccount,
@@ -14,7 +19,8 @@ use crate::routes::user::schemas::{
 use crate::schemas::{MaskingType, Status};
 use anyhow::Context;
 use anyhow::anyhow;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use sqlx::{Executor, PgPool, Postgres, QueryBuilder, Transaction};
 use uuid::Uuid;
 #[tracing::instrument(name = "create user account")]
@@ -686,3 +692,375 @@ pub async fn update_business_account(
 
     Ok(())
 }
+
+pub fn validate_business_location(req: &BusinessLocationCreationRequest) -> Result<(), String> {
+    if req.name.trim().is_empty() {
+        return Err("Location name cannot be empty".to_string());
+    }
+    if req.time_zone.parse::<Tz>().is_err() {
+        return Err(format!("Invalid time zone: {}", req.time_zone));
+    }
+    if req.work_week.is_empty() {
+        return Err("Work week must have at least one working day".to_string());
+    }
+    if req.work_week.iter().collect::<HashSet<_>>().len() != req.work_week.len() {
+        return Err("Duplicate days found in work week".to_string());
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save business location", skip(pool))]
+pub async fn save_business_location(
+    pool: &PgPool,
+    req: &BusinessLocationCreationRequest,
+    business_id: Uuid,
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO business_location (id, business_id, name, time_zone, work_week, created_on, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (id) DO UPDATE
+        SET name = EXCLUDED.name,
+            time_zone = EXCLUDED.time_zone,
+            work_week = EXCLUDED.work_week,
+            updated_on = EXCLUDED.created_on,
+            updated_by = EXCLUDED.created_by
+        "#,
+        req.id.unwrap_or_else(Uuid::new_v4),
+        business_id,
+        req.name.trim(),
+        req.time_zone,
+        sqlx::types::Json(&req.work_week) as sqlx::types::Json<&Vec<WeekDay>>,
+        Utc::now(),
+        created_by
+    );
+
+    query.execute(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving business location")
+    })?;
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch business location models", skip(pool))]
+pub async fn fetch_business_location_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<BusinessLocationModel>, anyhow::Error> {
+    let mut query_builder = QueryBuilder::<Postgres>::new(
+        "SELECT id, name, time_zone, work_week FROM business_location WHERE business_id = ",
+    );
+    query_builder.push_bind(business_id);
+
+    if let Some(id_list) = id_list
+        && !id_list.is_empty()
+    {
+        query_builder.push(" AND id = ANY(");
+        query_builder.push_bind(id_list);
+        query_builder.push(")");
+    }
+    query_builder.push(" ORDER BY name");
+
+    let query = query_builder.build_query_as::<BusinessLocationModel>();
+
+    let rows = query.fetch_all(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching business location")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_business_locations(
+    pool: &PgPool,
+    business_id: Uuid,
+    id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<BusinessLocation>, anyhow::Error> {
+    let models = fetch_business_location_models(pool, business_id, id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete business location", skip(pool))]
+pub async fn delete_business_location(pool: &PgPool, id: Uuid) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        DELETE FROM business_location
+        WHERE id = $1
+        "#,
+        id
+    );
+
+    query.execute(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting business location")
+    })?;
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "assign business location to users", skip(transaction))]
+pub async fn assign_business_location(
+    transaction: &mut Transaction<'_, Postgres>,
+    business_id: Uuid,
+    location_id: Option<Uuid>,
+    user_id_list: &Vec<Uuid>,
+    updated_by: Uuid,
+) -> Result<u64, anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE business_user_relationship
+        SET location_id = $1, updated_by = $2, updated_on = $3
+        WHERE business_id = $4 AND user_id = ANY($5)
+        "#,
+        location_id,
+        updated_by,
+        Utc::now(),
+        business_id,
+        user_id_list
+    );
+
+    let result = transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while assigning business location")
+    })?;
+
+    Ok(result.rows_affected())
+}
+
+#[tracing::instrument(name = "fetch user business location", skip(pool))]
+pub async fn get_user_business_location(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<BusinessLocation>, anyhow::Error> {
+    let row = sqlx::query_as!(
+        BusinessLocationModel,
+        r#"
+        SELECT bl.id, bl.name, bl.time_zone, bl.work_week as "work_week: sqlx::types::Json<Vec<WeekDay>>"
+        FROM business_user_relationship bur
+        INNER JOIN business_location bl ON bur.location_id = bl.id
+        WHERE bur.business_id = $1 AND bur.user_id = $2
+        "#,
+        business_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching user business location")
+    })?;
+
+    Ok(row.map(|a| a.into_schema()))
+}
+
+#[tracing::instrument(name = "fetch user business role", skip(pool))]
+pub async fn get_user_business_role_id(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Uuid>, anyhow::Error> {
+    let role_id = sqlx::query_scalar!(
+        r#"
+        SELECT role_id FROM business_user_relationship
+        WHERE business_id = $1 AND user_id = $2
+        "#,
+        business_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching user business role")
+    })?;
+
+    Ok(role_id)
+}
+
+#[tracing::instrument(name = "fetch user business join date", skip(pool))]
+pub async fn get_user_business_join_date(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+    let created_on = sqlx::query_scalar!(
+        r#"
+        SELECT created_on FROM business_user_relationship
+        WHERE business_id = $1 AND user_id = $2
+        "#,
+        business_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching user business join date")
+    })?;
+
+    Ok(created_on)
+}
+
+#[tracing::instrument(name = "fetch business user membership", skip(pool))]
+pub async fn fetch_business_user_membership_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id_list: &[Uuid],
+) -> Result<Vec<BusinessUserMembershipModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        BusinessUserMembershipModel,
+        r#"
+        SELECT bur.user_id, bur.role_id, bur.created_on AS joined_on,
+        COALESCE(
+            ARRAY(
+                SELECT budr.department_id FROM business_user_department_relationship budr
+                WHERE budr.user_id = bur.user_id AND budr.business_id = bur.business_id
+            ),
+            '{}'
+        ) AS "department_id_list!"
+        FROM business_user_relationship bur
+        WHERE bur.business_id = $1 AND bur.user_id = ANY($2)
+        "#,
+        business_id,
+        user_id_list
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching business user membership")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_business_user_membership(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<BusinessUserMembership>, anyhow::Error> {
+    let models = fetch_business_user_membership_models(pool, business_id, &[user_id]).await?;
+    Ok(models.into_iter().next().map(|a| a.into_schema()))
+}
+
+/// Returns the membership of each of the given users who are part of the business.
+pub async fn get_business_user_membership_map(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id_list: &[Uuid],
+) -> Result<HashMap<Uuid, BusinessUserMembership>, anyhow::Error> {
+    let models = fetch_business_user_membership_models(pool, business_id, user_id_list).await?;
+    Ok(models
+        .into_iter()
+        .map(|a| (a.user_id, a.into_schema()))
+        .collect())
+}
+
+#[tracing::instrument(name = "update reporting manager", skip(pool))]
+pub async fn update_reporting_manager(
+    pool: &PgPool,
+    business_id: Uuid,
+    manager_id: Option<Uuid>,
+    user_id_list: &Vec<Uuid>,
+    updated_by: Uuid,
+) -> Result<u64, anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE business_user_relationship
+        SET reporting_manager_id = $1, updated_by = $2, updated_on = $3
+        WHERE business_id = $4 AND user_id = ANY($5)
+        "#,
+        manager_id,
+        updated_by,
+        Utc::now(),
+        business_id,
+        user_id_list
+    );
+
+    let result = query.execute(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while updating reporting manager")
+    })?;
+
+    Ok(result.rows_affected())
+}
+
+/// Returns the verified members of the business, optionally narrowed down to the given
+/// departments and business role.
+#[tracing::instrument(name = "fetch business user id list", skip(pool))]
+pub async fn fetch_business_user_id_list(
+    pool: &PgPool,
+    business_id: Uuid,
+    department_id_list: Option<&Vec<Uuid>>,
+    role_id: Option<Uuid>,
+) -> Result<Vec<Uuid>, anyhow::Error> {
+    let user_id_list = sqlx::query_scalar!(
+        r#"
+        SELECT bur.user_id
+        FROM business_user_relationship bur
+        INNER JOIN user_account ua ON ua.id = bur.user_id
+        WHERE bur.business_id = $1
+          AND bur.verified = TRUE
+          AND ua.is_deleted = FALSE
+          AND ($3::uuid IS NULL OR bur.role_id = $3)
+          AND (
+            $2::uuid[] IS NULL OR EXISTS (
+                SELECT 1 FROM business_user_department_relationship budr
+                WHERE budr.user_id = bur.user_id
+                  AND budr.business_id = bur.business_id
+                  AND budr.department_id = ANY($2)
+            )
+          )
+        ORDER BY bur.created_on
+        "#,
+        business_id,
+        department_id_list.map(|a| &a[..]) as Option<&[Uuid]>,
+        role_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching business user id list")
+    })?;
+
+    Ok(user_id_list)
+}
+
+/// Returns the users from the list who are part of the business and hold the given permission.
+#[tracing::instrument(name = "fetch users with business permission", skip(pool))]
+pub async fn fetch_user_id_list_with_business_permission(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id_list: &Vec<Uuid>,
+    permission: &str,
+) -> Result<Vec<Uuid>, anyhow::Error> {
+    let user_id_list = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT bur.user_id
+        FROM business_user_relationship bur
+        INNER JOIN role_permission rp ON bur.role_id = rp.role_id
+        INNER JOIN permission p ON rp.permission_id = p.id
+        WHERE bur.business_id = $1
+          AND bur.user_id = ANY($2)
+          AND rp.is_deleted = FALSE
+          AND p.is_deleted = FALSE
+          AND p.name = $3
+        "#,
+        business_id,
+        user_id_list,
+        permission
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching users with permission")
+    })?;
+
+    Ok(user_id_list)
+}
@@ -22,13 +22,13 @@ use super::{
     schemas::{
         BasicDepartmentAccount, CreateDepartmentAccount, DepartmentAccount, DepartmentFetchRequest,
         DepartmentPermissionRequest, DepartmentUserAssociationRequest, UpdateDepartmentAccount,
-        UserDepartmentDeassociationRequest,
+        UpdateDepartmentHeadRequest, UserDepartmentDeassociationRequest,
     },
     utils::{
         associate_user_to_department, create_department_account,
         delete_user_department_relationship, get_basic_department_accounts,
         get_basic_department_accounts_by_user_id, get_department_account,
-        soft_delete_department_account, update_department_account,
+        soft_delete_department_account, update_department_account, update_department_head,
         validate_user_department_permission,
     },
     // utils::{
@@ -480,3 +480,64 @@ pub async fn department_account_updation_req(
         (),
     )))
 }
+
+#[utoipa::path(
+    patch,
+    path = "/department/head/update",
+    tag = "Department Account",
+    description = "API for setting the head of a department, who is the default approver for leave requests of its members",
+    summary = "Department Head Updation API",
+    request_body(content = UpdateDepartmentHeadRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated department head.", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "Business id"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(err, name = "department head updation", skip(pool), fields())]
+pub async fn department_head_updation_req(
+    pool: web::Data<PgPool>,
+    user_account: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    req: UpdateDepartmentHeadRequest,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    if let Some(head_id) = req.user_id {
+        get_department_account(&pool, head_id, business_account.id, department_account.id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching department".to_owned(),
+                    e,
+                )
+            })?
+            .ok_or_else(|| {
+                GenericError::ValidationError(
+                    "Department head must be a member of the department".to_string(),
+                )
+            })?;
+    }
+    update_department_head(&pool, department_account.id, req.user_id, user_account.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while updating department head".to_owned(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated department head.",
+        (),
+    )))
+}
@@ -8,7 +8,7 @@ use crate::{
 };
 
 use super::handlers::{
-    department_account_deletion_req, department_account_updation_req,
+    department_account_deletion_req, department_account_updation_req, department_head_updation_req,
     department_permission_validation, fetch_department_req, list_department_req,
     register_department_account_req, user_department_association_req,
     user_department_deassociation_req,
@@ -69,5 +69,14 @@ pub fn department_routes(cfg: &mut web::ServiceConfig) {
                 permission_list: vec![PermissionType::UpdateDepartment.to_string()],
             })
             .wrap(DepartmentAccountValidation),
+    )
+    .route(
+        "/head/update",
+        web::patch()
+            .to(department_head_updation_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::UpdateDepartment.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
     );
 }
@@ -438,3 +438,30 @@ pub async fn update_department_account(
 
     Ok(())
 }
+
+#[tracing::instrument(name = "update department head", skip(pool))]
+pub async fn update_department_head(
+    pool: &PgPool,
+    department_id: Uuid,
+    head_id: Option<Uuid>,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"UPDATE department_account SET
+            head_id = $1,
+            updated_on = $2,
+            updated_by = $3
+        WHERE id = $4"#,
+        head_id,
+        Utc::now(),
+        updated_by,
+        department_id
+    );
+
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::anyhow!(e).context("A database failure occurred while updating department head")
+    })?;
+
+    Ok(())
+}
@@ -1,7 +1,12 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet, hash_map::Entry};
 
-use crate::pulsar_client::{PulsarClient, PulsarTopic, SchedulerMessageData};
+use crate::pulsar_client::PulsarClient;
 use crate::routes::business::schemas::BusinessAccount;
+use crate::routes::business::utils::{
+    fetch_business_user_id_list, fetch_user_id_list_with_business_permission,
+    get_business_user_membership, get_business_user_membership_map, get_user_business_location,
+    get_user_business_role_id, validate_user_business_permission,
+};
 use crate::routes::web_socket::schemas::ProcessType;
 use crate::routes::web_socket::utils::send_notification;
 use crate::websocket_client::Server;
@@ -16,7 +21,7 @@ use crate::{
         },
         user::{
             schemas::{UserAccount, VectorType},
-            utils::get_user,
+            utils::{get_minimal_user_list, get_user},
         },
     },
     schemas::{AllowedPermission, GenericResponse, PermissionType},
@@ -28,6 +33,7 @@ use actix_web::web;
 use anyhow::Context;
 use bigdecimal::BigDecimal;
 
+use chrono::{NaiveDate, Utc};
 use chrono_tz::Tz;
 use secrecy::SecretString;
 use sqlx::PgPool;
@@ -37,22 +43,50 @@ use utoipa::TupleUnit;
 use uuid::Uuid;
 
 use super::schemas::{
-    CreateLeaveRequest, FetchLeaveQuery, FetchLeaveRequest, FetchLeaveType,
-    LeavePeriodCreationRequest, LeavePeriodData, LeavePeriodFetchRequest, LeaveRequestData,
-    LeaveRequestEmailContext, LeaveRequestStatusEmailContext, UpdateLeaveStatusRequest,
+    BulkLeaveUserAssociationRequest, BulkUserLeaveAllocationResult,
+    CreateLeaveUserAssociationRequest, LeaveAccrualPolicy, LeaveAccrualPolicySaveRequest,
+    LeaveAutoApprovalRule, LeaveAutoApprovalRuleSaveRequest, LeaveBalanceAdjustmentRequest,
+    LeaveBalanceAdjustmentTarget, LeaveBlackout, LeaveBlackoutCreationRequest, LeaveCalendar,
+    LeaveCalendarFetchRequest, LeaveCarryForwardRule, LeaveCarryForwardRuleSaveRequest,
+    LeaveComment, LeaveCommentCreationRequest, LeaveGroup, LeaveGroupCloneRequest,
+    LeaveGroupCloneResult, LeaveGroupCreationRequest, LeaveLedgerEntryData, LeaveLedgerEntryType,
+    LeaveRequestCreationResult, LeaveStatus, LeaveTypeCreationRequest, LeaveTypeData,
+    LeaveTypeFetchRequest, LeaveTypePolicy, LeaveTypePolicySaveRequest,
+    ListLeaveUserAssociationRequest, UserLeave, UserLeaveAllocation, UserLeaveCreationData,
+    UserLeaveLedger,
 };
 use super::schemas::{
-    CreateLeaveUserAssociationRequest, LeaveGroup, LeaveGroupCreationRequest, LeaveStatus,
-    LeaveTypeCreationRequest, LeaveTypeData, LeaveTypeFetchRequest,
-    ListLeaveUserAssociationRequest, UserLeave,
+    BulkUpdateLeaveStatusRequest, CreateLeaveData, CreateLeaveRequest, FetchLeaveQuery,
+    FetchLeaveRequest, FetchLeaveType, HolidayCreationRequest, HolidayData, HolidayFetchRequest,
+    LeaveApproval, LeaveApprovalStage, LeaveApprovalStageFetchRequest,
+    LeaveApprovalStageSaveRequest, LeavePeriodCreationRequest, LeavePeriodData,
+    LeavePeriodFetchRequest, LeaveRequestData, LeaveRequestEmailContext,
+    LeaveRequestStatusEmailContext, LeaveStatusUpdateResult, PendingLeaveApprovalData,
+    UpdateLeaveRequest, UpdateLeaveStatusRequest,
 };
 use super::utils::{
-    delete_leave, delete_leave_group, delete_leave_period, delete_leave_type, delete_user_leave,
-    fetch_user_leaves, get_leave_group, get_leave_period, get_leave_type, get_leaves,
-    leave_group_create_validation, leave_type_create_validation, save_leave_group,
-    save_leave_period, save_leave_request, save_leave_type, save_user_leave,
-    update_leave_request_status, update_user_leave_count, validate_leave_request_creation,
-    validate_leave_status_update,
+    apply_leave_status_update, auto_approve_leave_request, build_bulk_user_leave_allocation_result,
+    build_user_leave_ledger, delete_holiday, delete_leave, delete_leave_accrual_policy,
+    delete_leave_auto_approval_rule, delete_leave_blackout, delete_leave_carry_forward_rule,
+    delete_leave_comment, delete_leave_group, delete_leave_period, delete_leave_type,
+    delete_leave_type_policy, delete_user_leave, fetch_user_leaves, find_leave_auto_approval_rule,
+    get_holiday, get_leave_accrual_policies, get_leave_approval_stages, get_leave_approvals,
+    get_leave_auto_approval_rules, get_leave_blackouts, get_leave_carry_forward_rules,
+    get_leave_comments, get_leave_group, get_leave_ledger, get_leave_participant_id_list,
+    get_leave_period, get_leave_request_data, get_leave_type, get_leave_type_policies, get_leaves,
+    get_user_leave_allocation, group_pending_leave_approvals, holiday_create_validation,
+    leave_group_create_validation, leave_type_create_validation, partition_eligible_user_id_list,
+    pick_leave_approver, populate_cloned_leave_group, resolve_leave_approver, save_holiday,
+    save_leave_accrual_policy, save_leave_approval, save_leave_approval_stage,
+    save_leave_auto_approval_rule, save_leave_blackout, save_leave_carry_forward_rule,
+    save_leave_comment, save_leave_group, save_leave_ledger, save_leave_period, save_leave_request,
+    save_leave_type, save_leave_type_policy, save_user_leave, schedule_leave_alert,
+    schedule_leave_reminder, update_leave_request, update_leave_request_status,
+    update_user_leave_allocation, update_user_leave_count, validate_leave_accrual_policy_data,
+    validate_leave_approval_stage_data, validate_leave_auto_approval_rule_data,
+    validate_leave_balance_adjustment, validate_leave_blackout,
+    validate_leave_carry_forward_rule_data, validate_leave_request_creation,
+    validate_leave_request_rules, validate_leave_type_eligibility, validate_leave_type_policy_data,
 };
 
 #[utoipa::path(
@@ -84,13 +118,24 @@ pub async fn leave_request_fetch_req(
     user: UserAccount,
     mail_config: web::Data<EmailClientConfig>,
     permissions: AllowedPermission,
+    business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<Vec<LeaveRequestData>>>, GenericError> {
     let setting_key_list = vec![SettingKey::TimeZone.to_string()];
-    let setting_list = get_setting_value(&pool, &setting_key_list, None, Some(user.id), false)
-        .await
-        .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let (setting_res, location_res) = join!(
+        get_setting_value(&pool, &setting_key_list, None, Some(user.id), false),
+        get_user_business_location(&pool, business_account.id, user.id),
+    );
+    let setting_list = setting_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let location = location_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user location".to_string(),
+            e,
+        )
+    })?;
+    // A user level time zone takes precedence over the one of the assigned location
     let timezone = setting_list
         .get_setting(&SettingKey::TimeZone.to_string())
+        .or_else(|| location.map(|a| a.time_zone))
         .ok_or_else(|| {
             GenericError::DataNotFound(format!("Please set the {}", SettingKey::TimeZone))
         })?;
@@ -225,7 +270,7 @@ pub async fn leave_type_list_req(
     user: UserAccount,
     business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<Vec<LeaveTypeData>>>, GenericError> {
-    let leave_type_list = get_leave_type(&pool, business_account.id, None, None, req.query)
+    let mut leave_type_list = get_leave_type(&pool, business_account.id, None, None, req.query)
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
@@ -233,6 +278,21 @@ pub async fn leave_type_list_req(
                 e,
             )
         })?;
+    if let Some(user_id) = req.eligible_user_id {
+        let membership = get_business_user_membership(&pool, business_account.id, user_id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user membership".to_string(),
+                    e,
+                )
+            })?
+            .ok_or_else(|| {
+                GenericError::DataNotFound("User is not associated with the business".to_string())
+            })?;
+        let now = Utc::now();
+        leave_type_list.retain(|a| a.ineligibility_reason(&membership, now).is_none());
+    }
 
     Ok(web::Json(GenericResponse::success(
         "sucessfully fetched leave type",
@@ -347,6 +407,115 @@ pub async fn leave_group_create_req(
     )))
 }
 
+#[utoipa::path(
+    post,
+    description = "API for cloning a leave group along with its user leave allocations to a new period",
+    tag = "Leave",
+    summary = "Leave Group Clone API",
+    path = "/leave/group/clone",
+    request_body(content = LeaveGroupCloneRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully cloned leave group", body= GenericResponse<LeaveGroupCloneResult>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave group clone request", skip(pool), fields())]
+pub async fn leave_group_clone_req(
+    pool: web::Data<PgPool>,
+    req: LeaveGroupCloneRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<LeaveGroupCloneResult>>, GenericError> {
+    if req.start_date >= req.end_date {
+        return Err(GenericError::ValidationError(
+            "Start date must be before end date".to_string(),
+        ));
+    }
+    let source_group_id_list = vec![req.source_group_id];
+    let source_group_list = get_leave_group(
+        &pool,
+        business_account.id,
+        Some(&source_group_id_list),
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave group".to_string(),
+            e,
+        )
+    })?;
+    let source_group = source_group_list.first().ok_or(GenericError::DataNotFound(
+        "Leave group not found.".to_string(),
+    ))?;
+    if req.carry_forward && req.start_date < source_group.end_date {
+        return Err(GenericError::ValidationError(
+            "Leave group must start after the source leave group ends to carry forward".to_string(),
+        ));
+    }
+    let group_req = LeaveGroupCreationRequest {
+        start_date: req.start_date,
+        end_date: req.end_date,
+        label: req.label.to_owned(),
+        id: None,
+    };
+    leave_group_create_validation(&pool, &group_req, business_account.id).await?;
+    let group_id = save_leave_group(&pool, &group_req, business_account.id, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave group".to_string(),
+                e,
+            )
+        })?;
+    let target_group = LeaveGroup {
+        id: group_id,
+        label: group_req.label,
+        start_date: group_req.start_date,
+        end_date: group_req.end_date,
+    };
+    let clone_result = match populate_cloned_leave_group(
+        &pool,
+        business_account.id,
+        source_group,
+        &target_group,
+        req.allocation_change.as_ref(),
+        req.carry_forward,
+        user.id,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            // The user leaves saved so far are deleted along with the group
+            if let Err(err) = delete_leave_group(&pool, group_id).await {
+                tracing::error!("Failed to delete partially cloned leave group: {:?}", err);
+            }
+            return Err(GenericError::DatabaseError(
+                "Something went wrong while cloning leave group".to_string(),
+                e,
+            ));
+        }
+    };
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully cloned leave group",
+        clone_result,
+    )))
+}
+
 #[utoipa::path(
     delete,
     description = "API for deleting leave group",
@@ -465,7 +634,7 @@ pub async fn leave_group_list_req(
     path = "/leave/user/allocate",
     request_body(content = CreateLeaveUserAssociationRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully associated user to leave", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully associated user to leave", body= GenericResponse<Vec<UserLeaveAllocation>>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -482,10 +651,10 @@ pub async fn leave_group_list_req(
 #[tracing::instrument(err, name = "User Leave Association request", skip(pool), fields())]
 pub async fn create_leave_user_association_req(
     pool: web::Data<PgPool>,
-    data: CreateLeaveUserAssociationRequest,
+    mut data: CreateLeaveUserAssociationRequest,
     user: UserAccount,
     business_account: BusinessAccount,
-) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+) -> Result<web::Json<GenericResponse<Vec<UserLeaveAllocation>>>, GenericError> {
     let group_id_list = vec![data.group_id];
     let leave_type_set: HashSet<Uuid> = data.data.iter().map(|x| x.type_id).collect();
     let leave_type_list: Vec<Uuid> = leave_type_set.iter().copied().collect();
@@ -519,30 +688,62 @@ pub async fn create_leave_user_association_req(
             "Leave type/s not found for given business".to_string(),
         ));
     }
-    save_user_leave(&pool, &data.data, data.user_id, leave_group.id, user.id)
+    let membership = get_business_user_membership(&pool, business_account.id, data.user_id)
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while saving user leave association".to_string(),
+                "Something went wrong while fetching user membership".to_string(),
                 e,
             )
         })?;
+    validate_leave_type_eligibility(
+        &leave_type.iter().collect::<Vec<_>>(),
+        membership.as_ref(),
+        Utc::now(),
+    )?;
+    let allocation_list = get_user_leave_allocation(
+        &pool,
+        business_account.id,
+        data.user_id,
+        leave_group,
+        &data.data,
+        &leave_type,
+    )
+    .await?;
+    for (item, allocation) in data.data.iter_mut().zip(allocation_list.iter()) {
+        item.count = allocation.allocated_count.clone();
+    }
+    save_user_leave(
+        &pool,
+        &data.data,
+        &[data.user_id],
+        leave_group.id,
+        user.id,
+        false,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while saving user leave association".to_string(),
+            e,
+        )
+    })?;
 
     Ok(web::Json(GenericResponse::success(
         "sucessfully associated user to leave",
-        (),
+        allocation_list,
     )))
 }
 
 #[utoipa::path(
     post,
-    description = "API for listing associating user to leave",
+    description = "API for previewing the leave counts allocated to a user, including pro-rata",
     tag = "Leave",
-    summary = "List Leave User Association API",
-    path = "/leave/user/allocation/list",
-    request_body(content = ListLeaveUserAssociationRequest, description = "Request Body"),
+    summary = "Leave User Allocation Preview API",
+    path = "/leave/user/allocate/preview",
+    request_body(content = CreateLeaveUserAssociationRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully fetched user leaves", body= GenericResponse<Vec<UserLeave>>),
+        (status=200, description= "sucessfully computed user leave allocation", body= GenericResponse<Vec<UserLeaveAllocation>>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -558,53 +759,73 @@ pub async fn create_leave_user_association_req(
 )]
 #[tracing::instrument(
     err,
-    name = "List User Leave Association request",
+    name = "User Leave Allocation Preview request",
     skip(pool),
     fields()
 )]
-pub async fn list_leave_user_association_req(
-    req: ListLeaveUserAssociationRequest,
+pub async fn leave_user_allocation_preview_req(
     pool: web::Data<PgPool>,
-    user: UserAccount,
+    data: CreateLeaveUserAssociationRequest,
     business_account: BusinessAccount,
-    permissions: AllowedPermission,
-) -> Result<web::Json<GenericResponse<Vec<UserLeave>>>, GenericError> {
-    let user_id = if !permissions
-        .permission_list
-        .contains(&PermissionType::ListUserLeave.to_string())
-    {
-        req.user_id.unwrap_or(user.id)
-    } else {
-        user.id
-    };
-    let data = fetch_user_leaves(
+) -> Result<web::Json<GenericResponse<Vec<UserLeaveAllocation>>>, GenericError> {
+    let group_id_list = vec![data.group_id];
+    let leave_type_set: HashSet<Uuid> = data.data.iter().map(|x| x.type_id).collect();
+    let leave_type_list: Vec<Uuid> = leave_type_set.iter().copied().collect();
+
+    let (leave_group_res, leave_type_res) = join!(
+        get_leave_group(
+            &pool,
+            business_account.id,
+            Some(&group_id_list),
+            None,
+            None,
+            None
+        ),
+        get_leave_type(
+            &pool,
+            business_account.id,
+            Some(leave_type_list.to_vec()),
+            None,
+            None
+        ),
+    );
+    let leave_group_list =
+        leave_group_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let leave_group = leave_group_list.first().ok_or(GenericError::DataNotFound(
+        "Leave group not found.".to_string(),
+    ))?;
+    let leave_type = leave_type_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let allowed_leave_type_set: HashSet<Uuid> = leave_type.iter().map(|x| x.id).collect();
+    if !allowed_leave_type_set.is_superset(&leave_type_set) {
+        return Err(GenericError::DataNotFound(
+            "Leave type/s not found for given business".to_string(),
+        ));
+    }
+    let allocation_list = get_user_leave_allocation(
         &pool,
         business_account.id,
-        user_id,
-        Some(req.group_id),
-        None,
+        data.user_id,
+        leave_group,
+        &data.data,
+        &leave_type,
     )
-    .await
-    .map_err(|e| {
-        GenericError::DatabaseError(
-            "Something went wrong while fetching user leave association".to_string(),
-            e,
-        )
-    })?;
+    .await?;
+
     Ok(web::Json(GenericResponse::success(
-        "sucessfully fetched user leaves",
-        data,
+        "sucessfully computed user leave allocation",
+        allocation_list,
     )))
 }
 
 #[utoipa::path(
-    delete,
-    description = "API for delete associated user to leave",
+    post,
+    description = "API for allocating leave to all verified members of a business, departments or a role",
     tag = "Leave",
-    summary = "Delete Leave User Association API",
-    path = "/leave/user/allocation/delete",
+    summary = "Bulk Leave User Association API",
+    path = "/leave/user/allocate/bulk",
+    request_body(content = BulkLeaveUserAssociationRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully deleted user leaves", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully associated users to leave", body= GenericResponse<BulkUserLeaveAllocationResult>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -616,46 +837,144 @@ pub async fn list_leave_user_association_req(
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
         ("x-business-id" = String, Header, description = "id of business_account"),
-        ("id" = String, Path, description = "Leave User ID"),
       )
 )]
 #[tracing::instrument(
     err,
-    name = "Delete User Leave Association request",
+    name = "Bulk User Leave Association request",
     skip(pool),
     fields()
 )]
-pub async fn delete_leave_user_association_req(
-    path: web::Path<Uuid>,
+pub async fn bulk_create_leave_user_association_req(
     pool: web::Data<PgPool>,
+    data: BulkLeaveUserAssociationRequest,
     user: UserAccount,
     business_account: BusinessAccount,
-    permissions: AllowedPermission,
-) -> Result<web::Json<GenericResponse<()>>, GenericError> {
-    let leave_type_id = path.into_inner();
-    delete_user_leave(&pool, leave_type_id, business_account.id)
+) -> Result<web::Json<GenericResponse<BulkUserLeaveAllocationResult>>, GenericError> {
+    if data.data.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Leave allocation data cannot be empty".to_string(),
+        ));
+    }
+    if data.data.iter().any(|a| a.pro_rata.is_some()) {
+        return Err(GenericError::ValidationError(
+            "Pro-rata allocation is not supported for bulk allocation".to_string(),
+        ));
+    }
+    let group_id_list = vec![data.group_id];
+    let leave_type_set: HashSet<Uuid> = data.data.iter().map(|x| x.type_id).collect();
+    if leave_type_set.len() != data.data.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate leave types found in allocation data".to_string(),
+        ));
+    }
+    let leave_type_list: Vec<Uuid> = leave_type_set.iter().copied().collect();
+
+    let (leave_group_res, leave_type_res, user_id_res) = join!(
+        get_leave_group(
+            &pool,
+            business_account.id,
+            Some(&group_id_list),
+            None,
+            None,
+            None
+        ),
+        get_leave_type(
+            &pool,
+            business_account.id,
+            Some(leave_type_list.to_vec()),
+            None,
+            None
+        ),
+        fetch_business_user_id_list(
+            &pool,
+            business_account.id,
+            data.department_id_list.as_ref(),
+            data.role_id
+        ),
+    );
+    let leave_group_list =
+        leave_group_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let leave_group = leave_group_list.first().ok_or(GenericError::DataNotFound(
+        "Leave group not found.".to_string(),
+    ))?;
+    let leave_type = leave_type_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let allowed_leave_type_set: HashSet<Uuid> = leave_type.iter().map(|x| x.id).collect();
+    if !allowed_leave_type_set.is_superset(&leave_type_set) {
+        return Err(GenericError::DataNotFound(
+            "Leave type/s not found for given business".to_string(),
+        ));
+    }
+    let user_id_list = user_id_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching business users".to_string(),
+            e,
+        )
+    })?;
+    let membership_map =
+        get_business_user_membership_map(&pool, business_account.id, &user_id_list)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user membership".to_string(),
+                    e,
+                )
+            })?;
+    // Leave types open to the same users are saved together, the ineligible users end up in
+    // the skipped list.
+    let now = Utc::now();
+    let mut allocation_map: BTreeMap<Vec<Uuid>, Vec<UserLeaveCreationData>> = BTreeMap::new();
+    for item in data.data.iter() {
+        let Some(item_leave_type) = leave_type.iter().find(|a| a.id == item.type_id) else {
+            continue;
+        };
+        let (eligible_user_id_list, _) =
+            partition_eligible_user_id_list(item_leave_type, &user_id_list, &membership_map, now);
+        allocation_map
+            .entry(eligible_user_id_list)
+            .or_default()
+            .push(UserLeaveCreationData {
+                type_id: item.type_id,
+                count: item.count.clone(),
+                status: item.status.clone(),
+                pro_rata: None,
+            });
+    }
+    let mut created_list = vec![];
+    for (eligible_user_id_list, allocation_data) in allocation_map.iter() {
+        let saved_list = save_user_leave(
+            &pool,
+            allocation_data,
+            eligible_user_id_list,
+            leave_group.id,
+            user.id,
+            true,
+        )
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while deleting user leaves".to_string(),
+                "Something went wrong while saving user leave association".to_string(),
                 e,
             )
         })?;
+        created_list.extend(saved_list);
+    }
+
     Ok(web::Json(GenericResponse::success(
-        "sucessfully deleted user leaves",
-        (),
+        "sucessfully associated users to leave",
+        build_bulk_user_leave_allocation_result(&user_id_list, &data.data, &created_list),
     )))
 }
 
 #[utoipa::path(
     post,
-    description = "API for making a leave request",
+    description = "API for listing associating user to leave",
     tag = "Leave",
-    summary = "Leave Request Creation API",
-    path = "/leave/request/create",
-    request_body(content = CreateLeaveRequest, description = "Request Body"),
+    summary = "List Leave User Association API",
+    path = "/leave/user/allocation/list",
+    request_body(content = ListLeaveUserAssociationRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully created leave request", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully fetched user leaves", body= GenericResponse<Vec<UserLeave>>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -666,12 +985,323 @@ pub async fn delete_leave_user_association_req(
         ("Authorization" = String, Header, description = "JWT token"),
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
       )
 )]
 #[tracing::instrument(
     err,
-    name = "Leave Request Creation API",
-    skip(pool, body, producer_client),
+    name = "List User Leave Association request",
+    skip(pool),
+    fields()
+)]
+pub async fn list_leave_user_association_req(
+    req: ListLeaveUserAssociationRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    permissions: AllowedPermission,
+) -> Result<web::Json<GenericResponse<Vec<UserLeave>>>, GenericError> {
+    let user_id = if !permissions
+        .permission_list
+        .contains(&PermissionType::ListUserLeave.to_string())
+    {
+        req.user_id.unwrap_or(user.id)
+    } else {
+        user.id
+    };
+    let data = fetch_user_leaves(
+        &pool,
+        business_account.id,
+        user_id,
+        Some(req.group_id),
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user leave association".to_string(),
+            e,
+        )
+    })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched user leaves",
+        data,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing the leave ledger of a user for a leave group. Users without the leave type creation permission can only fetch their own ledger.",
+    tag = "Leave",
+    summary = "User Leave Ledger List API",
+    path = "/leave/user/ledger",
+    request_body(content = ListLeaveUserAssociationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched user leave ledger", body= GenericResponse<Vec<UserLeaveLedger>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "List User Leave Ledger request", skip(pool), fields())]
+pub async fn leave_ledger_list_req(
+    req: ListLeaveUserAssociationRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    permissions: AllowedPermission,
+) -> Result<web::Json<GenericResponse<Vec<UserLeaveLedger>>>, GenericError> {
+    let user_id = if permissions
+        .permission_list
+        .contains(&PermissionType::CreateLeaveType.to_string())
+    {
+        req.user_id.unwrap_or(user.id)
+    } else {
+        user.id
+    };
+    let user_leave_list = fetch_user_leaves(
+        &pool,
+        business_account.id,
+        user_id,
+        Some(req.group_id),
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user leave association".to_string(),
+            e,
+        )
+    })?;
+    let user_leave_id_list: Vec<Uuid> = user_leave_list.iter().map(|a| a.id).collect();
+    let entry_list = get_leave_ledger(&pool, &user_leave_id_list)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave ledger".to_string(),
+                e,
+            )
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched user leave ledger",
+        build_user_leave_ledger(user_leave_list, entry_list),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for crediting or debiting the allocated or used count of a user leave. Every adjustment is recorded in the leave ledger along with the reason.",
+    tag = "Leave",
+    summary = "User Leave Balance Adjustment API",
+    path = "/leave/user/adjust",
+    request_body(content = LeaveBalanceAdjustmentRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully adjusted user leave balance", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "User Leave Balance Adjustment API",
+    skip(pool, producer_client),
+    fields()
+)]
+pub async fn leave_balance_adjustment_req(
+    body: LeaveBalanceAdjustmentRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let user_leave = fetch_user_leaves(
+        &pool,
+        business_account.id,
+        body.user_id,
+        None,
+        Some(body.user_leave_id),
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching  user leave data".to_string(),
+            e,
+        )
+    })?
+    .into_iter()
+    .next()
+    .ok_or_else(|| GenericError::DataNotFound("User leave not found.".to_string()))?;
+    validate_leave_balance_adjustment(&body, &user_leave)?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let (entry, update_res) = match body.target {
+        LeaveBalanceAdjustmentTarget::Allocated => (
+            LeaveLedgerEntryData {
+                user_leave_id: user_leave.id,
+                entry_type: LeaveLedgerEntryType::Adjustment,
+                allocated_change: body.amount.clone(),
+                used_change: BigDecimal::default(),
+                leave_id: None,
+                reason: Some(body.reason.trim()),
+            },
+            update_user_leave_allocation(&mut transaction, user_leave.id, &body.amount, user.id)
+                .await,
+        ),
+        LeaveBalanceAdjustmentTarget::Used => (
+            LeaveLedgerEntryData {
+                user_leave_id: user_leave.id,
+                entry_type: LeaveLedgerEntryType::Adjustment,
+                allocated_change: BigDecimal::default(),
+                used_change: body.amount.clone(),
+                leave_id: None,
+                reason: Some(body.reason.trim()),
+            },
+            update_user_leave_count(&mut transaction, user_leave.id, &body.amount, user.id).await,
+        ),
+    };
+    update_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while updating leave count".to_string(),
+            e,
+        )
+    })?;
+    save_leave_ledger(&mut transaction, &[entry], user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave ledger".to_string(),
+                e,
+            )
+        })?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to adjust leave balance.")?;
+
+    // Crediting the allocation or debiting the usage both add to the remaining balance
+    let is_credit = (body.amount > BigDecimal::default())
+        == (body.target == LeaveBalanceAdjustmentTarget::Allocated);
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::LeaveBalanceAdjustment,
+        ProcessType::Deferred,
+        vec![user_leave.user_id],
+        format!(
+            "{} {} {} {} leave: {}",
+            user.display_name,
+            if is_credit { "credited" } else { "debited" },
+            body.amount.abs(),
+            user_leave.leave_type.label,
+            body.reason.trim()
+        ),
+        Some(business_account.id),
+        &producer_client,
+    )
+    .await;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully adjusted user leave balance",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for delete associated user to leave",
+    tag = "Leave",
+    summary = "Delete Leave User Association API",
+    path = "/leave/user/allocation/delete",
+    responses(
+        (status=200, description= "sucessfully deleted user leaves", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Leave User ID"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Delete User Leave Association request",
+    skip(pool),
+    fields()
+)]
+pub async fn delete_leave_user_association_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    permissions: AllowedPermission,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let leave_type_id = path.into_inner();
+    delete_user_leave(&pool, leave_type_id, business_account.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting user leaves".to_string(),
+                e,
+            )
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted user leaves",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for making a leave request",
+    tag = "Leave",
+    summary = "Leave Request Creation API",
+    path = "/leave/request/create",
+    request_body(content = CreateLeaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully created leave request", body= GenericResponse<LeaveRequestCreationResult>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Request Creation API",
+    skip(pool, body, producer_client),
     fields()
 )]
 #[allow(clippy::too_many_arguments)]
@@ -684,7 +1314,7 @@ pub async fn create_leave_req(
     permissions: AllowedPermission,
     websocket_srv: web::Data<Addr<Server>>,
     producer_client: web::Data<PulsarClient>,
-) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+) -> Result<web::Json<GenericResponse<LeaveRequestCreationResult>>, GenericError> {
     if body.user_id.is_some()
         && !permissions
             .permission_list
@@ -705,26 +1335,146 @@ pub async fn create_leave_req(
         SettingKey::EmailAppPassword.to_string(),
         SettingKey::LeaveRequestTemplate.to_string(),
     ];
-    let (config_res, reciever_account_res, user_leave_res) = join!(
-        get_setting_value(&pool, &setting_keys, None, Some(user.id), true),
-        get_user(vec![body.to.get()], &pool),
-        // get_leave_type(&pool, business.id, Some(vec![body.r#type]), None, None),
-        // get_leave_group(&pool, business.id, None, None, Some(Utc::now()), Some(Utc::now())),
-        fetch_user_leaves(&pool, business.id, user_id, None, Some(body.user_leave_id)),
-    );
-    let user_leave_list =
-        user_leave_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
-    let user_leave = user_leave_list.first().ok_or_else(|| {
-        GenericError::DataNotFound(
-            "No Leave is added for the user for given group and type is not set fot user"
-                .to_string(),
-        )
+    let approver_id = match &body.to {
+        Some(to) => {
+            let override_permission_list = validate_user_business_permission(
+                &pool,
+                user.id,
+                business.id,
+                &vec![PermissionType::OverrideLeaveApprover.to_string()],
+            )
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user permission".to_string(),
+                    e,
+                )
+            })?;
+            if override_permission_list.is_empty() {
+                return Err(GenericError::InsufficientPrevilegeError(
+                    "You don't have sufficient previlege to choose the approver of a leave request"
+                        .to_string(),
+                ));
+            }
+            let approver = get_user(vec![to.get()], &pool)
+                .await
+                .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?
+                .ok_or(GenericError::DataNotFound("User not found.".to_string()))?;
+            let approver_id_list = fetch_user_id_list_with_business_permission(
+                &pool,
+                business.id,
+                &vec![approver.id],
+                &PermissionType::ApproveLeaveRequest.to_string(),
+            )
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user permission".to_string(),
+                    e,
+                )
+            })?;
+            if pick_leave_approver(user_id, &[Some(approver.id)], &approver_id_list).is_none() {
+                return Err(GenericError::ValidationError(
+                    "Provided user is not allowed to approve leave requests".to_string(),
+                ));
+            }
+            approver.id
+        }
+        None => resolve_leave_approver(&pool, business.id, user_id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while resolving leave approver".to_string(),
+                    e,
+                )
+            })?
+            .ok_or_else(|| {
+                GenericError::ValidationError(
+                    "No approver found, please set a department head, reporting manager or the leave fallback approver".to_string(),
+                )
+            })?,
+    };
+    let approver_id = approver_id.to_string();
+    let (config_res, reciever_account_res, user_leave_res) = join!(
+        get_setting_value(&pool, &setting_keys, None, Some(user.id), true),
+        get_user(vec![&approver_id], &pool),
+        // get_leave_type(&pool, business.id, Some(vec![body.r#type]), None, None),
+        // get_leave_group(&pool, business.id, None, None, Some(Utc::now()), Some(Utc::now())),
+        fetch_user_leaves(&pool, business.id, user_id, None, Some(body.user_leave_id)),
+    );
+    let user_leave_list =
+        user_leave_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let user_leave = user_leave_list.first().ok_or_else(|| {
+        GenericError::DataNotFound(
+            "No Leave is added for the user for given group and type is not set fot user"
+                .to_string(),
+        )
     })?;
     // .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
     let configs = config_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
 
-    validate_leave_request_creation(&body, user_leave)
+    let leave_date_list: Vec<NaiveDate> = body.leave_data.iter().map(|a| a.date).collect();
+    let (holiday_res, location_res) = join!(
+        get_holiday(
+            &pool,
+            business.id,
+            None,
+            body.leave_range
+                .as_ref()
+                .map_or(Some(&leave_date_list), |_| None),
+            body.leave_range.as_ref().map(|a| a.start_date),
+            body.leave_range.as_ref().map(|a| a.end_date),
+        ),
+        get_user_business_location(&pool, business.id, user_id),
+    );
+    let holiday_list = holiday_res.map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching holiday".to_string(), e)
+    })?;
+    let location = location_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user location".to_string(),
+            e,
+        )
+    })?;
+    let leave_data = get_leave_request_data(&body, &holiday_list, location.as_ref())
         .map_err(|e| GenericError::ValidationError(e.to_string()))?;
+    validate_leave_request_creation(&leave_data, user_leave, &holiday_list, location.as_ref())
+        .map_err(|e| GenericError::ValidationError(e.to_string()))?;
+    validate_leave_request_rules(
+        &pool,
+        business.id,
+        user_id,
+        user.id,
+        user_leave,
+        &leave_data,
+        body.attachment_url.as_deref(),
+        location.as_ref(),
+        None,
+    )
+    .await?;
+    let (approval_stage_res, auto_approval_rule_res) = join!(
+        get_leave_approval_stages(&pool, business.id, Some(user_leave.leave_type.id), true),
+        get_leave_auto_approval_rules(&pool, business.id, Some(user_leave.leave_type.id)),
+    );
+    let approval_stage_list = approval_stage_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave approval stage".to_string(),
+            e,
+        )
+    })?;
+    let auto_approval_rule_list = auto_approval_rule_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave auto approval rule".to_string(),
+            e,
+        )
+    })?;
+    let auto_approval_rule = find_leave_auto_approval_rule(
+        &auto_approval_rule_list,
+        &leave_data,
+        user_leave,
+        Utc::now().date_naive(),
+    )
+    .cloned();
 
     let email_password = configs
         .get_setting(&SettingKey::EmailAppPassword.to_string())
@@ -753,94 +1503,2157 @@ pub async fn create_leave_req(
         .begin()
         .await
         .context("Failed to acquire a Postgres connection from the pool")?;
-    if save_leave_request(
+    let leave_id_list = save_leave_request(
         &mut transaction,
         &body,
+        &leave_data,
         user_leave.id,
         user.id,
         reciever_account.id,
         message_id.as_deref(),
     )
     .await
-    .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?
-    {
-        if body.send_mail {
-            let html_template: String = configs
-                .get_setting(&SettingKey::LeaveRequestTemplate.to_string())
-                .ok_or_else(|| {
-                    GenericError::DataNotFound(format!(
-                        "Please set the {}",
-                        SettingKey::LeaveRequestTemplate
-                    ))
-                })?;
-            let receiver = to_title_case(&reciever_account.display_name);
-            let sender = to_title_case(&user.display_name);
-            let reason = body.reason.unwrap_or("NA".to_string());
-            let context_data = LeaveRequestEmailContext::new(
-                &sender,
-                body.leave_data.iter().map(|a| a.date.to_string()).collect(),
-                &reason,
-                &receiver,
-                &user_leave.leave_type.label,
-            );
-            let context =
-                TeraContext::from_serialize(&context_data).map_err(|e: tera::Error| {
-                    tracing::error!("{}", e);
-                    GenericError::UnexpectedCustomError(
-                        "Something went wrong while rendering the email html data".to_string(),
-                    )
-                })?;
-            let rendered_string = Tera::one_off(&html_template, &context, true).map_err(|e| {
-                tracing::error!("Error while rendering html {} error: {}", html_template, e);
-                GenericError::UnexpectedCustomError(
-                    "Something went wrong while rendering the email html data".to_string(),
-                )
-            })?;
-            personal_email_client
-                .send_html_email(
-                    &body.to,
-                    &body.cc,
-                    &format!("Request for {} leave", &user_leave.leave_type.label),
-                    rendered_string,
-                    message_id,
-                    None,
-                )
-                .await
-                .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
-        }
-
-        let _ = send_notification(
-            &pool,
-            &websocket_srv,
-            WebSocketActionType::LeaveRequest,
-            ProcessType::Deferred,
-            vec![reciever_account.id],
-            format!("Leave Request send by {}", user.display_name),
-            Some(business.id),
-            &producer_client,
+    .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let mut approved_date_list = vec![];
+    if !leave_id_list.is_empty() {
+        match &auto_approval_rule {
+            Some(rule) => {
+                approved_date_list = auto_approve_leave_request(
+                    &mut transaction,
+                    &leave_id_list,
+                    user_leave.id,
+                    rule,
+                    user.id,
+                )
+                .await
+                .map_err(|e| {
+                    GenericError::DatabaseError(
+                        "Something went wrong while auto approving leave request".to_string(),
+                        e,
+                    )
+                })?;
+            }
+            None => {
+                save_leave_approval(&mut transaction, &leave_id_list, &approval_stage_list)
+                    .await
+                    .map_err(|e| {
+                        GenericError::DatabaseError(
+                            "Something went wrong while saving leave approval".to_string(),
+                            e,
+                        )
+                    })?;
+            }
+        }
+        if body.send_mail {
+            let html_template: String = configs
+                .get_setting(&SettingKey::LeaveRequestTemplate.to_string())
+                .ok_or_else(|| {
+                    GenericError::DataNotFound(format!(
+                        "Please set the {}",
+                        SettingKey::LeaveRequestTemplate
+                    ))
+                })?;
+            let receiver = to_title_case(&reciever_account.display_name);
+            let sender = to_title_case(&user.display_name);
+            let reason = body.reason.unwrap_or("NA".to_string());
+            let date_range = body.leave_range.as_ref().map(|a| a.label());
+            let dates = match &date_range {
+                Some(label) => vec![label.to_owned()],
+                None => leave_data.iter().map(|a| a.date.to_string()).collect(),
+            };
+            let context_data = LeaveRequestEmailContext::new(
+                &sender,
+                dates,
+                date_range,
+                &reason,
+                &receiver,
+                &user_leave.leave_type.label,
+            );
+            let context =
+                TeraContext::from_serialize(&context_data).map_err(|e: tera::Error| {
+                    tracing::error!("{}", e);
+                    GenericError::UnexpectedCustomError(
+                        "Something went wrong while rendering the email html data".to_string(),
+                    )
+                })?;
+            let rendered_string = Tera::one_off(&html_template, &context, true).map_err(|e| {
+                tracing::error!("Error while rendering html {} error: {}", html_template, e);
+                GenericError::UnexpectedCustomError(
+                    "Something went wrong while rendering the email html data".to_string(),
+                )
+            })?;
+            personal_email_client
+                .send_html_email(
+                    &reciever_account.email,
+                    &body.cc,
+                    &format!("Request for {} leave", &user_leave.leave_type.label),
+                    rendered_string,
+                    message_id,
+                    None,
+                )
+                .await
+                .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+        }
+
+        let _ = send_notification(
+            &pool,
+            &websocket_srv,
+            WebSocketActionType::LeaveRequest,
+            ProcessType::Deferred,
+            vec![reciever_account.id],
+            format!("Leave Request send by {}", user.display_name),
+            Some(business.id),
+            &producer_client,
+        )
+        .await;
+        // .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+    }
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to store a new user account.")?;
+    if !leave_id_list.is_empty() {
+        match &auto_approval_rule {
+            Some(rule) => {
+                schedule_leave_alert(&producer_client, &approved_date_list)
+                    .await
+                    .map_err(GenericError::UnexpectedError)?;
+                let _ = send_notification(
+                    &pool,
+                    &websocket_srv,
+                    WebSocketActionType::LeaveRequestStatusUpdation,
+                    ProcessType::Deferred,
+                    vec![user_id],
+                    format!("Leave Request auto approved by rule {}", rule.label),
+                    Some(business.id),
+                    &producer_client,
+                )
+                .await;
+            }
+            None => {
+                let date_list: Vec<NaiveDate> = leave_data.iter().map(|a| a.date).collect();
+                if let Err(e) = schedule_leave_reminder(
+                    &pool,
+                    &producer_client,
+                    business.id,
+                    &leave_id_list,
+                    &date_list,
+                )
+                .await
+                {
+                    tracing::error!("Failed to schedule leave reminder: {:?}", e);
+                }
+            }
+        }
+    }
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully created leave request",
+        LeaveRequestCreationResult {
+            status: if auto_approval_rule.is_some() {
+                LeaveStatus::Approved
+            } else {
+                LeaveStatus::Requested
+            },
+            auto_approval_rule,
+        },
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for making a deleting leave request",
+    tag = "Leave",
+    summary = "Leave Request Deletion API",
+    path = "/leave/request/delete",
+    responses(
+        (status=200, description= "sucessfully deleted leave request", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("id" = String, Path, description = "Leave ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave Request Deletion Request", skip(pool), fields())]
+pub async fn leave_request_deletion_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    mail_config: web::Data<EmailClientConfig>,
+    // permissions: AllowedPermission,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let leave_id = path.into_inner();
+
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(leave_id));
+    let leave = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+
+    if leave.user_id != user.id {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You don't have previlege to delete other user's leaves".to_string(),
+        ));
+    };
+    if leave.status == LeaveStatus::Approved || leave.status == LeaveStatus::CancellationRequested {
+        return Err(GenericError::ValidationError(
+            "The leave is already approved, pls reject the leave request before deletion"
+                .to_string(),
+        ));
+    }
+    delete_leave(&pool, leave_id, user.id).await.map_err(|_| {
+        GenericError::UnexpectedCustomError("something went wrong while deleting leave".to_string())
+    })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave request",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for requesting the cancellation of an approved leave which is yet to be taken. The leave stays counted as used until the approver confirms the cancellation by moving it to cancelled, or declines it by moving it back to approved.",
+    tag = "Leave",
+    summary = "Leave Cancellation Request API",
+    path = "/leave/request/cancel/{id}",
+    responses(
+        (status=200, description= "sucessfully requested leave cancellation", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("id" = String, Path, description = "Leave ID"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Cancellation Request API",
+    skip(pool, producer_client),
+    fields()
+)]
+pub async fn leave_cancellation_request_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business: BusinessAccount,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let leave_id = path.into_inner();
+
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(leave_id));
+    let leave = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+
+    if leave.user_id != user.id {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You don't have previlege to cancel other user's leaves".to_string(),
+        ));
+    };
+    if leave.status != LeaveStatus::Approved {
+        return Err(GenericError::ValidationError(
+            "Only approved leaves can be requested for cancellation".to_string(),
+        ));
+    }
+    if leave.date.date_naive() < Utc::now().date_naive() {
+        return Err(GenericError::ValidationError(
+            "The leave is already taken and cannot be cancelled".to_string(),
+        ));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    update_leave_request_status(
+        &mut transaction,
+        leave.id,
+        &LeaveStatus::CancellationRequested,
+        user.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while updating leave request".to_string(),
+            e,
+        )
+    })?;
+
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::LeaveRequest,
+        ProcessType::Deferred,
+        vec![leave.receiver_id],
+        format!(
+            "Leave cancellation for {} requested by {}",
+            leave.date.date_naive(),
+            user.display_name
+        ),
+        Some(business.id),
+        &producer_client,
+    )
+    .await;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to request leave cancellation.")?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully requested leave cancellation",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for modifying the date, period or reason of a leave request yet to be acted upon. The change is re-validated and sent as a reply on the original email thread.",
+    tag = "Leave",
+    summary = "Leave Request Modification API",
+    path = "/leave/request/update",
+    request_body(content = UpdateLeaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully modified leave request", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Request Modification API",
+    skip(pool, body, producer_client),
+    fields()
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn leave_request_update_req(
+    body: UpdateLeaveRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business: BusinessAccount,
+    mail_config: web::Data<EmailClientConfig>,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(body.id));
+    let leave = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+
+    if leave.user_id != user.id {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You don't have previlege to modify other user's leaves".to_string(),
+        ));
+    };
+    if leave.status != LeaveStatus::Requested {
+        return Err(GenericError::ValidationError(
+            "Only leave requests yet to be acted upon can be modified".to_string(),
+        ));
+    }
+    let current_date = leave.date.date_naive();
+    let date = body.date.unwrap_or(current_date);
+    let period_id = body.period_id.unwrap_or(leave.period.id);
+    let reason = body.reason.or_else(|| leave.reason.clone());
+    if date == current_date && period_id == leave.period.id && reason == leave.reason {
+        return Err(GenericError::ValidationError(
+            "Nothing to modify in the leave request".to_string(),
+        ));
+    }
+
+    let date_list = vec![date];
+    let (user_leave_res, holiday_res, location_res) = join!(
+        fetch_user_leaves(
+            &pool,
+            business.id,
+            leave.user_id,
+            None,
+            Some(leave.user_leave_id)
+        ),
+        get_holiday(&pool, business.id, None, Some(&date_list), None, None),
+        get_user_business_location(&pool, business.id, leave.user_id),
+    );
+    let user_leave = user_leave_res
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching  user leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            GenericError::DataNotFound("Provided Leave Request not found in database".to_string())
+        })?;
+    let holiday_list = holiday_res.map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching holiday".to_string(), e)
+    })?;
+    let location = location_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user location".to_string(),
+            e,
+        )
+    })?;
+    let leave_data = vec![CreateLeaveData { period_id, date }];
+    validate_leave_request_creation(&leave_data, &user_leave, &holiday_list, location.as_ref())
+        .map_err(|e| GenericError::ValidationError(e.to_string()))?;
+    if date != current_date {
+        validate_leave_request_rules(
+            &pool,
+            business.id,
+            leave.user_id,
+            user.id,
+            &user_leave,
+            &leave_data,
+            leave.attachment_url.as_deref(),
+            location.as_ref(),
+            Some(leave.id),
+        )
+        .await?;
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let is_updated = update_leave_request(
+        &mut transaction,
+        leave.id,
+        date,
+        period_id,
+        reason.as_deref(),
+        user.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while updating leave request".to_string(),
+            e,
+        )
+    })?;
+    if !is_updated {
+        return Err(GenericError::ValidationError(format!(
+            "A leave request for the same period already exists on {}",
+            date
+        )));
+    }
+
+    let setting_key_list = vec![
+        SettingKey::EmailAppPassword.to_string(),
+        SettingKey::LeaveRequestTemplate.to_string(),
+    ];
+    let reciever_id = leave.receiver_id.to_string();
+    let (config_res, reciever_account_res) = join!(
+        get_setting_value(&pool, &setting_key_list, None, Some(user.id), true),
+        get_user(vec![&reciever_id], &pool),
+    );
+    let configs = config_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let reciever_account = reciever_account_res
+        .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?
+        .ok_or(GenericError::DataNotFound("User not found.".to_string()))?;
+
+    if leave.email_message_id.is_some() && user.is_vector_verified(&VectorType::Email) {
+        let html_template: String = configs
+            .get_setting(&SettingKey::LeaveRequestTemplate.to_string())
+            .ok_or_else(|| {
+                GenericError::DataNotFound(format!(
+                    "Please set the {}",
+                    SettingKey::LeaveRequestTemplate
+                ))
+            })?;
+        let receiver = to_title_case(&reciever_account.display_name);
+        let sender = to_title_case(&user.display_name);
+        let reason = reason.clone().unwrap_or("NA".to_string());
+        let context_data = LeaveRequestEmailContext::new(
+            &sender,
+            vec![date.to_string()],
+            None,
+            &reason,
+            &receiver,
+            &leave.leave_type,
+        );
+        let context = TeraContext::from_serialize(&context_data).map_err(|e: tera::Error| {
+            tracing::error!("{}", e);
+            GenericError::UnexpectedCustomError(
+                "Something went wrong while rendering the email html data".to_string(),
+            )
+        })?;
+        let rendered_string = Tera::one_off(&html_template, &context, true).map_err(|e| {
+            tracing::error!("Error while rendering html {} error: {}", html_template, e);
+            GenericError::UnexpectedCustomError(
+                "Something went wrong while rendering the email html data".to_string(),
+            )
+        })?;
+        let email_password = configs
+            .get_setting(&SettingKey::EmailAppPassword.to_string())
+            .ok_or_else(|| {
+                GenericError::DataNotFound(format!(
+                    "Please set the {}",
+                    SettingKey::EmailAppPassword
+                ))
+            })?;
+
+        let personal_email_client = SmtpEmailClient::new_personal(
+            &user.email,
+            SecretString::from(email_password.as_ref()),
+            &mail_config.personal.base_url,
+        )
+        .unwrap();
+        personal_email_client
+            .send_html_email(
+                &reciever_account.email,
+                &leave.cc,
+                &format!("Request for {} leave", leave.leave_type),
+                rendered_string,
+                leave.email_message_id.clone(),
+                leave.email_message_id.clone(),
+            )
+            .await
+            .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+    }
+
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::LeaveRequest,
+        ProcessType::Deferred,
+        vec![reciever_account.id],
+        format!("Leave Request modified by {}", user.display_name),
+        Some(business.id),
+        &producer_client,
+    )
+    .await;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to modify leave request.")?;
+    // Reminders already sent are recorded, so only the expiry of the new date takes effect
+    if date != current_date {
+        let schedule_res =
+            schedule_leave_reminder(&pool, &producer_client, business.id, &[leave.id], &[date])
+                .await;
+        if let Err(e) = schedule_res {
+            tracing::error!("Failed to schedule leave reminder: {:?}", e);
+        }
+    }
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully modified leave request",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for commenting on a leave request. The requester, the receiver and the cc'd users take part in the comment thread and are notified of every new comment.",
+    tag = "Leave",
+    summary = "Leave Comment Creation API",
+    path = "/leave/request/comment/create",
+    request_body(content = LeaveCommentCreationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully created leave comment", body= GenericResponse<LeaveComment>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Comment Creation API",
+    skip(pool, body, producer_client),
+    fields()
+)]
+pub async fn leave_comment_create_req(
+    body: LeaveCommentCreationRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business: BusinessAccount,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<LeaveComment>>, GenericError> {
+    let comment = body.comment.trim();
+    if comment.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Comment cannot be empty".to_string(),
+        ));
+    }
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(body.leave_id));
+    let leave = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+    if !leave.is_participant(user.id, &user.email) {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You are not part of this leave request".to_string(),
+        ));
+    }
+    let created_on = Utc::now();
+    let id = save_leave_comment(&pool, leave.id, comment, user.id, created_on)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave comment".to_string(),
+                e,
+            )
+        })?;
+    let participant_id_list = get_leave_participant_id_list(&pool, &leave)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave participants".to_string(),
+                e,
+            )
+        })?;
+    let notified_user_id_list: Vec<Uuid> = participant_id_list
+        .into_iter()
+        .filter(|a| *a != user.id)
+        .collect();
+    if !notified_user_id_list.is_empty() {
+        let _ = send_notification(
+            &pool,
+            &websocket_srv,
+            WebSocketActionType::LeaveRequestComment,
+            ProcessType::Deferred,
+            notified_user_id_list,
+            format!(
+                "{} commented on the leave request for {}",
+                user.display_name,
+                leave.date.date_naive()
+            ),
+            Some(business.id),
+            &producer_client,
+        )
+        .await;
+    }
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully created leave comment",
+        LeaveComment {
+            id,
+            leave_id: leave.id,
+            comment: comment.to_string(),
+            created_by: user.id,
+            display_name: user.display_name,
+            created_on,
+        },
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for listing the comments on a leave request, oldest first",
+    tag = "Leave",
+    summary = "Leave Comment List API",
+    path = "/leave/request/comment/list/{id}",
+    responses(
+        (status=200, description= "sucessfully fetched leave comment", body= GenericResponse<Vec<LeaveComment>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business"),
+        ("id" = String, Path, description = "Leave ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave Comment List API", skip(pool), fields())]
+pub async fn leave_comment_list_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveComment>>>, GenericError> {
+    let leave_id = path.into_inner();
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(leave_id));
+    let leave = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+    if !leave.is_participant(user.id, &user.email) {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You are not part of this leave request".to_string(),
+        ));
+    }
+    let comment_list = get_leave_comments(&pool, leave.id).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave comment".to_string(),
+            e,
+        )
+    })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave comment",
+        comment_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting a leave comment, only the author of the comment is allowed to",
+    tag = "Leave",
+    summary = "Leave Comment Deletion API",
+    path = "/leave/request/comment/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted leave comment", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business"),
+        ("id" = String, Path, description = "Leave comment ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave Comment Deletion API", skip(pool), fields())]
+pub async fn leave_comment_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let is_deleted = delete_leave_comment(&pool, path.into_inner(), user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting leave comment".to_string(),
+                e,
+            )
+        })?;
+    if !is_deleted {
+        return Err(GenericError::DataNotFound(
+            "Leave comment not found".to_string(),
+        ));
+    }
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave comment",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for making a updating leave status",
+    tag = "Leave",
+    summary = "Leave Request Status Updation API",
+    path = "/leave/request/status/update",
+    request_body(content = UpdateLeaveStatusRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated leave request status", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Request Status Updation API",
+    skip(pool, body, producer_client),
+    fields()
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn update_leave_status_req(
+    body: UpdateLeaveStatusRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    mail_config: web::Data<EmailClientConfig>,
+    permissions: AllowedPermission,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+    business: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(body.id));
+    let leave: LeaveRequestData = get_leaves(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            GenericError::DataNotFound("Provided Leave Request not found in database".to_string())
+        })?;
+
+    let user_leave = fetch_user_leaves(
+        &pool,
+        business.id,
+        leave.user_id,
+        None,
+        Some(leave.user_leave_id),
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching  user leave data".to_string(),
+            e,
+        )
+    })?
+    .into_iter()
+    .next()
+    .ok_or_else(|| {
+        GenericError::DataNotFound("Provided Leave Request not found in database".to_string())
+    })?;
+    let leave_id_list = vec![leave.id];
+    let (approval_res, role_res) = join!(
+        get_leave_approvals(&pool, &leave_id_list),
+        get_user_business_role_id(&pool, business.id, user.id),
+    );
+    let approval_list = approval_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave approval".to_string(),
+            e,
+        )
+    })?;
+    let role_id = role_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user role".to_string(),
+            e,
+        )
+    })?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let (status, _) = apply_leave_status_update(
+        &mut transaction,
+        &leave,
+        &user_leave,
+        &approval_list,
+        &body.status,
+        &permissions,
+        role_id,
+        user.id,
+    )
+    .await?;
+
+    let setting_value_list = vec![
+        SettingKey::LeaveRequestStatusUpdateTemplate.to_string(),
+        SettingKey::EmailAppPassword.to_string(),
+    ];
+    let reciever_id = leave.user_id.to_string();
+    let (config_res, reciever_account_res) = join!(
+        get_setting_value(&pool, &setting_value_list, None, Some(user.id), true),
+        get_user(vec![&reciever_id], &pool),
+    );
+    let configs = config_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
+    let reciever_account = reciever_account_res
+        .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?
+        .ok_or(GenericError::DataNotFound("User not found.".to_string()))?;
+
+    let receiver = to_title_case(&reciever_account.display_name);
+    let sender = to_title_case(&user.display_name);
+    // Leave alert is already scheduled when a cancellation request is declined
+    if status == LeaveStatus::Approved && leave.status != LeaveStatus::CancellationRequested {
+        schedule_leave_alert(&producer_client, &[leave.date])
+            .await
+            .map_err(GenericError::UnexpectedError)?;
+    }
+    //     if !user.is_vector_verified(&VectorType::Email) {
+    //     return Err(GenericError::InsufficientPrevilegeError(
+    //         "Please Verify your email, before updating leave requst status".to_string(),
+    //     ));
+    // }
+    if leave.email_message_id.is_some() && user.is_vector_verified(&VectorType::Email) {
+        let html_template: String = configs
+            .get_setting(&SettingKey::LeaveRequestStatusUpdateTemplate.to_string())
+            .ok_or_else(|| {
+                GenericError::DataNotFound(format!(
+                    "Please set the {}",
+                    SettingKey::LeaveRequestStatusUpdateTemplate
+                ))
+            })?;
+        let context_data =
+            LeaveRequestStatusEmailContext::new(&sender, &receiver, &status, &leave.date);
+        let context = TeraContext::from_serialize(&context_data).map_err(|e: tera::Error| {
+            tracing::error!("{}", e);
+            GenericError::UnexpectedCustomError(
+                "Something went wrong while rendering the email html data".to_string(),
+            )
+        })?;
+        let rendered_string = Tera::one_off(&html_template, &context, true).map_err(|e| {
+            tracing::error!("Error while rendering html {} error: {}", html_template, e);
+            GenericError::UnexpectedCustomError(
+                "Something went wrong while rendering the email html data".to_string(),
+            )
+        })?;
+        let email_password = configs
+            .get_setting(&SettingKey::EmailAppPassword.to_string())
+            .ok_or_else(|| {
+                GenericError::DataNotFound(format!(
+                    "Please set the {}",
+                    SettingKey::EmailAppPassword
+                ))
+            })?;
+
+        let personal_email_client = SmtpEmailClient::new_personal(
+            &user.email,
+            SecretString::from(email_password.as_ref()),
+            &mail_config.personal.base_url,
+        )
+        .unwrap();
+        personal_email_client
+            .send_html_email(
+                &reciever_account.email,
+                &leave.cc,
+                &format!("Request for {} leave", leave.leave_type),
+                rendered_string,
+                leave.email_message_id.clone(),
+                leave.email_message_id,
+            )
+            .await
+            .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+    }
+
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::LeaveRequestStatusUpdation,
+        ProcessType::Deferred,
+        vec![reciever_account.id],
+        format!("Leave Request send by {}", user.display_name),
+        Some(business.id),
+        &producer_client,
+    )
+    .await;
+    // .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to store a new user account.")?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated leave request status",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for updating the status of multiple leave requests at once. Each request is validated on its own and the result is returned per request. Email replies are not sent for bulk updates.",
+    tag = "Leave",
+    summary = "Leave Request Bulk Status Updation API",
+    path = "/leave/request/status/bulk-update",
+    request_body(content = BulkUpdateLeaveStatusRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated leave request status", body= GenericResponse<Vec<LeaveStatusUpdateResult>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave Request Bulk Status Updation API",
+    skip(pool, body, producer_client),
+    fields()
+)]
+pub async fn bulk_update_leave_status_req(
+    body: BulkUpdateLeaveStatusRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    permissions: AllowedPermission,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+    business: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveStatusUpdateResult>>>, GenericError> {
+    if body.data.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Leave request list cannot be empty".to_string(),
+        ));
+    }
+    let id_list: Vec<Uuid> = body.data.iter().map(|a| a.id).collect();
+    if id_list.iter().collect::<HashSet<_>>().len() != id_list.len() {
+        return Err(GenericError::ValidationError(
+            "Leave request list contains duplicate entries".to_string(),
+        ));
+    }
+    let filter_query = FetchLeaveQuery::builder()
+        .with_business_id(Some(business.id))
+        .with_leave_id_list(Some(&id_list));
+    let (leave_res, approval_res, role_res) = join!(
+        get_leaves(&pool, &filter_query),
+        get_leave_approvals(&pool, &id_list),
+        get_user_business_role_id(&pool, business.id, user.id),
+    );
+    let leave_map: HashMap<Uuid, LeaveRequestData> = leave_res
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave data".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .map(|a| (a.id, a))
+        .collect();
+    let mut approval_map: HashMap<Uuid, Vec<LeaveApproval>> = HashMap::new();
+    for approval in approval_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave approval".to_string(),
+            e,
+        )
+    })? {
+        approval_map
+            .entry(approval.leave_id)
+            .or_default()
+            .push(approval);
+    }
+    let role_id = role_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user role".to_string(),
+            e,
+        )
+    })?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    // Used counts are tracked locally so that several requests on the same user leave are
+    // validated against the count left by the previous ones
+    let mut user_leave_map: HashMap<Uuid, UserLeave> = HashMap::new();
+    let mut result_list = vec![];
+    let mut alert_date_list = vec![];
+    let mut notified_user_id_list = vec![];
+    for item in body.data.iter() {
+        let Some(leave) = leave_map.get(&item.id) else {
+            result_list.push(LeaveStatusUpdateResult::failure(
+                item.id,
+                "Provided Leave Request not found in database".to_string(),
+            ));
+            continue;
+        };
+        let user_leave = match user_leave_map.entry(leave.user_leave_id) {
+            Entry::Occupied(entry) => Some(entry.into_mut()),
+            Entry::Vacant(entry) => fetch_user_leaves(
+                &pool,
+                business.id,
+                leave.user_id,
+                None,
+                Some(leave.user_leave_id),
+            )
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching  user leave data".to_string(),
+                    e,
+                )
+            })?
+            .into_iter()
+            .next()
+            .map(|user_leave| entry.insert(user_leave)),
+        };
+        let Some(user_leave) = user_leave else {
+            result_list.push(LeaveStatusUpdateResult::failure(
+                item.id,
+                "User leave of the leave request not found in database".to_string(),
+            ));
+            continue;
+        };
+        let approval_list = approval_map.get(&leave.id).map(|a| a.as_slice());
+        match apply_leave_status_update(
+            &mut transaction,
+            leave,
+            user_leave,
+            approval_list.unwrap_or_default(),
+            &item.status,
+            &permissions,
+            role_id,
+            user.id,
+        )
+        .await
+        {
+            Ok((status, adjustment)) => {
+                user_leave.used_count += adjustment;
+                if status == LeaveStatus::Approved
+                    && leave.status != LeaveStatus::CancellationRequested
+                {
+                    alert_date_list.push(leave.date);
+                }
+                if !notified_user_id_list.contains(&leave.user_id) {
+                    notified_user_id_list.push(leave.user_id);
+                }
+                result_list.push(LeaveStatusUpdateResult::success(item.id, status));
+            }
+            Err(GenericError::ValidationError(message))
+            | Err(GenericError::InsufficientPrevilegeError(message)) => {
+                result_list.push(LeaveStatusUpdateResult::failure(item.id, message));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update leave request status.")?;
+
+    schedule_leave_alert(&producer_client, &alert_date_list)
+        .await
+        .map_err(GenericError::UnexpectedError)?;
+    if !notified_user_id_list.is_empty() {
+        let _ = send_notification(
+            &pool,
+            &websocket_srv,
+            WebSocketActionType::LeaveRequestStatusUpdation,
+            ProcessType::Deferred,
+            notified_user_id_list,
+            format!("Leave Request status updated by {}", user.display_name),
+            Some(business.id),
+            &producer_client,
+        )
+        .await;
+    }
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated leave request status",
+        result_list,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for listing the leave requests awaiting the approval of the user across the business, grouped by requester",
+    tag = "Leave",
+    summary = "Pending Leave Approval List API",
+    path = "/leave/approvals/pending",
+    responses(
+        (status=200, description= "sucessfully fetched pending leave approval", body= GenericResponse<Vec<PendingLeaveApprovalData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business"),
+      )
+)]
+#[tracing::instrument(err, name = "Pending Leave Approval List API", skip(pool), fields())]
+pub async fn pending_leave_approval_list_req(
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<PendingLeaveApprovalData>>>, GenericError> {
+    // Requests partially through a multi-stage approval or awaiting confirmation of their
+    // cancellation are still awaiting an action
+    let status_list = vec![
+        LeaveStatus::Requested,
+        LeaveStatus::InProgress,
+        LeaveStatus::CancellationRequested,
+    ];
+    let filter_query = FetchLeaveQuery::builder()
+        .with_business_id(Some(business.id))
+        .with_recevier_id(Some(user.id))
+        .with_status_list(Some(&status_list));
+    let leave_list = get_leaves(&pool, &filter_query).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave data".to_string(),
+            e,
+        )
+    })?;
+    let user_id_list: Vec<Uuid> = leave_list
+        .iter()
+        .map(|a| a.user_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_list = if user_id_list.is_empty() {
+        vec![]
+    } else {
+        get_minimal_user_list(
+            &pool,
+            None,
+            user_id_list.len() as i32,
+            0,
+            Some(&user_id_list),
+        )
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching user data".to_string(),
+                e,
+            )
+        })?
+    };
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched pending leave approval",
+        group_pending_leave_approvals(leave_list, &user_list),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for creating leave period",
+    tag = "Leave",
+    summary = "Leave Period Creation API",
+    path = "/leave/period/create",
+    request_body(content = LeavePeriodCreationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved leave period", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave group create request", skip(pool), fields())]
+pub async fn leave_period_create_req(
+    pool: web::Data<PgPool>,
+    req: LeavePeriodCreationRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let label_list = req
+        .data
+        .iter()
+        .filter(|a| a.id.is_none())
+        .map(|a| a.label.as_str())
+        .collect();
+    let leave_type_list =
+        get_leave_period(&pool, business_account.id, None, Some(label_list), None)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching leave period".to_string(),
+                    e,
+                )
+            })?;
+
+    if !leave_type_list.is_empty() {
+        let existing_labels = leave_type_list
+            .iter()
+            .map(|p| p.label.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+
+        return Err(GenericError::ValidationError(format!(
+            "Leave periods with label(s) already exist: {}",
+            existing_labels
+        )));
+    }
+
+    save_leave_period(&pool, &req.data, user.id, business_account.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave period".to_string(),
+                e,
+            )
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved leave period",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing leave period",
+    tag = "Leave",
+    summary = "Leave Period List API",
+    path = "/leave/period/list",
+    request_body(content = LeaveTypeFetchRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched leave period", body= GenericResponse<Vec<LeavePeriodData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        // ("id" = String, Path, description = "Leave ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave type list request", skip(pool), fields())]
+pub async fn leave_period_list_req(
+    pool: web::Data<PgPool>,
+    req: LeavePeriodFetchRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeavePeriodData>>>, GenericError> {
+    let leave_type_list = get_leave_period(&pool, business_account.id, None, None, req.query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave period".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave period",
+        leave_type_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting leave period",
+    tag = "Leave",
+    summary = "Leave Group Delete API",
+    path = "/leave/period/delete",
+    // request_body(content = FetchLeaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully deleted leave period", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Leave Period ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave period delete request", skip(pool), fields())]
+pub async fn leave_period_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let leave_period_id = path.into_inner();
+    let leave_group_list = get_leave_period(
+        &pool,
+        business_account.id,
+        Some(&vec![leave_period_id]),
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave period".to_string(),
+            e,
+        )
+    })?;
+    leave_group_list
+        .first()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave period id".to_string()))?;
+
+    delete_leave_period(&pool, leave_period_id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting leave period".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave period",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for creating / updating business holidays",
+    tag = "Leave",
+    summary = "Holiday Creation API",
+    path = "/leave/holiday/create",
+    request_body(content = HolidayCreationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved holiday", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Holiday create request", skip(pool), fields())]
+pub async fn holiday_create_req(
+    pool: web::Data<PgPool>,
+    req: HolidayCreationRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    holiday_create_validation(&pool, &req.data, business_account.id).await?;
+    save_holiday(&pool, &req.data, user.id, business_account.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError("Something went wrong while saving holiday".to_string(), e)
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved holiday",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing business holidays",
+    tag = "Leave",
+    summary = "Holiday List API",
+    path = "/leave/holiday/list",
+    request_body(content = HolidayFetchRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched holiday", body= GenericResponse<Vec<HolidayData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Holiday list request", skip(pool), fields())]
+pub async fn holiday_list_req(
+    pool: web::Data<PgPool>,
+    req: HolidayFetchRequest,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<HolidayData>>>, GenericError> {
+    let holiday_list = get_holiday(
+        &pool,
+        business_account.id,
+        None,
+        None,
+        req.start_date,
+        req.end_date,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching holiday".to_string(), e)
+    })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched holiday",
+        holiday_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting business holiday",
+    tag = "Leave",
+    summary = "Holiday Delete API",
+    path = "/leave/holiday/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted holiday", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Holiday ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Holiday delete request", skip(pool), fields())]
+pub async fn holiday_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let holiday_id = path.into_inner();
+    get_holiday(
+        &pool,
+        business_account.id,
+        Some(&vec![holiday_id]),
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching holiday".to_string(), e)
+    })?
+    .first()
+    .ok_or_else(|| GenericError::DataNotFound("Invalid Holiday id".to_string()))?;
+
+    delete_holiday(&pool, holiday_id).await.map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while deleting holiday".to_string(), e)
+    })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted holiday",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for configuring the approval stages of a leave type. Without a leave type the stages are the business wide default",
+    tag = "Leave",
+    summary = "Leave Approval Stage Save API",
+    path = "/leave/approval/stage/save",
+    request_body(content = LeaveApprovalStageSaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved leave approval stage", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave approval stage save request", skip(pool), fields())]
+pub async fn leave_approval_stage_save_req(
+    pool: web::Data<PgPool>,
+    req: LeaveApprovalStageSaveRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    validate_leave_approval_stage_data(&req.stage_list)?;
+    if let Some(leave_type_id) = req.leave_type_id {
+        get_leave_type(
+            &pool,
+            business_account.id,
+            Some(vec![leave_type_id]),
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave type".to_string(),
+                e,
+            )
+        })?
+        .first()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid leave type id".to_string()))?;
+    }
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    save_leave_approval_stage(
+        &mut transaction,
+        business_account.id,
+        req.leave_type_id,
+        &req.stage_list,
+        user.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while saving leave approval stage".to_string(),
+            e,
+        )
+    })?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to save leave approval stage.")?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved leave approval stage",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing the approval stages of a leave type or the business wide default",
+    tag = "Leave",
+    summary = "Leave Approval Stage List API",
+    path = "/leave/approval/stage/list",
+    request_body(content = LeaveApprovalStageFetchRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched leave approval stage", body= GenericResponse<Vec<LeaveApprovalStage>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave approval stage list request", skip(pool), fields())]
+pub async fn leave_approval_stage_list_req(
+    pool: web::Data<PgPool>,
+    req: LeaveApprovalStageFetchRequest,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveApprovalStage>>>, GenericError> {
+    let stage_list =
+        get_leave_approval_stages(&pool, business_account.id, req.leave_type_id, false)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching leave approval stage".to_string(),
+                    e,
+                )
+            })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave approval stage",
+        stage_list,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for configuring the year-end carry forward rule of leave types",
+    tag = "Leave",
+    summary = "Leave Carry Forward Rule Save API",
+    path = "/leave/carry-forward/rule/save",
+    request_body(content = LeaveCarryForwardRuleSaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved leave carry forward rule", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave carry forward rule save request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_carry_forward_rule_save_req(
+    pool: web::Data<PgPool>,
+    req: LeaveCarryForwardRuleSaveRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    validate_leave_carry_forward_rule_data(&req.data)?;
+    let leave_type_set: HashSet<Uuid> = req.data.iter().map(|a| a.leave_type_id).collect();
+    let leave_type_list = get_leave_type(
+        &pool,
+        business_account.id,
+        Some(leave_type_set.iter().copied().collect()),
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave type".to_string(),
+            e,
+        )
+    })?;
+    let allowed_leave_type_set: HashSet<Uuid> = leave_type_list.iter().map(|a| a.id).collect();
+    if !allowed_leave_type_set.is_superset(&leave_type_set) {
+        return Err(GenericError::DataNotFound(
+            "Leave type/s not found for given business".to_string(),
+        ));
+    }
+    save_leave_carry_forward_rule(&pool, &req.data, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave carry forward rule".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved leave carry forward rule",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for listing the carry forward rules of the leave types of the business",
+    tag = "Leave",
+    summary = "Leave Carry Forward Rule List API",
+    path = "/leave/carry-forward/rule/list",
+    responses(
+        (status=200, description= "sucessfully fetched leave carry forward rule", body= GenericResponse<Vec<LeaveCarryForwardRule>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave carry forward rule list request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_carry_forward_rule_list_req(
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveCarryForwardRule>>>, GenericError> {
+    let rule_list = get_leave_carry_forward_rules(&pool, business_account.id, None)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave carry forward rule".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave carry forward rule",
+        rule_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting the carry forward rule of a leave type",
+    tag = "Leave",
+    summary = "Leave Carry Forward Rule Delete API",
+    path = "/leave/carry-forward/rule/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted leave carry forward rule", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Carry forward rule ID"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave carry forward rule delete request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_carry_forward_rule_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    delete_leave_carry_forward_rule(&pool, business_account.id, path.into_inner())
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting leave carry forward rule".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave carry forward rule",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for configuring the accrual policy of leave types",
+    tag = "Leave",
+    summary = "Leave Accrual Policy Save API",
+    path = "/leave/accrual/policy/save",
+    request_body(content = LeaveAccrualPolicySaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved leave accrual policy", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave accrual policy save request", skip(pool), fields())]
+pub async fn leave_accrual_policy_save_req(
+    pool: web::Data<PgPool>,
+    req: LeaveAccrualPolicySaveRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    validate_leave_accrual_policy_data(&req.data)?;
+    let leave_type_set: HashSet<Uuid> = req.data.iter().map(|a| a.leave_type_id).collect();
+    let leave_type_list = get_leave_type(
+        &pool,
+        business_account.id,
+        Some(leave_type_set.iter().copied().collect()),
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave type".to_string(),
+            e,
+        )
+    })?;
+    let allowed_leave_type_set: HashSet<Uuid> = leave_type_list.iter().map(|a| a.id).collect();
+    if !allowed_leave_type_set.is_superset(&leave_type_set) {
+        return Err(GenericError::DataNotFound(
+            "Leave type/s not found for given business".to_string(),
+        ));
+    }
+    save_leave_accrual_policy(&pool, &req.data, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave accrual policy".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved leave accrual policy",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for listing the accrual policies of the leave types of the business",
+    tag = "Leave",
+    summary = "Leave Accrual Policy List API",
+    path = "/leave/accrual/policy/list",
+    responses(
+        (status=200, description= "sucessfully fetched leave accrual policy", body= GenericResponse<Vec<LeaveAccrualPolicy>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave accrual policy list request", skip(pool), fields())]
+pub async fn leave_accrual_policy_list_req(
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveAccrualPolicy>>>, GenericError> {
+    let policy_list = get_leave_accrual_policies(&pool, Some(business_account.id), None)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave accrual policy".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave accrual policy",
+        policy_list,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting the accrual policy of a leave type",
+    tag = "Leave",
+    summary = "Leave Accrual Policy Delete API",
+    path = "/leave/accrual/policy/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted leave accrual policy", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Accrual policy ID"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "Leave accrual policy delete request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_accrual_policy_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    delete_leave_accrual_policy(&pool, business_account.id, path.into_inner())
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting leave accrual policy".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave accrual policy",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for configuring the request policy of leave types",
+    tag = "Leave",
+    summary = "Leave Type Policy Save API",
+    path = "/leave/type/policy/save",
+    request_body(content = LeaveTypePolicySaveRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully saved leave type policy", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave type policy save request", skip(pool), fields())]
+pub async fn leave_type_policy_save_req(
+    pool: web::Data<PgPool>,
+    req: LeaveTypePolicySaveRequest,
+    user: UserAccount,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    validate_leave_type_policy_data(&req.data)?;
+    let leave_type_set: HashSet<Uuid> = req.data.iter().map(|a| a.leave_type_id).collect();
+    let leave_type_list = get_leave_type(
+        &pool,
+        business_account.id,
+        Some(leave_type_set.iter().copied().collect()),
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave type".to_string(),
+            e,
         )
-        .await;
-        // .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
+    })?;
+    let allowed_leave_type_set: HashSet<Uuid> = leave_type_list.iter().map(|a| a.id).collect();
+    if !allowed_leave_type_set.is_superset(&leave_type_set) {
+        return Err(GenericError::DataNotFound(
+            "Leave type/s not found for given business".to_string(),
+        ));
     }
-    transaction
-        .commit()
+    save_leave_type_policy(&pool, &req.data, user.id)
         .await
-        .context("Failed to commit SQL transaction to store a new user account.")?;
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave type policy".to_string(),
+                e,
+            )
+        })?;
 
     Ok(web::Json(GenericResponse::success(
-        "sucessfully created leave request",
+        "sucessfully saved leave type policy",
         (),
     )))
 }
 
+#[utoipa::path(
+    get,
+    description = "API for listing the request policies of the leave types of the business",
+    tag = "Leave",
+    summary = "Leave Type Policy List API",
+    path = "/leave/type/policy/list",
+    responses(
+        (status=200, description= "sucessfully fetched leave type policy", body= GenericResponse<Vec<LeaveTypePolicy>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave type policy list request", skip(pool), fields())]
+pub async fn leave_type_policy_list_req(
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveTypePolicy>>>, GenericError> {
+    let policy_list = get_leave_type_policies(&pool, business_account.id, None)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave type policy".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave type policy",
+        policy_list,
+    )))
+}
+
 #[utoipa::path(
     delete,
-    description = "API for making a deleting leave request",
+    description = "API for deleting the request policy of a leave type",
     tag = "Leave",
-    summary = "Leave Request Deletion API",
-    path = "/leave/request/delete",
+    summary = "Leave Type Policy Delete API",
+    path = "/leave/type/policy/delete/{id}",
     responses(
-        (status=200, description= "sucessfully deleted leave request", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully deleted leave type policy", body= GenericResponse<TupleUnit>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -851,61 +3664,40 @@ pub async fn create_leave_req(
         ("Authorization" = String, Header, description = "JWT token"),
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
-        ("id" = String, Path, description = "Leave ID"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Leave type policy ID"),
       )
 )]
-#[tracing::instrument(err, name = "Leave Request Deletion Request", skip(pool), fields())]
-pub async fn leave_request_deletion_req(
+#[tracing::instrument(err, name = "Leave type policy delete request", skip(pool), fields())]
+pub async fn leave_type_policy_delete_req(
     path: web::Path<Uuid>,
     pool: web::Data<PgPool>,
-    user: UserAccount,
-    mail_config: web::Data<EmailClientConfig>,
-    // permissions: AllowedPermission,
+    business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<()>>, GenericError> {
-    let leave_id = path.into_inner();
-
-    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(leave_id));
-    let leave = get_leaves(&pool, &filter_query)
+    delete_leave_type_policy(&pool, business_account.id, path.into_inner())
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while fetching leave data".to_string(),
+                "Something went wrong while deleting leave type policy".to_string(),
                 e,
             )
-        })?
-        .into_iter()
-        .next()
-        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave ID".to_string()))?;
+        })?;
 
-    if leave.user_id != user.id {
-        return Err(GenericError::InsufficientPrevilegeError(
-            "You don't have previlege to delete other user's leaves".to_string(),
-        ));
-    };
-    if leave.status == LeaveStatus::Approved {
-        return Err(GenericError::ValidationError(
-            "The leave is already approved, pls reject the leave request before deletion"
-                .to_string(),
-        ));
-    }
-    delete_leave(&pool, leave_id, user.id).await.map_err(|_| {
-        GenericError::UnexpectedCustomError("something went wrong while deleting leave".to_string())
-    })?;
     Ok(web::Json(GenericResponse::success(
-        "sucessfully deleted leave request",
+        "sucessfully deleted leave type policy",
         (),
     )))
 }
 
 #[utoipa::path(
-    patch,
-    description = "API for making a updating leave status",
+    post,
+    description = "API for saving a leave blackout period of the business or a department",
     tag = "Leave",
-    summary = "Leave Request Status Updation API",
-    path = "/leave/request/status/update",
-    request_body(content = UpdateLeaveStatusRequest, description = "Request Body"),
+    summary = "Leave Blackout Save API",
+    path = "/leave/blackout/save",
+    request_body(content = LeaveBlackoutCreationRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully updated leave request status", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully saved leave blackout", body= GenericResponse<TupleUnit>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -916,219 +3708,213 @@ pub async fn leave_request_deletion_req(
         ("Authorization" = String, Header, description = "JWT token"),
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
       )
 )]
-#[tracing::instrument(
-    err,
-    name = "Leave Request Status Updation API",
-    skip(pool, body, producer_client),
-    fields()
-)]
-#[allow(clippy::too_many_arguments)]
-pub async fn update_leave_status_req(
-    body: UpdateLeaveStatusRequest,
+#[tracing::instrument(err, name = "Leave blackout save request", skip(pool), fields())]
+pub async fn leave_blackout_save_req(
     pool: web::Data<PgPool>,
+    req: LeaveBlackoutCreationRequest,
     user: UserAccount,
-    mail_config: web::Data<EmailClientConfig>,
-    permissions: AllowedPermission,
-    websocket_srv: web::Data<Addr<Server>>,
-    producer_client: web::Data<PulsarClient>,
-    business: BusinessAccount,
+    business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<()>>, GenericError> {
-    let filter_query = FetchLeaveQuery::builder().with_leave_id(Some(body.id));
-    let leave: LeaveRequestData = get_leaves(&pool, &filter_query)
+    validate_leave_blackout(&req)?;
+    if !req.leave_type_id_list.is_empty() {
+        let leave_type_set: HashSet<Uuid> = req.leave_type_id_list.iter().copied().collect();
+        let leave_type_list = get_leave_type(
+            &pool,
+            business_account.id,
+            Some(leave_type_set.iter().copied().collect()),
+            None,
+            None,
+        )
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while fetching leave data".to_string(),
+                "Something went wrong while fetching leave type".to_string(),
                 e,
             )
-        })?
-        .into_iter()
-        .next()
-        .ok_or_else(|| {
-            GenericError::DataNotFound("Provided Leave Request not found in database".to_string())
         })?;
+        let allowed_leave_type_set: HashSet<Uuid> = leave_type_list.iter().map(|a| a.id).collect();
+        if !allowed_leave_type_set.is_superset(&leave_type_set) {
+            return Err(GenericError::DataNotFound(
+                "Leave type/s not found for given business".to_string(),
+            ));
+        }
+    }
+    save_leave_blackout(&pool, &req, business_account.id, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving leave blackout".to_string(),
+                e,
+            )
+        })?
+        .ok_or_else(|| GenericError::DataNotFound("Invalid leave blackout id".to_string()))?;
 
-    let user_leave = fetch_user_leaves(
-        &pool,
-        business.id,
-        leave.user_id,
-        None,
-        Some(leave.user_leave_id),
-    )
-    .await
-    .map_err(|e| {
-        GenericError::DatabaseError(
-            "Something went wrong while fetching  user leave data".to_string(),
-            e,
-        )
-    })?
-    .into_iter()
-    .next()
-    .ok_or_else(|| {
-        GenericError::DataNotFound("Provided Leave Request not found in database".to_string())
-    })?;
-    validate_leave_status_update(
-        &body.status,
-        &leave.status,
-        &permissions,
-        &user_leave,
-        &leave.period,
-    )?;
-    let mut transaction = pool
-        .begin()
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully saved leave blackout",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for listing the leave blackout periods of the business",
+    tag = "Leave",
+    summary = "Leave Blackout List API",
+    path = "/leave/blackout/list",
+    responses(
+        (status=200, description= "sucessfully fetched leave blackout", body= GenericResponse<Vec<LeaveBlackout>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave blackout list request", skip(pool), fields())]
+pub async fn leave_blackout_list_req(
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<Vec<LeaveBlackout>>>, GenericError> {
+    let blackout_list = get_leave_blackouts(&pool, business_account.id, None, None, None)
         .await
-        .context("Failed to acquire a Postgres connection from the pool")?;
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave blackout".to_string(),
+                e,
+            )
+        })?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched leave blackout",
+        blackout_list,
+    )))
+}
 
-    update_leave_request_status(&mut transaction, body.id, &body.status, user.id)
+#[utoipa::path(
+    delete,
+    description = "API for deleting a leave blackout period",
+    tag = "Leave",
+    summary = "Leave Blackout Delete API",
+    path = "/leave/blackout/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted leave blackout", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("id" = String, Path, description = "Leave blackout ID"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave blackout delete request", skip(pool), fields())]
+pub async fn leave_blackout_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    delete_leave_blackout(&pool, business_account.id, path.into_inner())
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while updating leave request".to_string(),
+                "Something went wrong while deleting leave blackout".to_string(),
                 e,
             )
         })?;
-    if body.status == LeaveStatus::Approved || body.status == LeaveStatus::Cancelled {
-        let adjustment = match leave.status {
-            LeaveStatus::Approved => leave.period.value.clone(),
-            LeaveStatus::Cancelled => -&leave.period.value,
-            _ => BigDecimal::default(),
-        };
 
-        update_user_leave_count(&mut transaction, leave.user_leave_id, &adjustment, user.id)
-            .await
-            .map_err(|e| {
-                GenericError::DatabaseError(
-                    "Something went wrong while updating leave count".to_string(),
-                    e,
-                )
-            })?;
-    }
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted leave blackout",
+        (),
+    )))
+}
 
-    let setting_value_list = vec![
-        SettingKey::LeaveRequestStatusUpdateTemplate.to_string(),
-        SettingKey::EmailAppPassword.to_string(),
-    ];
-    let reciever_id = leave.user_id.to_string();
-    let (config_res, reciever_account_res) = join!(
-        get_setting_value(&pool, &setting_value_list, None, Some(user.id), true),
-        get_user(vec![&reciever_id], &pool),
+#[utoipa::path(
+    post,
+    description = "API for fetching the leave calendar of the business with holidays and blackout periods",
+    tag = "Leave",
+    summary = "Leave Calendar API",
+    path = "/leave/calendar",
+    request_body(content = LeaveCalendarFetchRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched leave calendar", body= GenericResponse<LeaveCalendar>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+      )
+)]
+#[tracing::instrument(err, name = "Leave calendar request", skip(pool), fields())]
+pub async fn leave_calendar_req(
+    pool: web::Data<PgPool>,
+    req: LeaveCalendarFetchRequest,
+    business_account: BusinessAccount,
+) -> Result<web::Json<GenericResponse<LeaveCalendar>>, GenericError> {
+    let (holiday_res, blackout_res) = join!(
+        get_holiday(
+            &pool,
+            business_account.id,
+            None,
+            None,
+            req.start_date,
+            req.end_date,
+        ),
+        get_leave_blackouts(
+            &pool,
+            business_account.id,
+            req.start_date,
+            req.end_date,
+            req.department_id
+        ),
     );
-    let configs = config_res.map_err(|e| GenericError::DatabaseError(e.to_string(), e))?;
-    let reciever_account = reciever_account_res
-        .map_err(|e| GenericError::DatabaseError(e.to_string(), e))?
-        .ok_or(GenericError::DataNotFound("User not found.".to_string()))?;
-
-    let receiver = to_title_case(&reciever_account.display_name);
-    let sender = to_title_case(&user.display_name);
-    if body.status == LeaveStatus::Approved {
-        let msg = SchedulerMessageData {
-            partition_key: None,
-            date: leave.date,
-        };
-        let mut producer = producer_client
-            .get_producer(producer_client.get_product_topic(PulsarTopic::Scheduler))
-            .await;
-
-        let msg = producer
-            .create_message()
-            .with_content(msg)
-            .deliver_at(leave.date.into())
-            .map_err(|e| GenericError::UnexpectedError(e.into()))?;
-        msg.send_non_blocking()
-            .await
-            .map_err(|e| GenericError::UnexpectedError(e.into()))?;
-    }
-    //     if !user.is_vector_verified(&VectorType::Email) {
-    //     return Err(GenericError::InsufficientPrevilegeError(
-    //         "Please Verify your email, before updating leave requst status".to_string(),
-    //     ));
-    // }
-    if leave.email_message_id.is_some() && user.is_vector_verified(&VectorType::Email) {
-        let html_template: String = configs
-            .get_setting(&SettingKey::LeaveRequestStatusUpdateTemplate.to_string())
-            .ok_or_else(|| {
-                GenericError::DataNotFound(format!(
-                    "Please set the {}",
-                    SettingKey::LeaveRequestStatusUpdateTemplate
-                ))
-            })?;
-        let context_data =
-            LeaveRequestStatusEmailContext::new(&sender, &receiver, &body.status, &leave.date);
-        let context = TeraContext::from_serialize(&context_data).map_err(|e: tera::Error| {
-            tracing::error!("{}", e);
-            GenericError::UnexpectedCustomError(
-                "Something went wrong while rendering the email html data".to_string(),
-            )
-        })?;
-        let rendered_string = Tera::one_off(&html_template, &context, true).map_err(|e| {
-            tracing::error!("Error while rendering html {} error: {}", html_template, e);
-            GenericError::UnexpectedCustomError(
-                "Something went wrong while rendering the email html data".to_string(),
-            )
-        })?;
-        let email_password = configs
-            .get_setting(&SettingKey::EmailAppPassword.to_string())
-            .ok_or_else(|| {
-                GenericError::DataNotFound(format!(
-                    "Please set the {}",
-                    SettingKey::EmailAppPassword
-                ))
-            })?;
-
-        let personal_email_client = SmtpEmailClient::new_personal(
-            &user.email,
-            SecretString::from(email_password.as_ref()),
-            &mail_config.personal.base_url,
+    let holiday_list = holiday_res.map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching holiday".to_string(), e)
+    })?;
+    let blackout_list = blackout_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave blackout".to_string(),
+            e,
         )
-        .unwrap();
-        personal_email_client
-            .send_html_email(
-                &reciever_account.email,
-                &leave.cc,
-                &format!("Request for {} leave", leave.leave_type),
-                rendered_string,
-                leave.email_message_id.clone(),
-                leave.email_message_id,
-            )
-            .await
-            .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
-    }
-
-    let _ = send_notification(
-        &pool,
-        &websocket_srv,
-        WebSocketActionType::LeaveRequestStatusUpdation,
-        ProcessType::Deferred,
-        vec![reciever_account.id],
-        format!("Leave Request send by {}", user.display_name),
-        Some(business.id),
-        &producer_client,
-    )
-    .await;
-    // .map_err(|e| GenericError::UnexpectedCustomError(e.to_string()))?;
-
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit SQL transaction to store a new user account.")?;
+    })?;
 
     Ok(web::Json(GenericResponse::success(
-        "sucessfully updated leave request status",
-        (),
+        "sucessfully fetched leave calendar",
+        LeaveCalendar {
+            holiday_list,
+            blackout_list,
+        },
     )))
 }
 
 #[utoipa::path(
     post,
-    description = "API for creating leave period",
+    description = "API for configuring the rules approving low-risk leave requests on creation",
     tag = "Leave",
-    summary = "Leave Period Creation API",
-    path = "/leave/period/create",
-    request_body(content = LeavePeriodCreationRequest, description = "Request Body"),
+    summary = "Leave Auto Approval Rule Save API",
+    path = "/leave/auto-approval/rule/save",
+    request_body(content = LeaveAutoApprovalRuleSaveRequest, description = "Request Body"),
     responses(
-        (status=200, description= "sucessfully saved leave period", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully saved leave auto approval rule", body= GenericResponse<TupleUnit>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -1142,65 +3928,65 @@ pub async fn update_leave_status_req(
         ("x-business-id" = String, Header, description = "id of business_account"),
       )
 )]
-#[tracing::instrument(err, name = "Leave group create request", skip(pool), fields())]
-pub async fn leave_period_create_req(
+#[tracing::instrument(
+    err,
+    name = "Leave auto approval rule save request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_auto_approval_rule_save_req(
     pool: web::Data<PgPool>,
-    req: LeavePeriodCreationRequest,
+    req: LeaveAutoApprovalRuleSaveRequest,
     user: UserAccount,
     business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<()>>, GenericError> {
-    let label_list = req
-        .data
-        .iter()
-        .filter(|a| a.id.is_none())
-        .map(|a| a.label.as_str())
-        .collect();
-    let leave_type_list =
-        get_leave_period(&pool, business_account.id, None, Some(label_list), None)
-            .await
-            .map_err(|e| {
-                GenericError::DatabaseError(
-                    "Something went wrong while fetching leave period".to_string(),
-                    e,
-                )
-            })?;
-
-    if !leave_type_list.is_empty() {
-        let existing_labels = leave_type_list
-            .iter()
-            .map(|p| p.label.as_str())
-            .collect::<Vec<&str>>()
-            .join(", ");
-
-        return Err(GenericError::ValidationError(format!(
-            "Leave periods with label(s) already exist: {}",
-            existing_labels
-        )));
+    validate_leave_auto_approval_rule_data(&req.data)?;
+    let leave_type_set: HashSet<Uuid> = req.data.iter().filter_map(|a| a.leave_type_id).collect();
+    if !leave_type_set.is_empty() {
+        let leave_type_list = get_leave_type(
+            &pool,
+            business_account.id,
+            Some(leave_type_set.iter().copied().collect()),
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching leave type".to_string(),
+                e,
+            )
+        })?;
+        let allowed_leave_type_set: HashSet<Uuid> = leave_type_list.iter().map(|a| a.id).collect();
+        if !allowed_leave_type_set.is_superset(&leave_type_set) {
+            return Err(GenericError::DataNotFound(
+                "Leave type/s not found for given business".to_string(),
+            ));
+        }
     }
-
-    save_leave_period(&pool, &req.data, user.id, business_account.id)
+    save_leave_auto_approval_rule(&pool, &req.data, business_account.id, user.id)
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while saving leave period".to_string(),
+                "Something went wrong while saving leave auto approval rule".to_string(),
                 e,
             )
         })?;
+
     Ok(web::Json(GenericResponse::success(
-        "sucessfully saved leave period",
+        "sucessfully saved leave auto approval rule",
         (),
     )))
 }
 
 #[utoipa::path(
-    post,
-    description = "API for listing leave period",
+    get,
+    description = "API for listing the leave auto approval rules of the business",
     tag = "Leave",
-    summary = "Leave Period List API",
-    path = "/leave/period/list",
-    request_body(content = LeaveTypeFetchRequest, description = "Request Body"),
+    summary = "Leave Auto Approval Rule List API",
+    path = "/leave/auto-approval/rule/list",
     responses(
-        (status=200, description= "sucessfully fetched leave period", body= GenericResponse<Vec<LeavePeriodData>>),
+        (status=200, description= "sucessfully fetched leave auto approval rule", body= GenericResponse<Vec<LeaveAutoApprovalRule>>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -1212,40 +3998,41 @@ pub async fn leave_period_create_req(
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
         ("x-business-id" = String, Header, description = "id of business_account"),
-        // ("id" = String, Path, description = "Leave ID"),
       )
 )]
-#[tracing::instrument(err, name = "Leave type list request", skip(pool), fields())]
-pub async fn leave_period_list_req(
+#[tracing::instrument(
+    err,
+    name = "Leave auto approval rule list request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_auto_approval_rule_list_req(
     pool: web::Data<PgPool>,
-    req: LeavePeriodFetchRequest,
-    user: UserAccount,
     business_account: BusinessAccount,
-) -> Result<web::Json<GenericResponse<Vec<LeavePeriodData>>>, GenericError> {
-    let leave_type_list = get_leave_period(&pool, business_account.id, None, None, req.query)
+) -> Result<web::Json<GenericResponse<Vec<LeaveAutoApprovalRule>>>, GenericError> {
+    let rule_list = get_leave_auto_approval_rules(&pool, business_account.id, None)
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while fetching leave period".to_string(),
+                "Something went wrong while fetching leave auto approval rule".to_string(),
                 e,
             )
         })?;
 
     Ok(web::Json(GenericResponse::success(
-        "sucessfully fetched leave period",
-        leave_type_list,
+        "sucessfully fetched leave auto approval rule",
+        rule_list,
     )))
 }
 
 #[utoipa::path(
     delete,
-    description = "API for deleting leave period",
+    description = "API for deleting a leave auto approval rule",
     tag = "Leave",
-    summary = "Leave Group Delete API",
-    path = "/leave/period/delete",
-    // request_body(content = FetchLeaveRequest, description = "Request Body"),
+    summary = "Leave Auto Approval Rule Delete API",
+    path = "/leave/auto-approval/rule/delete/{id}",
     responses(
-        (status=200, description= "sucessfully deleted leave period", body= GenericResponse<TupleUnit>),
+        (status=200, description= "sucessfully deleted leave auto approval rule", body= GenericResponse<TupleUnit>),
         (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
         (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
 	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
@@ -1257,46 +4044,31 @@ pub async fn leave_period_list_req(
         ("x-request-id" = String, Header, description = "Request id"),
         ("x-device-id" = String, Header, description = "Device id"),
         ("x-business-id" = String, Header, description = "id of business_account"),
-        ("id" = String, Path, description = "Leave Period ID"),
+        ("id" = String, Path, description = "Auto approval rule ID"),
       )
 )]
-#[tracing::instrument(err, name = "Leave period delete request", skip(pool), fields())]
-pub async fn leave_period_delete_req(
+#[tracing::instrument(
+    err,
+    name = "Leave auto approval rule delete request",
+    skip(pool),
+    fields()
+)]
+pub async fn leave_auto_approval_rule_delete_req(
     path: web::Path<Uuid>,
     pool: web::Data<PgPool>,
-    user: UserAccount,
     business_account: BusinessAccount,
 ) -> Result<web::Json<GenericResponse<()>>, GenericError> {
-    let leave_period_id = path.into_inner();
-    let leave_group_list = get_leave_period(
-        &pool,
-        business_account.id,
-        Some(&vec![leave_period_id]),
-        None,
-        None,
-    )
-    .await
-    .map_err(|e| {
-        GenericError::DatabaseError(
-            "Something went wrong while fetching leave period".to_string(),
-            e,
-        )
-    })?;
-    leave_group_list
-        .first()
-        .ok_or_else(|| GenericError::DataNotFound("Invalid Leave period id".to_string()))?;
-
-    delete_leave_period(&pool, leave_period_id)
+    delete_leave_auto_approval_rule(&pool, business_account.id, path.into_inner())
         .await
         .map_err(|e| {
             GenericError::DatabaseError(
-                "Something went wrong while deleting leave period".to_string(),
+                "Something went wrong while deleting leave auto approval rule".to_string(),
                 e,
             )
         })?;
 
     Ok(web::Json(GenericResponse::success(
-        "sucessfully deleted leave period",
+        "sucessfully deleted leave auto approval rule",
         (),
     )))
 }
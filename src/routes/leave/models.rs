@@ -1,8 +1,11 @@
 use crate::email::EmailObject;
 
 use super::schemas::{
-    LeaveAllowedDate, LeaveGroup, LeavePeriodData, LeaveRequestData, LeaveStatus, LeaveTypeData,
-    UserLeave, UserLeaveGroup, UserLeaveType,
+    HolidayData, LeaveAccrualFrequency, LeaveAccrualPolicy, LeaveAllowedDate, LeaveApproval,
+    LeaveApprovalStage, LeaveApprovalStatus, LeaveAutoApprovalRule, LeaveBlackout,
+    LeaveCarryForwardRule, LeaveComment, LeaveGroup, LeaveLedgerEntry, LeaveLedgerEntryType,
+    LeavePeriodData, LeaveRequestData, LeaveStatus, LeaveTypeData, LeaveTypeEligibility,
+    LeaveTypePolicy, UserLeave, UserLeaveGroup, UserLeaveType,
 };
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
@@ -25,9 +28,12 @@ pub struct LeaveDataModel {
     pub leave_type: String,
     pub user_id: Uuid,
     pub user_leave_id: Uuid,
+    pub receiver_id: Uuid,
     pub leave_period_id: Uuid,
     pub period_label: String,
     pub period_value: BigDecimal,
+    pub attachment_url: Option<String>,
+    pub comment_count: i64,
 }
 
 impl LeaveDataModel {
@@ -50,12 +56,15 @@ impl LeaveDataModel {
             leave_type: self.leave_type,
             user_id: self.user_id,
             user_leave_id: self.user_leave_id,
+            receiver_id: self.receiver_id,
             // leave_period_id: self.leave_period_id,
             period: LeavePeriodData {
                 id: self.leave_period_id,
                 label: self.period_label,
                 value: self.period_value,
             },
+            attachment_url: self.attachment_url,
+            comment_count: self.comment_count,
         }
     }
 }
@@ -67,6 +76,13 @@ pub struct MinimalLeaveModel {
     pub period: String,
     pub user_id: Uuid,
     pub r#type: String,
+    pub business_id: Uuid,
+}
+
+#[derive(Debug, FromRow)]
+pub struct UserLeaveDateModel {
+    pub user_id: Uuid,
+    pub date: DateTime<Utc>,
 }
 
 // pub period_label: String,
@@ -92,6 +108,7 @@ pub struct LeaveTypeModel {
     pub id: Uuid,
     pub label: String,
     pub allowed_dates: Option<Json<Vec<LeaveAllowedDateModel>>>,
+    pub eligibility: Option<Json<LeaveTypeEligibility>>,
 }
 
 impl LeaveTypeModel {
@@ -103,6 +120,7 @@ impl LeaveTypeModel {
             allowed_dates: self
                 .allowed_dates
                 .map(|dates| dates.0.into_iter().map(|d| d.into_schema()).collect()),
+            eligibility: self.eligibility.map(|a| a.0),
         }
     }
 }
@@ -138,6 +156,7 @@ pub struct UserLeaveModel {
     pub leave_group_label: String,
     pub leave_type_label: String,
     pub allowed_dates: Option<Json<Vec<LeaveAllowedDateModel>>>,
+    pub policy: Option<Json<LeaveTypePolicyModel>>,
     // pub period_label: String,
     // pub period_id: Uuid,
 }
@@ -162,6 +181,7 @@ impl UserLeaveModel {
             allowed_dates: self
                 .allowed_dates
                 .map(|p| p.0.into_iter().map(|p| p.into_schema()).collect()),
+            policy: self.policy.map(|a| a.0.into_schema()),
         }
     }
 }
@@ -201,3 +221,284 @@ impl LeavePeriodWithTypeModel {
         }
     }
 }
+
+#[derive(Debug, FromRow)]
+pub struct HolidayModel {
+    pub id: Uuid,
+    pub label: String,
+    pub date: NaiveDate,
+}
+
+impl HolidayModel {
+    pub fn into_schema(self) -> HolidayData {
+        HolidayData {
+            id: self.id,
+            label: self.label,
+            date: self.date,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveApprovalStageModel {
+    pub id: Uuid,
+    pub leave_type_id: Option<Uuid>,
+    pub stage_order: i32,
+    pub label: String,
+    pub approver_role_id: Uuid,
+}
+
+impl LeaveApprovalStageModel {
+    pub fn into_schema(self) -> LeaveApprovalStage {
+        LeaveApprovalStage {
+            id: self.id,
+            leave_type_id: self.leave_type_id,
+            stage_order: self.stage_order,
+            label: self.label,
+            approver_role_id: self.approver_role_id,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveApprovalModel {
+    pub id: Uuid,
+    pub leave_id: Uuid,
+    pub stage_order: i32,
+    pub label: String,
+    pub approver_role_id: Uuid,
+    pub status: LeaveApprovalStatus,
+    pub acted_by: Option<Uuid>,
+    pub acted_on: Option<DateTime<Utc>>,
+}
+
+impl LeaveApprovalModel {
+    pub fn into_schema(self) -> LeaveApproval {
+        LeaveApproval {
+            id: self.id,
+            leave_id: self.leave_id,
+            stage_order: self.stage_order,
+            label: self.label,
+            approver_role_id: self.approver_role_id,
+            status: self.status,
+            acted_by: self.acted_by,
+            acted_on: self.acted_on,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveApproverCandidateModel {
+    pub department_head_id: Option<Uuid>,
+    pub reporting_manager_id: Option<Uuid>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveLedgerModel {
+    pub id: Uuid,
+    pub user_leave_id: Uuid,
+    pub entry_type: LeaveLedgerEntryType,
+    pub allocated_change: BigDecimal,
+    pub used_change: BigDecimal,
+    pub leave_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub created_by: Uuid,
+    pub created_on: DateTime<Utc>,
+}
+
+impl LeaveLedgerModel {
+    pub fn into_schema(self) -> LeaveLedgerEntry {
+        LeaveLedgerEntry {
+            id: self.id,
+            user_leave_id: self.user_leave_id,
+            entry_type: self.entry_type,
+            allocated_change: self.allocated_change,
+            used_change: self.used_change,
+            leave_id: self.leave_id,
+            reason: self.reason,
+            created_by: self.created_by,
+            created_on: self.created_on,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveCarryForwardRuleModel {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    pub max_carry_forward: BigDecimal,
+    pub expiry_days: Option<i32>,
+    pub lapse_remaining: bool,
+}
+
+impl LeaveCarryForwardRuleModel {
+    pub fn into_schema(self) -> LeaveCarryForwardRule {
+        LeaveCarryForwardRule {
+            id: self.id,
+            leave_type_id: self.leave_type_id,
+            max_carry_forward: self.max_carry_forward,
+            expiry_days: self.expiry_days,
+            lapse_remaining: self.lapse_remaining,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct CarryForwardSourceModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub leave_type_id: Uuid,
+    pub allocated_count: BigDecimal,
+    pub used_count: BigDecimal,
+}
+
+#[derive(Debug, FromRow)]
+pub struct CarriedUserLeaveModel {
+    pub id: Uuid,
+    pub carried_count: BigDecimal,
+    pub used_count: BigDecimal,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveAccrualPolicyModel {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    pub amount: BigDecimal,
+    pub frequency: LeaveAccrualFrequency,
+    pub max_balance: Option<BigDecimal>,
+}
+
+impl LeaveAccrualPolicyModel {
+    pub fn into_schema(self) -> LeaveAccrualPolicy {
+        LeaveAccrualPolicy {
+            id: self.id,
+            leave_type_id: self.leave_type_id,
+            amount: self.amount,
+            frequency: self.frequency,
+            max_balance: self.max_balance,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct AccrualUserLeaveModel {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    pub allocated_count: BigDecimal,
+    pub used_count: BigDecimal,
+    pub created_on: DateTime<Utc>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct UserLeaveAllocationModel {
+    pub user_id: Uuid,
+    pub leave_type_id: Uuid,
+    pub allocated_count: BigDecimal,
+}
+
+#[derive(Debug, FromRow, Deserialize)]
+pub struct LeaveTypePolicyModel {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    pub min_notice_days: Option<i32>,
+    pub max_consecutive_days: Option<i32>,
+    pub max_requests_per_month: Option<i32>,
+    pub allow_negative_balance: bool,
+    pub max_negative_balance: Option<BigDecimal>,
+    pub allow_backdated: bool,
+    pub require_attachment: bool,
+}
+
+impl LeaveTypePolicyModel {
+    pub fn into_schema(self) -> LeaveTypePolicy {
+        LeaveTypePolicy {
+            id: self.id,
+            leave_type_id: self.leave_type_id,
+            min_notice_days: self.min_notice_days,
+            max_consecutive_days: self.max_consecutive_days,
+            max_requests_per_month: self.max_requests_per_month,
+            allow_negative_balance: self.allow_negative_balance,
+            max_negative_balance: self.max_negative_balance,
+            allow_backdated: self.allow_backdated,
+            require_attachment: self.require_attachment,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveBlackoutModel {
+    pub id: Uuid,
+    pub department_id: Option<Uuid>,
+    pub reason: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub leave_type_id_list: Vec<Uuid>,
+}
+
+impl LeaveBlackoutModel {
+    pub fn into_schema(self) -> LeaveBlackout {
+        LeaveBlackout {
+            id: self.id,
+            department_id: self.department_id,
+            reason: self.reason,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            leave_type_id_list: self.leave_type_id_list,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveAutoApprovalRuleModel {
+    pub id: Uuid,
+    pub label: String,
+    pub leave_type_id: Option<Uuid>,
+    pub max_days: Option<BigDecimal>,
+    pub min_notice_days: Option<i32>,
+}
+
+impl LeaveAutoApprovalRuleModel {
+    pub fn into_schema(self) -> LeaveAutoApprovalRule {
+        LeaveAutoApprovalRule {
+            id: self.id,
+            label: self.label,
+            leave_type_id: self.leave_type_id,
+            max_days: self.max_days,
+            min_notice_days: self.min_notice_days,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveReminderTargetModel {
+    pub id: Uuid,
+    pub date: DateTime<Utc>,
+    pub receiver_id: Uuid,
+    pub user_id: Uuid,
+    pub business_id: Uuid,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LeaveCommentModel {
+    pub id: Uuid,
+    pub leave_id: Uuid,
+    pub comment: String,
+    pub created_by: Uuid,
+    pub display_name: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl LeaveCommentModel {
+    pub fn into_schema(self) -> LeaveComment {
+        LeaveComment {
+            id: self.id,
+            leave_id: self.leave_id,
+            comment: self.comment,
+            created_by: self.created_by,
+            display_name: self.display_name,
+            created_on: self.created_on,
+        }
+    }
+}
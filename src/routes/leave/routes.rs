@@ -3,11 +3,23 @@ use actix_web::web;
 use crate::{middlewares::BusinessPermissionValidation, schemas::PermissionType};
 
 use super::handlers::{
-    create_leave_req, create_leave_user_association_req, delete_leave_user_association_req,
-    leave_group_create_req, leave_group_delete_req, leave_group_list_req, leave_period_create_req,
-    leave_period_delete_req, leave_period_list_req, leave_request_deletion_req,
-    leave_request_fetch_req, leave_type_create_req, leave_type_delete_req, leave_type_list_req,
-    list_leave_user_association_req, update_leave_status_req,
+    bulk_create_leave_user_association_req, bulk_update_leave_status_req, create_leave_req,
+    create_leave_user_association_req, delete_leave_user_association_req, holiday_create_req,
+    holiday_delete_req, holiday_list_req, leave_accrual_policy_delete_req,
+    leave_accrual_policy_list_req, leave_accrual_policy_save_req, leave_approval_stage_list_req,
+    leave_approval_stage_save_req, leave_auto_approval_rule_delete_req,
+    leave_auto_approval_rule_list_req, leave_auto_approval_rule_save_req,
+    leave_balance_adjustment_req, leave_blackout_delete_req, leave_blackout_list_req,
+    leave_blackout_save_req, leave_calendar_req, leave_cancellation_request_req,
+    leave_carry_forward_rule_delete_req, leave_carry_forward_rule_list_req,
+    leave_carry_forward_rule_save_req, leave_comment_create_req, leave_comment_delete_req,
+    leave_comment_list_req, leave_group_clone_req, leave_group_create_req, leave_group_delete_req,
+    leave_group_list_req, leave_ledger_list_req, leave_period_create_req, leave_period_delete_req,
+    leave_period_list_req, leave_request_deletion_req, leave_request_fetch_req,
+    leave_request_update_req, leave_type_create_req, leave_type_delete_req, leave_type_list_req,
+    leave_type_policy_delete_req, leave_type_policy_list_req, leave_type_policy_save_req,
+    leave_user_allocation_preview_req, list_leave_user_association_req,
+    pending_leave_approval_list_req, update_leave_status_req,
 };
 
 pub fn leave_routes(cfg: &mut web::ServiceConfig) {
@@ -44,6 +56,14 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
                 permission_list: vec![PermissionType::CreateLeaveType.to_string()],
             }),
     );
+    cfg.route(
+        "/group/clone",
+        web::post()
+            .to(leave_group_clone_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
     cfg.route(
         "/group/delete/{id}",
         web::delete()
@@ -62,6 +82,22 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
                 permission_list: vec![PermissionType::CreateLeaveType.to_string()],
             }),
     );
+    cfg.route(
+        "/user/allocate/bulk",
+        web::post()
+            .to(bulk_create_leave_user_association_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/user/allocate/preview",
+        web::post()
+            .to(leave_user_allocation_preview_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
     cfg.route(
         "/user/allocation/delete/{id}",
         web::delete()
@@ -78,6 +114,25 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
                 permission_list: vec![PermissionType::CreateLeaveType.to_string()],
             }),
     );
+    cfg.route(
+        "/user/ledger",
+        web::post()
+            .to(leave_ledger_list_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![
+                    PermissionType::CreateLeaveType.to_string(),
+                    PermissionType::ListUserLeaveSelf.to_string(),
+                ],
+            }),
+    );
+    cfg.route(
+        "/user/adjust",
+        web::post()
+            .to(leave_balance_adjustment_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::AdjustLeaveBalance.to_string()],
+            }),
+    );
     cfg.route(
         "/request/create",
         web::post()
@@ -100,6 +155,40 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
                 ],
             }),
     );
+    cfg.route(
+        "/request/cancel/{id}",
+        web::patch()
+            .to(leave_cancellation_request_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![
+                    PermissionType::CreateLeaveRequestSelf.to_string(),
+                    PermissionType::CreateLeaveRequest.to_string(),
+                ],
+            }),
+    );
+    cfg.route(
+        "/request/update",
+        web::patch()
+            .to(leave_request_update_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![
+                    PermissionType::CreateLeaveRequestSelf.to_string(),
+                    PermissionType::CreateLeaveRequest.to_string(),
+                ],
+            }),
+    );
+    cfg.route(
+        "/request/comment/create",
+        web::post().to(leave_comment_create_req),
+    );
+    cfg.route(
+        "/request/comment/list/{id}",
+        web::get().to(leave_comment_list_req),
+    );
+    cfg.route(
+        "/request/comment/delete/{id}",
+        web::delete().to(leave_comment_delete_req),
+    );
     cfg.route(
         "/request/list",
         web::post()
@@ -123,6 +212,26 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
             }),
     );
 
+    cfg.route(
+        "/request/status/bulk-update",
+        web::patch()
+            .to(bulk_update_leave_status_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![
+                    PermissionType::ApproveLeaveRequest.to_string(),
+                    PermissionType::UpdateLeaveRequestStatus.to_string(),
+                ],
+            }),
+    );
+    cfg.route(
+        "/approvals/pending",
+        web::get()
+            .to(pending_leave_approval_list_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            }),
+    );
+
     cfg.route(
         "/period/create",
         web::post()
@@ -148,4 +257,132 @@ pub fn leave_routes(cfg: &mut web::ServiceConfig) {
                 permission_list: vec![PermissionType::CreateLeaveType.to_string()],
             }),
     );
+
+    cfg.route(
+        "/holiday/create",
+        web::post()
+            .to(holiday_create_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/holiday/delete/{id}",
+        web::delete()
+            .to(holiday_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route("/holiday/list", web::post().to(holiday_list_req));
+    cfg.route(
+        "/approval/stage/save",
+        web::post()
+            .to(leave_approval_stage_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/approval/stage/list",
+        web::post().to(leave_approval_stage_list_req),
+    );
+    cfg.route(
+        "/carry-forward/rule/save",
+        web::post()
+            .to(leave_carry_forward_rule_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/carry-forward/rule/list",
+        web::get().to(leave_carry_forward_rule_list_req),
+    );
+    cfg.route(
+        "/carry-forward/rule/delete/{id}",
+        web::delete()
+            .to(leave_carry_forward_rule_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/accrual/policy/save",
+        web::post()
+            .to(leave_accrual_policy_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/accrual/policy/list",
+        web::get().to(leave_accrual_policy_list_req),
+    );
+    cfg.route(
+        "/accrual/policy/delete/{id}",
+        web::delete()
+            .to(leave_accrual_policy_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/type/policy/save",
+        web::post()
+            .to(leave_type_policy_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/type/policy/list",
+        web::get().to(leave_type_policy_list_req),
+    );
+    cfg.route(
+        "/type/policy/delete/{id}",
+        web::delete()
+            .to(leave_type_policy_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/blackout/save",
+        web::post()
+            .to(leave_blackout_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route("/blackout/list", web::get().to(leave_blackout_list_req));
+    cfg.route(
+        "/blackout/delete/{id}",
+        web::delete()
+            .to(leave_blackout_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route("/calendar", web::post().to(leave_calendar_req));
+    cfg.route(
+        "/auto-approval/rule/save",
+        web::post()
+            .to(leave_auto_approval_rule_save_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
+    cfg.route(
+        "/auto-approval/rule/list",
+        web::get().to(leave_auto_approval_rule_list_req),
+    );
+    cfg.route(
+        "/auto-approval/rule/delete/{id}",
+        web::delete()
+            .to(leave_auto_approval_rule_delete_req)
+            .wrap(BusinessPermissionValidation {
+                permission_list: vec![PermissionType::CreateLeaveType.to_string()],
+            }),
+    );
 }
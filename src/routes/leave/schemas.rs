@@ -1,7 +1,10 @@
-use crate::{email::EmailObject, errors::GenericError, schemas::Status};
+use crate::{
+    email::EmailObject, errors::GenericError, routes::business::schemas::BusinessUserMembership,
+    schemas::Status,
+};
 use actix_http::Payload;
 use actix_web::{FromRequest, HttpRequest, web};
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, RoundingMode};
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
 use chrono_tz::Tz;
 use futures::future::LocalBoxFuture;
@@ -51,14 +54,23 @@ use super::models::LeaveAllowedDateModel;
 //     }
 // }
 
-#[derive(Deserialize, Debug, ToSchema)]
+#[derive(Deserialize, Debug, ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLeaveData {
     pub period_id: Uuid,
     pub date: chrono::NaiveDate,
 }
 
-#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq)]
+/// A leave date already requested against a user leave. The dates of one request share the
+/// same `created_on`.
+#[derive(Debug, Clone)]
+pub struct ActiveLeaveData {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    pub created_on: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq, Clone)]
 #[sqlx(type_name = "leave_status", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum LeaveStatus {
@@ -66,20 +78,49 @@ pub enum LeaveStatus {
     Rejected,
     Cancelled,
     Requested,
+    InProgress,
+    Expired,
+    CancellationRequested,
 }
 
 #[derive(Deserialize, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLeaveRequest {
-    pub to: EmailObject,
+    /// Overrides the automatically routed approver, requires `override:leave-approver`
+    pub to: Option<EmailObject>,
     pub cc: Option<Vec<EmailObject>>,
     pub reason: Option<String>,
     // pub type_id: Uuid,
     // pub group_id: Uuid,
     pub user_leave_id: Uuid,
     pub user_id: Option<Uuid>,
+    #[serde(default)]
     pub leave_data: Vec<CreateLeaveData>,
+    pub leave_range: Option<CreateLeaveRange>,
     pub send_mail: bool,
+    /// Supporting document, required when the leave type policy asks for one
+    pub attachment_url: Option<String>,
+}
+
+/// Alternate to `leave_data`, expanded server side into one entry per working day.
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLeaveRange {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub period_id: Uuid,
+    pub start_period_id: Option<Uuid>,
+    pub end_period_id: Option<Uuid>,
+}
+
+impl CreateLeaveRange {
+    pub fn label(&self) -> String {
+        if self.start_date == self.end_date {
+            self.start_date.to_string()
+        } else {
+            format!("{} to {}", self.start_date, self.end_date)
+        }
+    }
 }
 
 impl FromRequest for CreateLeaveRequest {
@@ -112,12 +153,14 @@ pub struct BulkLeaveRequestInsert<'a> {
     pub reason: Vec<Option<&'a str>>,
     pub email_message_id: Vec<Option<&'a str>>,
     pub cc: Vec<Option<Value>>,
+    pub attachment_url: Vec<Option<&'a str>>,
 }
 
 #[derive(Serialize)]
 pub struct LeaveRequestEmailContext<'a> {
     sender: &'a str,
     dates: Vec<String>,
+    date_range: Option<String>,
     reason: &'a str,
     receiver: &'a str,
     r#type: &'a str,
@@ -127,6 +170,7 @@ impl<'a> LeaveRequestEmailContext<'a> {
     pub fn new(
         sender: &'a str,
         dates: Vec<String>,
+        date_range: Option<String>,
         reason: &'a str,
         receiver: &'a str,
         r#type: &'a str,
@@ -134,6 +178,7 @@ impl<'a> LeaveRequestEmailContext<'a> {
         Self {
             sender,
             dates,
+            date_range,
             reason,
             receiver,
             r#type,
@@ -164,6 +209,84 @@ impl FromRequest for UpdateLeaveStatusRequest {
     }
 }
 
+/// Fields left out keep their current value on the leave request.
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLeaveRequest {
+    pub id: Uuid,
+    pub date: Option<NaiveDate>,
+    pub period_id: Option<Uuid>,
+    pub reason: Option<String>,
+}
+
+impl FromRequest for UpdateLeaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUpdateLeaveStatusRequest {
+    pub data: Vec<UpdateLeaveStatusRequest>,
+}
+
+impl FromRequest for BulkUpdateLeaveStatusRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveStatusUpdateResult {
+    pub id: Uuid,
+    pub is_success: bool,
+    /// Status the leave request moved to, absent when the update was not applied
+    pub status: Option<LeaveStatus>,
+    pub message: Option<String>,
+}
+
+impl LeaveStatusUpdateResult {
+    pub fn success(id: Uuid, status: LeaveStatus) -> Self {
+        Self {
+            id,
+            is_success: true,
+            status: Some(status),
+            message: None,
+        }
+    }
+
+    pub fn failure(id: Uuid, message: String) -> Self {
+        Self {
+            id,
+            is_success: false,
+            status: None,
+            message: Some(message),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, ToSchema, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LeaveRequestData {
@@ -176,11 +299,23 @@ pub struct LeaveRequestData {
     pub reason: Option<String>,
     pub status: LeaveStatus,
     pub user_id: Uuid,
+    pub receiver_id: Uuid,
     pub email_message_id: Option<String>,
     pub cc: Option<Vec<EmailObject>>,
     pub created_on: Option<DateTime<FixedOffset>>,
     pub leave_type: String,
     pub period: LeavePeriodData,
+    pub attachment_url: Option<String>,
+    pub comment_count: i64,
+}
+
+impl LeaveRequestData {
+    /// The requester, the receiver and the cc'd users take part in the comment thread.
+    pub fn is_participant(&self, user_id: Uuid, email: &EmailObject) -> bool {
+        self.user_id == user_id
+            || self.receiver_id == user_id
+            || self.cc.as_ref().is_some_and(|a| a.contains(email))
+    }
 }
 
 #[derive(Serialize)]
@@ -253,6 +388,9 @@ pub struct FetchLeaveQuery<'a> {
     pub end_date: Option<&'a NaiveDateTime>,
     pub tz: Option<&'a Tz>,
     pub receiver_id: Option<Uuid>,
+    pub business_id: Option<Uuid>,
+    pub leave_id_list: Option<&'a Vec<Uuid>>,
+    pub status_list: Option<&'a Vec<LeaveStatus>>,
 }
 
 impl<'a> FetchLeaveQuery<'a> {
@@ -268,6 +406,9 @@ impl<'a> FetchLeaveQuery<'a> {
             end_date: None,
             tz: None,
             receiver_id: None,
+            business_id: None,
+            leave_id_list: None,
+            status_list: None,
         }
     }
 
@@ -283,6 +424,18 @@ impl<'a> FetchLeaveQuery<'a> {
         self.receiver_id = receiver_id;
         self
     }
+    pub fn with_business_id(mut self, business_id: Option<Uuid>) -> Self {
+        self.business_id = business_id;
+        self
+    }
+    pub fn with_leave_id_list(mut self, leave_id_list: Option<&'a Vec<Uuid>>) -> Self {
+        self.leave_id_list = leave_id_list;
+        self
+    }
+    pub fn with_status_list(mut self, status_list: Option<&'a Vec<LeaveStatus>>) -> Self {
+        self.status_list = status_list;
+        self
+    }
     pub fn with_offset(mut self, offset: Option<i32>) -> Self {
         self.offset = offset;
         self
@@ -342,6 +495,50 @@ pub struct LeaveTypeCreationData {
     pub label: String,
     pub period_id_list: Vec<Uuid>,
     pub allowed_dates: Option<Vec<LeaveAllowedDate>>,
+    pub eligibility: Option<LeaveTypeEligibility>,
+}
+
+/// Restricts who a leave type can be allocated to or requested by, an empty list allows
+/// everyone.
+#[derive(Deserialize, Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypeEligibility {
+    pub min_tenure_days: Option<i32>,
+    #[serde(default)]
+    pub department_id_list: Vec<Uuid>,
+    #[serde(default)]
+    pub role_id_list: Vec<Uuid>,
+}
+
+impl LeaveTypeEligibility {
+    /// Returns why the member is not eligible, `None` when eligible.
+    pub fn ineligibility_reason(
+        &self,
+        membership: &BusinessUserMembership,
+        now: DateTime<Utc>,
+    ) -> Option<String> {
+        if let Some(min_tenure_days) = self
+            .min_tenure_days
+            .filter(|a| (now - membership.joined_on).num_days() < *a as i64)
+        {
+            return Some(format!(
+                "requires a minimum tenure of {} day/s",
+                min_tenure_days
+            ));
+        }
+        if !self.department_id_list.is_empty()
+            && !membership
+                .department_id_list
+                .iter()
+                .any(|a| self.department_id_list.contains(a))
+        {
+            return Some("is not available for the user's department".to_string());
+        }
+        if !self.role_id_list.is_empty() && !self.role_id_list.contains(&membership.role_id) {
+            return Some("is not available for the user's role".to_string());
+        }
+        None
+    }
 }
 
 #[derive(Deserialize, Debug, ToSchema)]
@@ -374,6 +571,7 @@ pub struct BulkLeaveTypeInsert<'a> {
     pub created_by: Vec<Uuid>,
     pub business_id: Vec<Uuid>,
     pub allowed_dates: Vec<Option<Value>>,
+    pub eligibility: Vec<Option<Value>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -383,6 +581,20 @@ pub struct LeaveTypeData {
     pub label: String,
     pub period_list: Vec<LeavePeriodData>,
     pub allowed_dates: Option<Vec<LeaveAllowedDate>>,
+    pub eligibility: Option<LeaveTypeEligibility>,
+}
+
+impl LeaveTypeData {
+    pub fn ineligibility_reason(
+        &self,
+        membership: &BusinessUserMembership,
+        now: DateTime<Utc>,
+    ) -> Option<String> {
+        self.eligibility
+            .as_ref()
+            .and_then(|a| a.ineligibility_reason(membership, now))
+            .map(|reason| format!("{} {}", self.label, reason))
+    }
 }
 
 #[derive(Debug, Serialize, ToSchema, Deserialize)]
@@ -398,6 +610,8 @@ pub struct LeavePeriodData {
 #[serde(rename_all = "camelCase")]
 pub struct LeaveTypeFetchRequest {
     pub query: Option<String>,
+    /// Lists only the leave types the user is eligible for
+    pub eligible_user_id: Option<Uuid>,
 }
 
 impl FromRequest for LeaveTypeFetchRequest {
@@ -457,6 +671,28 @@ pub struct UserLeaveCreationData {
     #[schema(value_type = f64)]
     pub count: BigDecimal,
     pub status: Status,
+    /// Pro-rates `count` by the share of the leave group left after the user joined
+    pub pro_rata: Option<UserLeaveProRataData>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLeaveProRataData {
+    /// Employment start date, defaults to the date the user was associated to the business
+    pub start_date: Option<DateTime<Utc>>,
+    /// Leave period of the leave type whose value the pro-rated count is rounded to
+    pub rounding_period_id: Uuid,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLeaveAllocation {
+    pub type_id: Uuid,
+    #[schema(value_type = String)]
+    pub requested_count: BigDecimal,
+    #[schema(value_type = String)]
+    pub allocated_count: BigDecimal,
+    pub start_date: Option<DateTime<Utc>>,
 }
 
 #[derive(Deserialize, Debug, ToSchema)]
@@ -484,6 +720,47 @@ impl FromRequest for CreateLeaveUserAssociationRequest {
     }
 }
 
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkLeaveUserAssociationRequest {
+    pub group_id: Uuid,
+    /// Departments whose members are allocated, all verified members of the business if not set
+    pub department_id_list: Option<Vec<Uuid>>,
+    /// Business role whose members are allocated
+    pub role_id: Option<Uuid>,
+    pub data: Vec<UserLeaveCreationData>,
+}
+
+impl FromRequest for BulkLeaveUserAssociationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUserLeaveAllocationItem {
+    pub user_id: Uuid,
+    pub type_id: Uuid,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUserLeaveAllocationResult {
+    pub created_list: Vec<BulkUserLeaveAllocationItem>,
+    pub skipped_list: Vec<BulkUserLeaveAllocationItem>,
+}
+
 #[derive(Deserialize, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ListLeaveUserAssociationRequest {
@@ -535,6 +812,31 @@ pub struct UserLeave {
     pub leave_group: UserLeaveGroup,
     pub periods: Vec<LeavePeriodData>,
     pub allowed_dates: Option<Vec<LeaveAllowedDate>>,
+    pub policy: Option<LeaveTypePolicy>,
+}
+
+impl UserLeave {
+    pub fn allows_negative_balance(&self) -> bool {
+        self.policy
+            .as_ref()
+            .is_some_and(|a| a.allow_negative_balance)
+    }
+
+    /// Whether `count` more days can be used, allowing the negative balance permitted by the
+    /// leave type policy.
+    pub fn can_use(&self, count: &BigDecimal) -> bool {
+        let balance = &self.allocated_count - &self.used_count - count;
+        if balance >= BigDecimal::default() {
+            return true;
+        }
+        match self.policy.as_ref() {
+            Some(policy) if policy.allow_negative_balance => policy
+                .max_negative_balance
+                .as_ref()
+                .is_none_or(|a| -balance <= *a),
+            _ => false,
+        }
+    }
 }
 
 // #[derive(Deserialize, Debug, ToSchema)]
@@ -625,3 +927,735 @@ pub struct BulkLeaveTypePeriodInsert<'a> {
     pub created_on: Vec<DateTime<Utc>>,
     pub created_by: Vec<Uuid>,
 }
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayCreationData {
+    pub id: Option<Uuid>,
+    pub label: String,
+    pub date: NaiveDate,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayCreationRequest {
+    pub data: Vec<HolidayCreationData>,
+}
+
+impl FromRequest for HolidayCreationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkHolidayInsert<'a> {
+    pub id: Vec<Uuid>,
+    pub label: Vec<&'a str>,
+    pub date: Vec<NaiveDate>,
+    pub created_on: Vec<DateTime<Utc>>,
+    pub created_by: Vec<Uuid>,
+    pub business_id: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayFetchRequest {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+impl FromRequest for HolidayFetchRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayData {
+    pub id: Uuid,
+    pub label: String,
+    pub date: NaiveDate,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq, Clone)]
+#[sqlx(type_name = "leave_approval_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveApprovalStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveApprovalStageData {
+    pub label: String,
+    pub approver_role_id: Uuid,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveApprovalStageSaveRequest {
+    pub leave_type_id: Option<Uuid>,
+    pub stage_list: Vec<LeaveApprovalStageData>,
+}
+
+impl FromRequest for LeaveApprovalStageSaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveApprovalStageFetchRequest {
+    pub leave_type_id: Option<Uuid>,
+}
+
+impl FromRequest for LeaveApprovalStageFetchRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveApprovalStage {
+    pub id: Uuid,
+    pub leave_type_id: Option<Uuid>,
+    pub stage_order: i32,
+    pub label: String,
+    pub approver_role_id: Uuid,
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveApproval {
+    pub id: Uuid,
+    pub leave_id: Uuid,
+    pub stage_order: i32,
+    pub label: String,
+    pub approver_role_id: Uuid,
+    pub status: LeaveApprovalStatus,
+    pub acted_by: Option<Uuid>,
+    pub acted_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingLeaveApprovalData {
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub leave_list: Vec<LeaveRequestData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq, Clone)]
+#[sqlx(type_name = "leave_ledger_entry_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveLedgerEntryType {
+    Allocation,
+    Usage,
+    Reversal,
+    Adjustment,
+    CarryForward,
+    Encashment,
+}
+
+/// A single change to be appended to the leave ledger of a user leave.
+#[derive(Debug)]
+pub struct LeaveLedgerEntryData<'a> {
+    pub user_leave_id: Uuid,
+    pub entry_type: LeaveLedgerEntryType,
+    pub allocated_change: BigDecimal,
+    pub used_change: BigDecimal,
+    pub leave_id: Option<Uuid>,
+    pub reason: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveLedgerEntry {
+    pub id: Uuid,
+    pub user_leave_id: Uuid,
+    pub entry_type: LeaveLedgerEntryType,
+    #[schema(value_type = String)]
+    pub allocated_change: BigDecimal,
+    #[schema(value_type = String)]
+    pub used_change: BigDecimal,
+    pub leave_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub created_by: Uuid,
+    pub created_on: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLeaveLedger {
+    pub user_leave_id: Uuid,
+    pub leave_type: UserLeaveType,
+    #[schema(value_type = String)]
+    pub allocated_count: BigDecimal,
+    #[schema(value_type = String)]
+    pub used_count: BigDecimal,
+    /// Whether the stored counts match the totals derived from the ledger
+    pub is_reconciled: bool,
+    pub entry_list: Vec<LeaveLedgerEntry>,
+}
+
+#[derive(Deserialize, Debug, ToSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveBalanceAdjustmentTarget {
+    Allocated,
+    Used,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalanceAdjustmentRequest {
+    pub user_id: Uuid,
+    pub user_leave_id: Uuid,
+    pub target: LeaveBalanceAdjustmentTarget,
+    /// Added to the targeted count, a negative amount debits it
+    #[schema(value_type = f64)]
+    pub amount: BigDecimal,
+    pub reason: String,
+}
+
+impl FromRequest for LeaveBalanceAdjustmentRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCarryForwardRuleData {
+    pub leave_type_id: Uuid,
+    /// Largest balance moved into the next leave group
+    #[schema(value_type = f64)]
+    pub max_carry_forward: BigDecimal,
+    /// Days after the start of the next leave group after which unused carried days expire
+    pub expiry_days: Option<i32>,
+    /// Whether the balance above `max_carry_forward` lapses, otherwise it is encashed
+    pub lapse_remaining: bool,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCarryForwardRuleSaveRequest {
+    pub data: Vec<LeaveCarryForwardRuleData>,
+}
+
+impl FromRequest for LeaveCarryForwardRuleSaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCarryForwardRule {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    #[schema(value_type = String)]
+    pub max_carry_forward: BigDecimal,
+    pub expiry_days: Option<i32>,
+    pub lapse_remaining: bool,
+}
+
+impl LeaveCarryForwardRule {
+    /// Splits a closing balance into the part carried forward and the part that lapses or is
+    /// encashed.
+    pub fn split_balance(&self, balance: &BigDecimal) -> (BigDecimal, BigDecimal) {
+        if balance <= &BigDecimal::default() {
+            return (BigDecimal::default(), BigDecimal::default());
+        }
+        let carry = balance.min(&self.max_carry_forward).to_owned();
+        let remaining = balance - &carry;
+        (carry, remaining)
+    }
+}
+
+#[derive(Serialize, Debug, Default, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveRolloverSummary {
+    pub carried_count: usize,
+    pub encashed_count: usize,
+    pub lapsed_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq, Clone)]
+#[sqlx(type_name = "leave_accrual_frequency", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveAccrualFrequency {
+    Monthly,
+    Quarterly,
+    HalfYearly,
+    Yearly,
+}
+
+impl LeaveAccrualFrequency {
+    pub fn months(&self) -> u32 {
+        match self {
+            LeaveAccrualFrequency::Monthly => 1,
+            LeaveAccrualFrequency::Quarterly => 3,
+            LeaveAccrualFrequency::HalfYearly => 6,
+            LeaveAccrualFrequency::Yearly => 12,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAccrualPolicyData {
+    pub leave_type_id: Uuid,
+    /// Days credited at the start of every accrual period
+    #[schema(value_type = f64)]
+    pub amount: BigDecimal,
+    pub frequency: LeaveAccrualFrequency,
+    /// Largest available balance a user leave can reach through accrual
+    #[schema(value_type = Option<f64>)]
+    pub max_balance: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAccrualPolicySaveRequest {
+    pub data: Vec<LeaveAccrualPolicyData>,
+}
+
+impl FromRequest for LeaveAccrualPolicySaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAccrualPolicy {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    #[schema(value_type = String)]
+    pub amount: BigDecimal,
+    pub frequency: LeaveAccrualFrequency,
+    #[schema(value_type = Option<String>)]
+    pub max_balance: Option<BigDecimal>,
+}
+
+impl LeaveAccrualPolicy {
+    /// Returns the days to credit for an accrual, limited so that the balance stays within
+    /// `max_balance`.
+    pub fn get_credit(&self, balance: &BigDecimal) -> BigDecimal {
+        let Some(max_balance) = self.max_balance.as_ref() else {
+            return self.amount.clone();
+        };
+        let room = max_balance - balance;
+        if room <= BigDecimal::default() {
+            BigDecimal::default()
+        } else {
+            room.min(self.amount.clone())
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct LeaveAccrualSummary {
+    pub accrual_count: usize,
+    pub credited_count: usize,
+}
+
+#[derive(Deserialize, Debug, ToSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveAllocationChangeType {
+    Percentage,
+    Fixed,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAllocationChange {
+    pub change_type: LeaveAllocationChangeType,
+    /// Percentage or number of days added to every allocation, negative values reduce it
+    #[schema(value_type = f64)]
+    pub value: BigDecimal,
+}
+
+impl LeaveAllocationChange {
+    pub fn apply(&self, count: &BigDecimal) -> BigDecimal {
+        let changed_count = match self.change_type {
+            LeaveAllocationChangeType::Percentage => {
+                count + count * &self.value / BigDecimal::from(100)
+            }
+            LeaveAllocationChangeType::Fixed => count + &self.value,
+        };
+        if changed_count < BigDecimal::default() {
+            BigDecimal::default()
+        } else {
+            changed_count.with_scale_round(1, RoundingMode::HalfUp)
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveGroupCloneRequest {
+    pub source_group_id: Uuid,
+    pub label: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub allocation_change: Option<LeaveAllocationChange>,
+    /// Carries balances forward as per the carry forward rules of the leave types
+    #[serde(default)]
+    pub carry_forward: bool,
+}
+
+impl FromRequest for LeaveGroupCloneRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveGroupCloneResult {
+    pub group_id: Uuid,
+    pub allocation_count: usize,
+    /// Allocations left out as the user is no longer eligible for the leave type
+    pub skipped_list: Vec<BulkUserLeaveAllocationItem>,
+    pub rollover: Option<LeaveRolloverSummary>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypePolicyData {
+    pub leave_type_id: Uuid,
+    /// Days in advance a leave has to be requested
+    pub min_notice_days: Option<i32>,
+    /// Longest run of working days in a single leave request
+    pub max_consecutive_days: Option<i32>,
+    /// Leave days that can be requested within a calendar month
+    pub max_requests_per_month: Option<i32>,
+    pub allow_negative_balance: bool,
+    /// Largest negative balance allowed, unlimited if not set
+    #[schema(value_type = Option<f64>)]
+    pub max_negative_balance: Option<BigDecimal>,
+    pub allow_backdated: bool,
+    pub require_attachment: bool,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypePolicySaveRequest {
+    pub data: Vec<LeaveTypePolicyData>,
+}
+
+impl FromRequest for LeaveTypePolicySaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypePolicy {
+    pub id: Uuid,
+    pub leave_type_id: Uuid,
+    pub min_notice_days: Option<i32>,
+    pub max_consecutive_days: Option<i32>,
+    pub max_requests_per_month: Option<i32>,
+    pub allow_negative_balance: bool,
+    #[schema(value_type = Option<String>)]
+    pub max_negative_balance: Option<BigDecimal>,
+    pub allow_backdated: bool,
+    pub require_attachment: bool,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBlackoutCreationRequest {
+    pub id: Option<Uuid>,
+    /// Limits the blackout to the members of a department, applies to the whole business if not set
+    pub department_id: Option<Uuid>,
+    pub reason: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Leave types affected by the blackout, all leave types if empty
+    #[serde(default)]
+    pub leave_type_id_list: Vec<Uuid>,
+}
+
+impl FromRequest for LeaveBlackoutCreationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBlackout {
+    pub id: Uuid,
+    pub department_id: Option<Uuid>,
+    pub reason: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub leave_type_id_list: Vec<Uuid>,
+}
+
+impl LeaveBlackout {
+    /// Whether a leave of the given type on `date` by a member of the departments falls within
+    /// the blackout.
+    pub fn covers(
+        &self,
+        date: NaiveDate,
+        leave_type_id: Uuid,
+        department_id_list: &[Uuid],
+    ) -> bool {
+        date >= self.start_date
+            && date <= self.end_date
+            && (self.leave_type_id_list.is_empty()
+                || self.leave_type_id_list.contains(&leave_type_id))
+            && self
+                .department_id
+                .is_none_or(|a| department_id_list.contains(&a))
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCalendarFetchRequest {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    /// Lists the blackouts of the whole business and of this department only
+    pub department_id: Option<Uuid>,
+}
+
+impl FromRequest for LeaveCalendarFetchRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCalendar {
+    pub holiday_list: Vec<HolidayData>,
+    pub blackout_list: Vec<LeaveBlackout>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAutoApprovalRuleData {
+    pub id: Option<Uuid>,
+    pub label: String,
+    /// Leave type the rule applies to, all leave types if not set
+    pub leave_type_id: Option<Uuid>,
+    /// Largest number of leave days in a request approved by the rule
+    #[schema(value_type = Option<f64>)]
+    pub max_days: Option<BigDecimal>,
+    /// Days in advance the request has to be made for the rule to apply
+    pub min_notice_days: Option<i32>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAutoApprovalRuleSaveRequest {
+    pub data: Vec<LeaveAutoApprovalRuleData>,
+}
+
+impl FromRequest for LeaveAutoApprovalRuleSaveRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAutoApprovalRule {
+    pub id: Uuid,
+    pub label: String,
+    pub leave_type_id: Option<Uuid>,
+    #[schema(value_type = Option<String>)]
+    pub max_days: Option<BigDecimal>,
+    pub min_notice_days: Option<i32>,
+}
+
+impl LeaveAutoApprovalRule {
+    pub fn matches(&self, leave_type_id: Uuid, leave_count: &BigDecimal, notice_days: i64) -> bool {
+        self.leave_type_id.is_none_or(|a| a == leave_type_id)
+            && self.max_days.as_ref().is_none_or(|a| leave_count <= a)
+            && self.min_notice_days.is_none_or(|a| notice_days >= a as i64)
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveRequestCreationResult {
+    pub status: LeaveStatus,
+    /// Rule which approved the request on creation
+    pub auto_approval_rule: Option<LeaveAutoApprovalRule>,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, PartialEq, Clone)]
+#[sqlx(type_name = "leave_reminder_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveReminderType {
+    Reminder,
+    Escalation,
+    Expiry,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveCommentCreationRequest {
+    pub leave_id: Uuid,
+    pub comment: String,
+}
+
+impl FromRequest for LeaveCommentCreationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveComment {
+    pub id: Uuid,
+    pub leave_id: Uuid,
+    pub comment: String,
+    pub created_by: Uuid,
+    pub display_name: String,
+    pub created_on: DateTime<Utc>,
+}
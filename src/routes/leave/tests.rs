@@ -3,51 +3,84 @@ pub mod tests {
     use crate::{
         constants::{DUMMY_INTERNATIONAL_DIALING_CODE, DUMMY_TIMEZONE},
         email::EmailObject,
+        pulsar_client::SchedulerMessageType,
         routes::{
-            business::tests::tests::setup_business,
+            business::{
+                schemas::{BusinessLocation, BusinessUserMembership, WeekDay},
+                tests::tests::setup_business,
+            },
             leave::{
                 schemas::{
-                    CreateLeaveData, CreateLeaveRequest, FetchLeaveQuery,
-                    LeaveGroupCreationRequest, LeavePeriodCreationData, LeavePeriodData,
-                    LeaveStatus, LeaveTypeCreationData, UserLeave, UserLeaveCreationData,
+                    ActiveLeaveData, CreateLeaveData, CreateLeaveRange, CreateLeaveRequest,
+                    FetchLeaveQuery, HolidayData, LeaveAccrualFrequency, LeaveAccrualPolicy,
+                    LeaveAllocationChange, LeaveAllocationChangeType, LeaveApproval,
+                    LeaveApprovalStatus, LeaveAutoApprovalRule, LeaveBalanceAdjustmentRequest,
+                    LeaveBalanceAdjustmentTarget, LeaveBlackout, LeaveBlackoutCreationRequest,
+                    LeaveCarryForwardRule, LeaveGroup, LeaveGroupCreationRequest,
+                    LeaveLedgerEntryType, LeavePeriodCreationData, LeavePeriodData,
+                    LeaveRequestData, LeaveStatus, LeaveTypeCreationData, LeaveTypeData,
+                    LeaveTypeEligibility, LeaveTypePolicy, UserLeave, UserLeaveCreationData,
                     UserLeaveGroup, UserLeaveType,
                 },
                 utils::{
+                    build_bulk_user_leave_allocation_result,
+                    build_user_leave_ledger,
+                    compute_carry_forward_lapse,
+                    compute_pro_rata_count,
                     delete_leave,
                     delete_leave_group,
                     delete_leave_period,
                     delete_leave_type,
                     delete_user_leave,
+                    expand_leave_range,
                     fetch_user_leaves,
+                    find_leave_auto_approval_rule,
+                    find_leave_blackout,
+                    get_accrual_date_list,
                     get_leave_group,
+                    get_leave_ledger,
                     get_leave_period,
+                    get_leave_reminder_schedule,
+                    get_leave_request_data,
                     get_leave_type,
                     get_leaves,
+                    group_pending_leave_approvals,
+                    partition_eligible_user_id_list,
+                    pick_leave_approver,
+                    prepare_bulk_leave_request_data,
+                    resolve_leave_approval,
+                    save_leave_blackout,
                     save_leave_group,
                     save_leave_period,
                     save_leave_request,
                     save_leave_type,
                     save_user_leave,
+                    update_leave_request,
                     update_leave_request_status,
+                    validate_leave_balance_adjustment,
                     validate_leave_request_creation,
+                    validate_leave_request_rules,
+                    validate_leave_type_policy,
                     validate_leave_status_update, //  delete_leave, get_leaves,
                                                   // save_leave_request, update_leave_status,
                                                   // validate_leave_request, validate_leave_status_update,
                 },
             },
             user::{
+                schemas::MinimalUserAccount,
                 tests::tests::setup_user,
                 utils::{hard_delete_business_account, hard_delete_user_account},
             },
         },
-        schemas::{AllowedPermission, PermissionType, Status},
+        schemas::{AllowedPermission, MobileNoInfo, PermissionType, Status},
         tests::tests::get_test_pool,
     };
     use anyhow::Context;
     use bigdecimal::{BigDecimal, FromPrimitive};
-    use chrono::{DateTime, Duration, NaiveDate, Utc};
+    use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
     use chrono_tz::Tz;
     use sqlx::PgPool;
+    use std::collections::HashMap;
     use tokio::join;
     use uuid::Uuid;
     #[tokio::test]
@@ -261,12 +294,14 @@ pub mod tests {
                 label: "Casual Leave".to_string(),
                 period_id_list: vec![period_id],
                 allowed_dates: None,
+                eligibility: None,
             },
             LeaveTypeCreationData {
                 id: None,
                 label: "Restricted Leave".to_string(),
                 period_id_list: vec![period_id],
                 allowed_dates: None,
+                eligibility: None,
             },
         ];
         let mut transaction = pool
@@ -303,6 +338,7 @@ pub mod tests {
             label: "Medical Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
         let mut transaction = pool
             .begin()
@@ -376,6 +412,7 @@ pub mod tests {
             label: "Casual Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
         let mut transaction = pool
             .begin()
@@ -419,8 +456,17 @@ pub mod tests {
             type_id: leave_type_id,
             count: BigDecimal::from_i32(23).unwrap(),
             status: Status::Active,
+            pro_rata: None,
         }];
-        let res = save_user_leave(&pool, &user_leave_data, user_id, leave_group_id, user_id).await;
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
         assert!(res.is_ok());
 
         let user_leave_res =
@@ -446,6 +492,146 @@ pub mod tests {
         assert!(delete_user_account_res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_user_leave_ledger() {
+        let pool = get_test_pool().await;
+        let email = "testuser82@example.com";
+        let mobile_no = "1234575906";
+        let user_res = setup_user(&pool, "testuser82", email, mobile_no, "testuser@123").await;
+        assert!(user_res.is_ok());
+        let user_id = user_res.unwrap();
+        let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
+        let business_id = business_res.unwrap();
+
+        let start_date = Utc::now();
+        let end_date = start_date + Duration::days(2);
+        let period_id =
+            create_leave_period_and_get_id(&pool, "Full Day", 1.0, user_id, business_id)
+                .await
+                .unwrap();
+        let leave_group_data = LeaveGroupCreationRequest {
+            id: None,
+            label: "2025".to_string(),
+            start_date,
+            end_date,
+        };
+        let leave_type_data = vec![LeaveTypeCreationData {
+            id: None,
+            label: "Casual Leave".to_string(),
+            period_id_list: vec![period_id],
+            allowed_dates: None,
+            eligibility: None,
+        }];
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+
+        let (save_group_res, save_type_res) = tokio::join!(
+            save_leave_group(&pool, &leave_group_data, business_id, user_id),
+            save_leave_type(
+                &mut transaction,
+                &leave_type_data,
+                user_id,
+                user_id,
+                business_id
+            )
+        );
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to save leave type..")
+            .unwrap();
+        assert!(save_group_res.is_ok());
+        assert!(save_type_res.is_ok());
+        let leave_group_id = save_group_res.unwrap();
+
+        let leave_type_res = get_leave_type(
+            &pool,
+            business_id,
+            None,
+            Some(vec!["Casual Leave", "Restricted Leave"]),
+            None,
+        )
+        .await;
+        assert!(leave_type_res.is_ok());
+        let leave_type_list = leave_type_res.unwrap();
+        assert!(leave_type_list.first().is_some());
+        let leave_type_id = leave_type_list.first().unwrap().id;
+
+        let user_leave_data = vec![UserLeaveCreationData {
+            type_id: leave_type_id,
+            count: BigDecimal::from_i32(23).unwrap(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let user_leave_res =
+            fetch_user_leaves(&pool, business_id, user_id, Some(leave_group_id), None).await;
+        assert!(user_leave_res.is_ok());
+        let user_leave_opt = user_leave_res.unwrap();
+
+        assert!(user_leave_opt.first().is_some());
+
+        let user_leave_id_list = vec![user_leave_opt.first().unwrap().id];
+        let ledger_res = get_leave_ledger(&pool, &user_leave_id_list).await;
+        assert!(ledger_res.is_ok());
+        let ledger = ledger_res.unwrap();
+        assert!(ledger.len() == 1);
+        assert!(ledger[0].entry_type == LeaveLedgerEntryType::Allocation);
+        assert!(ledger[0].allocated_change == BigDecimal::from_i32(23).unwrap());
+
+        let user_leave_data = vec![UserLeaveCreationData {
+            type_id: leave_type_id,
+            count: BigDecimal::from_i32(25).unwrap(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
+        assert!(res.is_ok());
+        let ledger = get_leave_ledger(&pool, &user_leave_id_list).await.unwrap();
+        assert!(ledger.len() == 2);
+        assert!(ledger[1].allocated_change == BigDecimal::from_i32(2).unwrap());
+        let user_leave_opt =
+            fetch_user_leaves(&pool, business_id, user_id, Some(leave_group_id), None)
+                .await
+                .unwrap();
+        let user_leave_ledger = build_user_leave_ledger(user_leave_opt, ledger);
+        assert!(user_leave_ledger.len() == 1);
+        assert!(user_leave_ledger[0].is_reconciled);
+        assert!(user_leave_ledger[0].entry_list.len() == 2);
+
+        let del_res = delete_user_leave(&pool, business_id, user_leave_id_list[0]).await;
+        assert!(del_res.is_ok());
+
+        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &delete_mobile)
+        );
+        assert!(delete_business_account_res.is_ok());
+        assert!(delete_user_account_res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_leave_request_creation_validation() {
         let pool = get_test_pool().await;
@@ -467,6 +653,7 @@ pub mod tests {
             label: "Casual Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
 
         let mut transaction = pool
@@ -518,8 +705,17 @@ pub mod tests {
             type_id: leave_type_id,
             count: BigDecimal::from_i32(1).unwrap(),
             status: Status::Active,
+            pro_rata: None,
         }];
-        let res = save_user_leave(&pool, &user_leave_data, user_id, leave_group_id, user_id).await;
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
 
         assert!(res.is_ok());
 
@@ -536,19 +732,64 @@ pub mod tests {
             period_id,
         }];
         let mut leave_request = CreateLeaveRequest {
-            to: EmailObject::new(email.to_string()),
+            to: Some(EmailObject::new(email.to_string())),
             cc: None,
             reason: None,
             user_id: Some(user_id),
             leave_data,
             user_leave_id: user_leave.id,
+            leave_range: None,
             send_mail: false,
+            attachment_url: None,
         };
 
-        let leave_request_validation = validate_leave_request_creation(&leave_request, user_leave);
+        let leave_request_validation =
+            validate_leave_request_creation(&leave_request.leave_data, user_leave, &[], None);
         print!("Leave request validation: {:?}", leave_request_validation);
         assert!(leave_request_validation.is_ok());
 
+        let holiday_list = vec![HolidayData {
+            id: Uuid::new_v4(),
+            label: "Founders Day".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+        }];
+        let leave_request_validation = validate_leave_request_creation(
+            &leave_request.leave_data,
+            user_leave,
+            &holiday_list,
+            None,
+        );
+        assert!(leave_request_validation.is_err());
+
+        let mut location = BusinessLocation {
+            id: Uuid::new_v4(),
+            name: "Dubai".to_string(),
+            time_zone: "Asia/Dubai".to_string(),
+            work_week: vec![
+                WeekDay::Monday,
+                WeekDay::Tuesday,
+                WeekDay::Wednesday,
+                WeekDay::Thursday,
+                WeekDay::Friday,
+            ],
+        };
+        let leave_request_validation = validate_leave_request_creation(
+            &leave_request.leave_data,
+            user_leave,
+            &[],
+            Some(&location),
+        );
+        assert!(leave_request_validation.is_ok());
+
+        location.work_week = vec![WeekDay::Sunday, WeekDay::Monday, WeekDay::Tuesday];
+        let leave_request_validation = validate_leave_request_creation(
+            &leave_request.leave_data,
+            user_leave,
+            &[],
+            Some(&location),
+        );
+        assert!(leave_request_validation.is_err());
+
         let leave_data = vec![
             CreateLeaveData {
                 date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
@@ -560,7 +801,8 @@ pub mod tests {
             },
         ];
         leave_request.leave_data = leave_data;
-        let leave_request_validation = validate_leave_request_creation(&leave_request, &user_leave);
+        let leave_request_validation =
+            validate_leave_request_creation(&leave_request.leave_data, &user_leave, &[], None);
         assert!(leave_request_validation.is_err());
 
         let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
@@ -593,6 +835,7 @@ pub mod tests {
             label: "Casual Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
         let mut transaction = pool
             .begin()
@@ -642,8 +885,17 @@ pub mod tests {
             type_id: leave_type_id,
             count: BigDecimal::from_i32(5).unwrap(),
             status: Status::Active,
+            pro_rata: None,
         }];
-        let res = save_user_leave(&pool, &user_leave_data, user_id, leave_group_id, user_id).await;
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
 
         assert!(res.is_ok());
 
@@ -660,13 +912,15 @@ pub mod tests {
             date: NaiveDate::from_ymd_opt(2025, 7, 4).expect("invalid date"),
         }];
         let leave_request = CreateLeaveRequest {
-            to: EmailObject::new(email.to_string()),
+            to: Some(EmailObject::new(email.to_string())),
             cc: None,
             reason: None,
             user_id: Some(user_id),
             leave_data,
             user_leave_id: user_leave.id,
+            leave_range: None,
             send_mail: false,
+            attachment_url: None,
         };
         let mut transaction = pool
             .begin()
@@ -677,6 +931,7 @@ pub mod tests {
         let res = save_leave_request(
             &mut transaction,
             &leave_request,
+            &leave_request.leave_data,
             user_leave.id,
             user_id,
             Uuid::new_v4(),
@@ -714,34 +969,33 @@ pub mod tests {
     }
 
     #[tokio::test]
-    async fn test_leave_request_fetch() {
+    async fn test_leave_request_modification() {
         let pool = get_test_pool().await;
-        let email = "testuser21@example.com";
-        let mobile_no = "1234567904";
-        let user_res = setup_user(&pool, "testuser21", email, mobile_no, "testuser@123").await;
+        let email = "testuser83@example.com";
+        let mobile_no = "2234567901";
+        let user_res = setup_user(&pool, "testuser83", email, mobile_no, "testuser@123").await;
         assert!(user_res.is_ok());
         let user_id = user_res.unwrap();
         let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
         let business_id = business_res.unwrap();
-
-        let start_date = Utc::now();
-        let end_date = start_date + Duration::days(2);
         let period_id =
             create_leave_period_and_get_id(&pool, "Full Day", 1.0, user_id, business_id)
                 .await
                 .unwrap();
+        let start_date = Utc::now();
+        let end_date = start_date + Duration::days(2);
         let leave_type_data = vec![LeaveTypeCreationData {
             id: None,
             label: "Casual Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
         let mut transaction = pool
             .begin()
             .await
             .context("Failed to acquire a Postgres connection from the pool")
             .unwrap();
-
         let (save_group_res, save_type_res) = tokio::join!(
             create_test_leave_group(
                 &pool,
@@ -762,7 +1016,7 @@ pub mod tests {
         transaction
             .commit()
             .await
-            .context("Failed to commit SQL transaction to create leave type.")
+            .context("Failed to commit SQL transaction to save leave type..")
             .unwrap();
         assert!(save_group_res.is_ok());
         assert!(save_type_res.is_ok());
@@ -785,8 +1039,17 @@ pub mod tests {
             type_id: leave_type_id,
             count: BigDecimal::from_i32(5).unwrap(),
             status: Status::Active,
+            pro_rata: None,
         }];
-        let res = save_user_leave(&pool, &user_leave_data, user_id, leave_group_id, user_id).await;
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
 
         assert!(res.is_ok());
 
@@ -798,82 +1061,82 @@ pub mod tests {
         assert!(user_leave_opt.first().is_some());
         let user_leave = user_leave_opt.first().unwrap();
 
-        let leave_data = vec![
-            CreateLeaveData {
-                date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
-                period_id,
-            },
-            CreateLeaveData {
-                period_id,
-                date: NaiveDate::from_ymd_opt(2025, 7, 4).expect("invalid date"),
-            },
-        ];
+        let leave_data = vec![CreateLeaveData {
+            period_id,
+            date: NaiveDate::from_ymd_opt(2025, 7, 4).expect("invalid date"),
+        }];
         let leave_request = CreateLeaveRequest {
-            to: EmailObject::new(email.to_string()),
+            to: Some(EmailObject::new(email.to_string())),
             cc: None,
             reason: None,
             user_id: Some(user_id),
             leave_data,
             user_leave_id: user_leave.id,
+            leave_range: None,
             send_mail: false,
+            attachment_url: None,
         };
         let mut transaction = pool
             .begin()
             .await
             .context("Failed to acquire a Postgres connection from the pool")
             .unwrap();
-        let receiver_id = Uuid::new_v4();
+
         let res = save_leave_request(
             &mut transaction,
             &leave_request,
+            &leave_request.leave_data,
             user_leave.id,
             user_id,
-            receiver_id,
+            Uuid::new_v4(),
             Some("abc@gmail.com"),
         )
         .await;
         transaction
             .commit()
             .await
-            .context("Failed to commit SQL transaction to store a new user account.")
+            .context("Failed to commit SQL transaction to create leave type.")
             .unwrap();
         assert!(res.is_ok());
-        let query = FetchLeaveQuery::builder().with_sender_id(Some(user_id));
-        let leave_with_sender = get_leaves(&pool, &query).await;
-        assert!(leave_with_sender.is_ok());
-        let leave_vec = leave_with_sender.unwrap();
-        let leave_opt = leave_vec.first();
-        assert!(leave_opt.is_some());
-        let tz: Tz = DUMMY_TIMEZONE.parse().unwrap();
-        let start_utc: DateTime<Utc> = Utc::now();
-        let end_utc = start_utc - Duration::minutes(3);
-        let start_in_tz = start_utc.with_timezone(&tz);
-        let end_in_tz = end_utc.with_timezone(&tz);
-
-        let start_naive = start_in_tz.naive_local();
-        let end_naive = end_in_tz.naive_local();
-        let query = FetchLeaveQuery::builder()
-            .with_limit(Some(1))
-            .with_offset(Some(0))
-            .with_start_date(Some(&start_naive))
-            .with_end_date(Some(&end_naive));
-        let leave_with_start_and_end_date = get_leaves(&pool, &query).await;
-        assert!(leave_with_start_and_end_date.is_ok());
-        let leave_vec = leave_with_start_and_end_date.unwrap();
-        let leave_opt = leave_vec.first();
-        assert!(leave_opt.is_some());
-        let query = FetchLeaveQuery::builder().with_leave_id(Some(leave_opt.unwrap().id));
-        let leave_with_id = get_leaves(&pool, &query).await;
-        assert!(leave_with_id.is_ok());
-        let leave_vec = leave_with_id.unwrap();
+        let query: FetchLeaveQuery<'_> = FetchLeaveQuery::builder().with_sender_id(Some(user_id));
+        let leaves = get_leaves(&pool, &query).await;
+        assert!(leaves.is_ok());
+        let leave_vec = leaves.unwrap();
         let leave_opt = leave_vec.first();
         assert!(leave_opt.is_some());
-        let query = FetchLeaveQuery::builder().with_recevier_id(Some(receiver_id));
-        let leave_with_receiver_id = get_leaves(&pool, &query).await;
-        assert!(leave_with_receiver_id.is_ok());
-        let leave_vec = leave_with_receiver_id.unwrap();
+        let leave_id = leave_opt.unwrap().id;
+        let modified_date = NaiveDate::from_ymd_opt(2025, 7, 8).expect("invalid date");
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+        let res = update_leave_request(
+            &mut transaction,
+            leave_id,
+            modified_date,
+            period_id,
+            Some("Family function"),
+            user_id,
+        )
+        .await;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to modify leave request.")
+            .unwrap();
+        assert!(res.is_ok_and(|is_updated| is_updated));
+        let query = FetchLeaveQuery::builder().with_leave_id(Some(leave_id));
+        let leaves = get_leaves(&pool, &query).await;
+        assert!(leaves.is_ok());
+        let leave_vec = leaves.unwrap();
         let leave_opt = leave_vec.first();
-        assert!(leave_opt.is_some());
+        assert!(
+            leave_opt.is_some_and(|a| a.date.date_naive() == modified_date
+                && a.reason.as_deref() == Some("Family function"))
+        );
+        let deleted = delete_leave(&pool, leave_id, user_id).await;
+        assert!(deleted.is_ok());
 
         let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
         let (delete_business_account_res, delete_user_account_res) = tokio::join!(
@@ -884,151 +1147,236 @@ pub mod tests {
         assert!(delete_user_account_res.is_ok());
     }
 
-    fn get_dummy_user_leave_data(
-        user_id: Uuid,
-        used_count: BigDecimal,
-        allocated_count: BigDecimal,
-    ) -> UserLeave {
-        UserLeave {
-            id: Uuid::new_v4(),
-            allocated_count,
-            used_count,
-            business_id: user_id,
-            user_id,
-            leave_type: UserLeaveType {
-                id: Uuid::new_v4(),
-                label: "Casual Leave".to_owned(),
-            },
-            leave_group: UserLeaveGroup {
-                id: Uuid::new_v4(),
-                label: "2025".to_owned(),
-            },
-            periods: vec![LeavePeriodData {
-                id: Uuid::new_v4(),
-                label: "Full Day".to_string(),
-                value: BigDecimal::from_i32(1).unwrap(),
-            }],
-            allowed_dates: None,
-        }
-    }
-
     #[tokio::test]
-    async fn test_leave_request_status_validation() {
-        let mut dummy_user_leave = get_dummy_user_leave_data(
-            Uuid::new_v4(),
-            BigDecimal::from_i32(2).unwrap(),
-            BigDecimal::from_i32(5).unwrap(),
+    async fn test_leave_request_modification_into_blackout() {
+        let pool = get_test_pool().await;
+        let email = "testuser84@example.com";
+        let mobile_no = "2234567902";
+        let user_res = setup_user(&pool, "testuser84", email, mobile_no, "testuser@123").await;
+        assert!(user_res.is_ok());
+        let user_id = user_res.unwrap();
+        let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
+        let business_id = business_res.unwrap();
+        let period_id =
+            create_leave_period_and_get_id(&pool, "Full Day", 1.0, user_id, business_id)
+                .await
+                .unwrap();
+        let start_date = Utc::now();
+        let end_date = start_date + Duration::days(2);
+        let leave_type_data = vec![LeaveTypeCreationData {
+            id: None,
+            label: "Casual Leave".to_string(),
+            period_id_list: vec![period_id],
+            allowed_dates: None,
+            eligibility: None,
+        }];
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+        let (save_group_res, save_type_res) = tokio::join!(
+            create_test_leave_group(
+                &pool,
+                business_id,
+                user_id,
+                "2025".to_string(),
+                start_date,
+                end_date
+            ),
+            save_leave_type(
+                &mut transaction,
+                &leave_type_data,
+                user_id,
+                user_id,
+                business_id
+            )
         );
-        let period = LeavePeriodData {
-            id: Uuid::new_v4(),
-            label: "Full Day".to_string(),
-            value: BigDecimal::from_f32(0.5).unwrap(),
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to save leave type..")
+            .unwrap();
+        assert!(save_group_res.is_ok());
+        assert!(save_type_res.is_ok());
+        let leave_group_id = save_group_res.unwrap();
+
+        let leave_type_res =
+            get_leave_type(&pool, business_id, None, Some(vec!["Casual Leave"]), None).await;
+        assert!(leave_type_res.is_ok());
+        let leave_type_list = leave_type_res.unwrap();
+        assert!(!leave_type_list.is_empty());
+        let leave_type_id = leave_type_list.first().unwrap().id;
+
+        let user_leave_data = vec![UserLeaveCreationData {
+            type_id: leave_type_id,
+            count: BigDecimal::from_i32(5).unwrap(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let user_leave_res =
+            fetch_user_leaves(&pool, business_id, user_id, Some(leave_group_id), None).await;
+        assert!(user_leave_res.is_ok());
+        let user_leave_opt = user_leave_res.unwrap();
+        assert!(!user_leave_opt.is_empty());
+        let user_leave = user_leave_opt.first().unwrap();
+
+        let leave_data = vec![CreateLeaveData {
+            period_id,
+            date: NaiveDate::from_ymd_opt(2025, 7, 4).expect("invalid date"),
+        }];
+        let leave_request = CreateLeaveRequest {
+            to: Some(EmailObject::new(email.to_string())),
+            cc: None,
+            reason: None,
+            user_id: Some(user_id),
+            leave_data,
+            user_leave_id: user_leave.id,
+            leave_range: None,
+            send_mail: false,
+            attachment_url: None,
         };
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Approved,
-            &LeaveStatus::Rejected,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_err());
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+        let res = save_leave_request(
+            &mut transaction,
+            &leave_request,
+            &leave_request.leave_data,
+            user_leave.id,
+            user_id,
+            Uuid::new_v4(),
+            Some("abc@gmail.com"),
+        )
+        .await;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to create leave type.")
+            .unwrap();
+        assert!(res.is_ok());
+        let query: FetchLeaveQuery<'_> = FetchLeaveQuery::builder().with_sender_id(Some(user_id));
+        let leaves = get_leaves(&pool, &query).await;
+        assert!(leaves.is_ok());
+        let leave_vec = leaves.unwrap();
+        assert!(!leave_vec.is_empty());
+        let leave_id = leave_vec.first().unwrap().id;
 
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Approved,
-            &LeaveStatus::Cancelled,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_err());
+        let blackout = LeaveBlackoutCreationRequest {
+            id: None,
+            department_id: None,
+            reason: "Quarter end".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 7, 7).expect("invalid date"),
+            end_date: NaiveDate::from_ymd_opt(2025, 7, 9).expect("invalid date"),
+            leave_type_id_list: vec![],
+        };
+        let blackout_res = save_leave_blackout(&pool, &blackout, business_id, user_id).await;
+        assert!(blackout_res.is_ok());
 
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Approved,
-            &LeaveStatus::Approved,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_err());
+        let moved_leave_data = |day: u32| {
+            vec![CreateLeaveData {
+                period_id,
+                date: NaiveDate::from_ymd_opt(2025, 7, day).expect("invalid date"),
+            }]
+        };
+        // A user without the override permission cannot move the leave into the blackout
+        let acting_user_id = Uuid::new_v4();
+        let res = validate_leave_request_rules(
+            &pool,
+            business_id,
+            user_id,
+            acting_user_id,
+            user_leave,
+            &moved_leave_data(8),
+            None,
+            None,
+            Some(leave_id),
+        )
+        .await;
+        assert!(res.is_err());
+        let res = validate_leave_request_rules(
+            &pool,
+            business_id,
+            user_id,
+            acting_user_id,
+            user_leave,
+            &moved_leave_data(10),
+            None,
+            None,
+            Some(leave_id),
+        )
+        .await;
+        assert!(res.is_ok());
+        // The business owner holds the override permission
+        let res = validate_leave_request_rules(
+            &pool,
+            business_id,
+            user_id,
+            user_id,
+            user_leave,
+            &moved_leave_data(8),
+            None,
+            None,
+            Some(leave_id),
+        )
+        .await;
+        assert!(res.is_ok());
 
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Rejected,
-            &LeaveStatus::Approved,
-            &AllowedPermission {
-                permission_list: vec![],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_err());
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Cancelled,
-            &LeaveStatus::Approved,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_ok());
-        dummy_user_leave.used_count = BigDecimal::from_i32(5).unwrap();
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Cancelled,
-            &LeaveStatus::Approved,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
-        );
-        assert!(val_res.is_err());
+        let deleted = delete_leave(&pool, leave_id, user_id).await;
+        assert!(deleted.is_ok());
 
-        dummy_user_leave.used_count = BigDecimal::from_i32(5).unwrap();
-        let val_res = validate_leave_status_update(
-            &LeaveStatus::Cancelled,
-            &LeaveStatus::Approved,
-            &AllowedPermission {
-                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
-            },
-            &dummy_user_leave,
-            &period,
+        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &delete_mobile)
         );
-        assert!(val_res.is_err());
+        assert!(delete_business_account_res.is_ok());
+        assert!(delete_user_account_res.is_ok());
     }
 
     #[tokio::test]
-    async fn test_leave_request_status_updation() {
+    async fn test_leave_request_fetch() {
         let pool = get_test_pool().await;
-        let email = "testuser19@example.com";
-        let mobile_no = "1234567902";
-        let user_res = setup_user(&pool, "testuser19", email, mobile_no, "testuser@123").await;
+        let email = "testuser21@example.com";
+        let mobile_no = "1234567904";
+        let user_res = setup_user(&pool, "testuser21", email, mobile_no, "testuser@123").await;
         assert!(user_res.is_ok());
         let user_id = user_res.unwrap();
         let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
         let business_id = business_res.unwrap();
+
+        let start_date = Utc::now();
+        let end_date = start_date + Duration::days(2);
         let period_id =
             create_leave_period_and_get_id(&pool, "Full Day", 1.0, user_id, business_id)
                 .await
                 .unwrap();
-        let start_date = Utc::now();
-        let end_date = start_date + Duration::days(2);
         let leave_type_data = vec![LeaveTypeCreationData {
             id: None,
             label: "Casual Leave".to_string(),
             period_id_list: vec![period_id],
             allowed_dates: None,
+            eligibility: None,
         }];
         let mut transaction = pool
             .begin()
             .await
             .context("Failed to acquire a Postgres connection from the pool")
             .unwrap();
+
         let (save_group_res, save_type_res) = tokio::join!(
             create_test_leave_group(
                 &pool,
@@ -1049,7 +1397,7 @@ pub mod tests {
         transaction
             .commit()
             .await
-            .context("Failed to commit SQL transaction to store a new user account.")
+            .context("Failed to commit SQL transaction to create leave type.")
             .unwrap();
         assert!(save_group_res.is_ok());
         assert!(save_type_res.is_ok());
@@ -1072,8 +1420,17 @@ pub mod tests {
             type_id: leave_type_id,
             count: BigDecimal::from_i32(5).unwrap(),
             status: Status::Active,
+            pro_rata: None,
         }];
-        let res = save_user_leave(&pool, &user_leave_data, user_id, leave_group_id, user_id).await;
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
 
         assert!(res.is_ok());
 
@@ -1084,30 +1441,41 @@ pub mod tests {
 
         assert!(user_leave_opt.first().is_some());
         let user_leave = user_leave_opt.first().unwrap();
-        let leave_data = vec![CreateLeaveData {
-            period_id,
-            date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
-        }];
+
+        let leave_data = vec![
+            CreateLeaveData {
+                date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+                period_id,
+            },
+            CreateLeaveData {
+                period_id,
+                date: NaiveDate::from_ymd_opt(2025, 7, 4).expect("invalid date"),
+            },
+        ];
         let leave_request = CreateLeaveRequest {
-            to: EmailObject::new(email.to_string()),
+            to: Some(EmailObject::new(email.to_string())),
             cc: None,
             reason: None,
             user_id: Some(user_id),
             leave_data,
             user_leave_id: user_leave.id,
+            leave_range: None,
             send_mail: false,
+            attachment_url: None,
         };
         let mut transaction = pool
             .begin()
             .await
             .context("Failed to acquire a Postgres connection from the pool")
             .unwrap();
+        let receiver_id = Uuid::new_v4();
         let res = save_leave_request(
             &mut transaction,
             &leave_request,
+            &leave_request.leave_data,
             user_leave.id,
             user_id,
-            Uuid::new_v4(),
+            receiver_id,
             Some("abc@gmail.com"),
         )
         .await;
@@ -1118,37 +1486,1438 @@ pub mod tests {
             .unwrap();
         assert!(res.is_ok());
         let query = FetchLeaveQuery::builder().with_sender_id(Some(user_id));
-        let leaves = get_leaves(&pool, &query).await;
-        assert!(leaves.is_ok());
-        let leave_vec = leaves.unwrap();
+        let leave_with_sender = get_leaves(&pool, &query).await;
+        assert!(leave_with_sender.is_ok());
+        let leave_vec = leave_with_sender.unwrap();
         let leave_opt = leave_vec.first();
         assert!(leave_opt.is_some());
-        let leave = leave_opt.unwrap();
+        let tz: Tz = DUMMY_TIMEZONE.parse().unwrap();
+        let start_utc: DateTime<Utc> = Utc::now();
+        let end_utc = start_utc - Duration::minutes(3);
+        let start_in_tz = start_utc.with_timezone(&tz);
+        let end_in_tz = end_utc.with_timezone(&tz);
+
+        let start_naive = start_in_tz.naive_local();
+        let end_naive = end_in_tz.naive_local();
+        let query = FetchLeaveQuery::builder()
+            .with_limit(Some(1))
+            .with_offset(Some(0))
+            .with_start_date(Some(&start_naive))
+            .with_end_date(Some(&end_naive));
+        let leave_with_start_and_end_date = get_leaves(&pool, &query).await;
+        assert!(leave_with_start_and_end_date.is_ok());
+        let leave_vec = leave_with_start_and_end_date.unwrap();
+        let leave_opt = leave_vec.first();
+        assert!(leave_opt.is_some());
+        let query = FetchLeaveQuery::builder().with_leave_id(Some(leave_opt.unwrap().id));
+        let leave_with_id = get_leaves(&pool, &query).await;
+        assert!(leave_with_id.is_ok());
+        let leave_vec = leave_with_id.unwrap();
+        let leave_opt = leave_vec.first();
+        assert!(leave_opt.is_some());
+        let query = FetchLeaveQuery::builder().with_recevier_id(Some(receiver_id));
+        let leave_with_receiver_id = get_leaves(&pool, &query).await;
+        assert!(leave_with_receiver_id.is_ok());
+        let leave_vec = leave_with_receiver_id.unwrap();
+        let leave_opt = leave_vec.first();
+        assert!(leave_opt.is_some());
+
+        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &delete_mobile)
+        );
+        assert!(delete_business_account_res.is_ok());
+        assert!(delete_user_account_res.is_ok());
+    }
+
+    fn get_dummy_user_leave_data(
+        user_id: Uuid,
+        used_count: BigDecimal,
+        allocated_count: BigDecimal,
+    ) -> UserLeave {
+        UserLeave {
+            id: Uuid::new_v4(),
+            allocated_count,
+            used_count,
+            business_id: user_id,
+            user_id,
+            leave_type: UserLeaveType {
+                id: Uuid::new_v4(),
+                label: "Casual Leave".to_owned(),
+            },
+            leave_group: UserLeaveGroup {
+                id: Uuid::new_v4(),
+                label: "2025".to_owned(),
+            },
+            periods: vec![LeavePeriodData {
+                id: Uuid::new_v4(),
+                label: "Full Day".to_string(),
+                value: BigDecimal::from_i32(1).unwrap(),
+            }],
+            allowed_dates: None,
+            policy: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leave_request_status_validation() {
+        let mut dummy_user_leave = get_dummy_user_leave_data(
+            Uuid::new_v4(),
+            BigDecimal::from_i32(2).unwrap(),
+            BigDecimal::from_i32(5).unwrap(),
+        );
+        let period = LeavePeriodData {
+            id: Uuid::new_v4(),
+            label: "Full Day".to_string(),
+            value: BigDecimal::from_f32(0.5).unwrap(),
+        };
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Approved,
+            &LeaveStatus::Rejected,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Approved,
+            &LeaveStatus::Cancelled,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Approved,
+            &LeaveStatus::Approved,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Rejected,
+            &LeaveStatus::Approved,
+            &AllowedPermission {
+                permission_list: vec![],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Cancelled,
+            &LeaveStatus::Approved,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_ok());
+        dummy_user_leave.used_count = BigDecimal::from_i32(5).unwrap();
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Cancelled,
+            &LeaveStatus::Approved,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+
+        dummy_user_leave.used_count = BigDecimal::from_i32(5).unwrap();
+        let val_res = validate_leave_status_update(
+            &LeaveStatus::Cancelled,
+            &LeaveStatus::Approved,
+            &AllowedPermission {
+                permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+            },
+            &dummy_user_leave,
+            &period,
+        );
+        assert!(val_res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_leave_cancellation_request_status_validation() {
+        let dummy_user_leave = get_dummy_user_leave_data(
+            Uuid::new_v4(),
+            BigDecimal::from_i32(5).unwrap(),
+            BigDecimal::from_i32(5).unwrap(),
+        );
+        let period = LeavePeriodData {
+            id: Uuid::new_v4(),
+            label: "Full Day".to_string(),
+            value: BigDecimal::from_i32(1).unwrap(),
+        };
+        let permissions = AllowedPermission {
+            permission_list: vec![PermissionType::ApproveLeaveRequest.to_string()],
+        };
+        let validate = |incoming_status: LeaveStatus, current_status: LeaveStatus| {
+            validate_leave_status_update(
+                &incoming_status,
+                &current_status,
+                &permissions,
+                &dummy_user_leave,
+                &period,
+            )
+        };
+
+        // Cancellation can only be requested by the requester
+        assert!(validate(LeaveStatus::CancellationRequested, LeaveStatus::Approved).is_err());
+        // Leave under a cancellation request is already counted, so a full balance is fine
+        assert!(validate(LeaveStatus::Cancelled, LeaveStatus::CancellationRequested).is_ok());
+        assert!(validate(LeaveStatus::Approved, LeaveStatus::CancellationRequested).is_ok());
+        assert!(validate(LeaveStatus::Rejected, LeaveStatus::CancellationRequested).is_err());
+        assert!(validate(LeaveStatus::Approved, LeaveStatus::Expired).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_leave_request_status_updation() {
+        let pool = get_test_pool().await;
+        let email = "testuser19@example.com";
+        let mobile_no = "1234567902";
+        let user_res = setup_user(&pool, "testuser19", email, mobile_no, "testuser@123").await;
+        assert!(user_res.is_ok());
+        let user_id = user_res.unwrap();
+        let business_res = setup_business(&pool, mobile_no, "business@example.com").await;
+        let business_id = business_res.unwrap();
+        let period_id =
+            create_leave_period_and_get_id(&pool, "Full Day", 1.0, user_id, business_id)
+                .await
+                .unwrap();
+        let start_date = Utc::now();
+        let end_date = start_date + Duration::days(2);
+        let leave_type_data = vec![LeaveTypeCreationData {
+            id: None,
+            label: "Casual Leave".to_string(),
+            period_id_list: vec![period_id],
+            allowed_dates: None,
+            eligibility: None,
+        }];
         let mut transaction = pool
             .begin()
             .await
             .context("Failed to acquire a Postgres connection from the pool")
             .unwrap();
-        let res = update_leave_request_status(
-            &mut transaction,
-            leave.id,
-            &LeaveStatus::Approved,
-            user_id,
-        )
-        .await;
+        let (save_group_res, save_type_res) = tokio::join!(
+            create_test_leave_group(
+                &pool,
+                business_id,
+                user_id,
+                "2025".to_string(),
+                start_date,
+                end_date
+            ),
+            save_leave_type(
+                &mut transaction,
+                &leave_type_data,
+                user_id,
+                user_id,
+                business_id
+            )
+        );
         transaction
             .commit()
             .await
             .context("Failed to commit SQL transaction to store a new user account.")
             .unwrap();
+        assert!(save_group_res.is_ok());
+        assert!(save_type_res.is_ok());
+        let leave_group_id = save_group_res.unwrap();
+
+        let leave_type_res = get_leave_type(
+            &pool,
+            business_id,
+            None,
+            Some(vec!["Casual Leave", "Restricted Leave"]),
+            None,
+        )
+        .await;
+        assert!(leave_type_res.is_ok());
+        let leave_type_list = leave_type_res.unwrap();
+        assert!(leave_type_list.first().is_some());
+        let leave_type_id = leave_type_list.first().unwrap().id;
+
+        let user_leave_data = vec![UserLeaveCreationData {
+            type_id: leave_type_id,
+            count: BigDecimal::from_i32(5).unwrap(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        let res = save_user_leave(
+            &pool,
+            &user_leave_data,
+            &[user_id],
+            leave_group_id,
+            user_id,
+            false,
+        )
+        .await;
 
         assert!(res.is_ok());
-        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
-        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
-            hard_delete_business_account(&pool, business_id),
-            hard_delete_user_account(&pool, &delete_mobile)
-        );
-        assert!(delete_business_account_res.is_ok());
+
+        let user_leave_res =
+            fetch_user_leaves(&pool, business_id, user_id, Some(leave_group_id), None).await;
+        assert!(user_leave_res.is_ok());
+        let user_leave_opt = user_leave_res.unwrap();
+
+        assert!(user_leave_opt.first().is_some());
+        let user_leave = user_leave_opt.first().unwrap();
+        let leave_data = vec![CreateLeaveData {
+            period_id,
+            date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+        }];
+        let leave_request = CreateLeaveRequest {
+            to: Some(EmailObject::new(email.to_string())),
+            cc: None,
+            reason: None,
+            user_id: Some(user_id),
+            leave_data,
+            user_leave_id: user_leave.id,
+            leave_range: None,
+            send_mail: false,
+            attachment_url: None,
+        };
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+        let res = save_leave_request(
+            &mut transaction,
+            &leave_request,
+            &leave_request.leave_data,
+            user_leave.id,
+            user_id,
+            Uuid::new_v4(),
+            Some("abc@gmail.com"),
+        )
+        .await;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to store a new user account.")
+            .unwrap();
+        assert!(res.is_ok());
+        let query = FetchLeaveQuery::builder().with_sender_id(Some(user_id));
+        let leaves = get_leaves(&pool, &query).await;
+        assert!(leaves.is_ok());
+        let leave_vec = leaves.unwrap();
+        let leave_opt = leave_vec.first();
+        assert!(leave_opt.is_some());
+        let leave = leave_opt.unwrap();
+        let mut transaction = pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection from the pool")
+            .unwrap();
+        let res = update_leave_request_status(
+            &mut transaction,
+            leave.id,
+            &LeaveStatus::Approved,
+            user_id,
+        )
+        .await;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to store a new user account.")
+            .unwrap();
+
+        assert!(res.is_ok());
+        let delete_mobile = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+        let (delete_business_account_res, delete_user_account_res) = tokio::join!(
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &delete_mobile)
+        );
+        assert!(delete_business_account_res.is_ok());
         assert!(delete_user_account_res.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_leave_range_expansion() {
+        let full_day = Uuid::new_v4();
+        let half_day = Uuid::new_v4();
+        // Thursday 2025-07-03 to Tuesday 2025-07-08 with Monday as a holiday
+        let range = CreateLeaveRange {
+            start_date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+            end_date: NaiveDate::from_ymd_opt(2025, 7, 8).expect("invalid date"),
+            period_id: full_day,
+            start_period_id: Some(half_day),
+            end_period_id: None,
+        };
+        let holiday_list = vec![HolidayData {
+            id: Uuid::new_v4(),
+            label: "Founders Day".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 7, 7).expect("invalid date"),
+        }];
+        let leave_data = expand_leave_range(&range, &holiday_list, None).unwrap();
+        let dates: Vec<u32> = leave_data.iter().map(|a| a.date.day()).collect();
+        assert_eq!(dates, vec![3, 4, 8]);
+        assert!(leave_data[0].period_id == half_day);
+        assert!(leave_data[1].period_id == full_day);
+
+        let location = BusinessLocation {
+            id: Uuid::new_v4(),
+            name: "Dubai".to_string(),
+            time_zone: "Asia/Dubai".to_string(),
+            work_week: vec![
+                WeekDay::Sunday,
+                WeekDay::Monday,
+                WeekDay::Tuesday,
+                WeekDay::Wednesday,
+                WeekDay::Thursday,
+            ],
+        };
+        let leave_data = expand_leave_range(&range, &[], Some(&location)).unwrap();
+        let dates: Vec<u32> = leave_data.iter().map(|a| a.date.day()).collect();
+        assert_eq!(dates, vec![3, 6, 7, 8]);
+
+        let range = CreateLeaveRange {
+            start_date: NaiveDate::from_ymd_opt(2025, 7, 5).expect("invalid date"),
+            end_date: NaiveDate::from_ymd_opt(2025, 7, 6).expect("invalid date"),
+            period_id: full_day,
+            start_period_id: None,
+            end_period_id: None,
+        };
+        assert!(expand_leave_range(&range, &[], None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_leave_request_data() {
+        let full_day = Uuid::new_v4();
+        let half_day = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let mut leave_request = CreateLeaveRequest {
+            to: None,
+            cc: None,
+            reason: Some("Vacation".to_string()),
+            user_id: None,
+            leave_data: vec![],
+            user_leave_id: Uuid::new_v4(),
+            leave_range: Some(CreateLeaveRange {
+                start_date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+                end_date: NaiveDate::from_ymd_opt(2025, 7, 8).expect("invalid date"),
+                period_id: full_day,
+                start_period_id: None,
+                end_period_id: Some(half_day),
+            }),
+            send_mail: false,
+            attachment_url: None,
+        };
+        let holiday_list = vec![HolidayData {
+            id: Uuid::new_v4(),
+            label: "Founders Day".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 7, 7).expect("invalid date"),
+        }];
+        let leave_data = get_leave_request_data(&leave_request, &holiday_list, None).unwrap();
+        let bulk_data = prepare_bulk_leave_request_data(
+            &leave_request,
+            &leave_data,
+            leave_request.user_leave_id,
+            user_id,
+            Uuid::new_v4(),
+            None,
+        )
+        .await
+        .unwrap()
+        .expect("range not expanded");
+        let dates: Vec<u32> = bulk_data.date.iter().map(|a| a.day()).collect();
+        assert_eq!(dates, vec![3, 4, 8]);
+        assert_eq!(
+            bulk_data.leave_period_id,
+            vec![&full_day, &full_day, &half_day]
+        );
+        assert!(bulk_data.reason.iter().all(|a| *a == Some("Vacation")));
+
+        leave_request.leave_data = vec![CreateLeaveData {
+            date: NaiveDate::from_ymd_opt(2025, 7, 3).expect("invalid date"),
+            period_id: full_day,
+        }];
+        assert!(get_leave_request_data(&leave_request, &holiday_list, None).is_err());
+
+        leave_request.leave_range = None;
+        let leave_data = get_leave_request_data(&leave_request, &holiday_list, None).unwrap();
+        assert_eq!(leave_data.len(), 1);
+
+        leave_request.leave_data = vec![];
+        let bulk_res = prepare_bulk_leave_request_data(
+            &leave_request,
+            &leave_request.leave_data,
+            leave_request.user_leave_id,
+            user_id,
+            Uuid::new_v4(),
+            None,
+        )
+        .await;
+        assert!(bulk_res.is_ok_and(|a| a.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_leave_approval() {
+        let leave_id = Uuid::new_v4();
+        let lead_role_id = Uuid::new_v4();
+        let hr_role_id = Uuid::new_v4();
+        let mut approval_list = vec![
+            LeaveApproval {
+                id: Uuid::new_v4(),
+                leave_id,
+                stage_order: 1,
+                label: "Team Lead".to_string(),
+                approver_role_id: lead_role_id,
+                status: LeaveApprovalStatus::Pending,
+                acted_by: None,
+                acted_on: None,
+            },
+            LeaveApproval {
+                id: Uuid::new_v4(),
+                leave_id,
+                stage_order: 2,
+                label: "HR".to_string(),
+                approver_role_id: hr_role_id,
+                status: LeaveApprovalStatus::Pending,
+                acted_by: None,
+                acted_on: None,
+            },
+        ];
+
+        let res = resolve_leave_approval(&LeaveStatus::Approved, &[], None).unwrap();
+        assert!(res.0.is_none());
+        assert!(res.1 == LeaveStatus::Approved);
+
+        assert!(
+            resolve_leave_approval(&LeaveStatus::Approved, &approval_list, Some(hr_role_id))
+                .is_err()
+        );
+        let (stage, status) =
+            resolve_leave_approval(&LeaveStatus::Approved, &approval_list, Some(lead_role_id))
+                .unwrap();
+        assert!(stage.unwrap().stage_order == 1);
+        assert!(status == LeaveStatus::InProgress);
+
+        approval_list[0].status = LeaveApprovalStatus::Approved;
+        let (stage, status) =
+            resolve_leave_approval(&LeaveStatus::Approved, &approval_list, Some(hr_role_id))
+                .unwrap();
+        assert!(stage.unwrap().stage_order == 2);
+        assert!(status == LeaveStatus::Approved);
+
+        let (_, status) =
+            resolve_leave_approval(&LeaveStatus::Rejected, &approval_list, Some(hr_role_id))
+                .unwrap();
+        assert!(status == LeaveStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_pick_leave_approver() {
+        let requester_id = Uuid::new_v4();
+        let head_id = Uuid::new_v4();
+        let manager_id = Uuid::new_v4();
+        let fallback_id = Uuid::new_v4();
+        let candidate_list = vec![Some(head_id), Some(manager_id), Some(fallback_id)];
+
+        let approver = pick_leave_approver(
+            requester_id,
+            &candidate_list,
+            &[fallback_id, manager_id, head_id],
+        );
+        assert!(approver == Some(head_id));
+
+        let approver = pick_leave_approver(requester_id, &candidate_list, &[fallback_id]);
+        assert!(approver == Some(fallback_id));
+
+        let approver = pick_leave_approver(
+            requester_id,
+            &[None, Some(requester_id), Some(fallback_id)],
+            &[requester_id, fallback_id],
+        );
+        assert!(approver == Some(fallback_id));
+
+        assert!(pick_leave_approver(requester_id, &[None, None, None], &[]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_group_pending_leave_approvals() {
+        let first_user_id = Uuid::new_v4();
+        let second_user_id = Uuid::new_v4();
+        let today = Utc::now();
+        let leave_request = |user_id: Uuid, date: DateTime<Utc>| LeaveRequestData {
+            id: Uuid::new_v4(),
+            user_leave_id: Uuid::new_v4(),
+            date,
+            reason: None,
+            status: LeaveStatus::Requested,
+            user_id,
+            receiver_id: Uuid::new_v4(),
+            email_message_id: None,
+            cc: None,
+            created_on: None,
+            attachment_url: None,
+            comment_count: 0,
+            leave_type: "Casual".to_string(),
+            period: LeavePeriodData {
+                id: Uuid::new_v4(),
+                label: "Full Day".to_string(),
+                value: BigDecimal::from(1),
+            },
+        };
+        let leave_list = vec![
+            leave_request(first_user_id, today + Duration::days(3)),
+            leave_request(second_user_id, today + Duration::days(2)),
+            leave_request(first_user_id, today + Duration::days(1)),
+        ];
+        let user_list = vec![MinimalUserAccount {
+            id: first_user_id,
+            mobile_no_info: MobileNoInfo {
+                mobile_no: "3100000072".to_string(),
+                international_dialing_code: DUMMY_INTERNATIONAL_DIALING_CODE.to_string(),
+            },
+            display_name: "testuser72".to_string(),
+        }];
+
+        let group_list = group_pending_leave_approvals(leave_list, &user_list);
+        assert!(group_list.len() == 2);
+        assert!(group_list[0].user_id == first_user_id);
+        assert!(group_list[0].display_name == "testuser72");
+        assert!(group_list[0].leave_list.len() == 2);
+        assert!(group_list[0].leave_list[0].date < group_list[0].leave_list[1].date);
+        assert!(group_list[1].user_id == second_user_id);
+        assert!(group_list[1].display_name.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_leave_balance_adjustment_validation() {
+        let user_leave = get_dummy_user_leave_data(
+            Uuid::new_v4(),
+            BigDecimal::from_i32(4).unwrap(),
+            BigDecimal::from_i32(5).unwrap(),
+        );
+        let mut body = LeaveBalanceAdjustmentRequest {
+            user_id: user_leave.user_id,
+            user_leave_id: user_leave.id,
+            target: LeaveBalanceAdjustmentTarget::Allocated,
+            amount: BigDecimal::from_i32(2).unwrap(),
+            reason: "Compensation for weekend work".to_string(),
+        };
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_ok());
+
+        body.amount = BigDecimal::from_i32(-2).unwrap();
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_err());
+
+        body.target = LeaveBalanceAdjustmentTarget::Used;
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_ok());
+
+        body.amount = BigDecimal::from_i32(-5).unwrap();
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_err());
+
+        body.amount = BigDecimal::from_i32(2).unwrap();
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_err());
+
+        body.amount = BigDecimal::default();
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_err());
+
+        body.amount = BigDecimal::from_i32(1).unwrap();
+        body.reason = "  ".to_string();
+        assert!(validate_leave_balance_adjustment(&body, &user_leave).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_leave_carry_forward_split() {
+        let rule = LeaveCarryForwardRule {
+            id: Uuid::new_v4(),
+            leave_type_id: Uuid::new_v4(),
+            max_carry_forward: BigDecimal::from_i32(5).unwrap(),
+            expiry_days: Some(90),
+            lapse_remaining: true,
+        };
+        let (carry, remaining) = rule.split_balance(&BigDecimal::from_f32(7.5).unwrap());
+        assert!(carry == BigDecimal::from_i32(5).unwrap());
+        assert!(remaining == BigDecimal::from_f32(2.5).unwrap());
+
+        let (carry, remaining) = rule.split_balance(&BigDecimal::from_i32(3).unwrap());
+        assert!(carry == BigDecimal::from_i32(3).unwrap());
+        assert!(remaining == BigDecimal::default());
+
+        let (carry, remaining) = rule.split_balance(&BigDecimal::from_i32(-2).unwrap());
+        assert!(carry == BigDecimal::default());
+        assert!(remaining == BigDecimal::default());
+
+        let lapse = compute_carry_forward_lapse(
+            &BigDecimal::from_i32(5).unwrap(),
+            &BigDecimal::from_i32(2).unwrap(),
+        );
+        assert!(lapse == BigDecimal::from_i32(3).unwrap());
+        let lapse = compute_carry_forward_lapse(
+            &BigDecimal::from_i32(5).unwrap(),
+            &BigDecimal::from_i32(8).unwrap(),
+        );
+        assert!(lapse == BigDecimal::default());
+    }
+
+    #[tokio::test]
+    async fn test_leave_accrual_schedule() {
+        let start_date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let end_date = Utc.with_ymd_and_hms(2025, 12, 31, 23, 59, 59).unwrap();
+        let till = Utc.with_ymd_and_hms(2025, 4, 15, 0, 0, 0).unwrap();
+        let date_list = get_accrual_date_list(
+            start_date,
+            end_date,
+            start_date,
+            &LeaveAccrualFrequency::Monthly,
+            till,
+        );
+        assert!(date_list.len() == 4);
+        assert!(date_list[3] == Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap());
+
+        let created_on = Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap();
+        let date_list = get_accrual_date_list(
+            start_date,
+            end_date,
+            created_on,
+            &LeaveAccrualFrequency::Monthly,
+            till,
+        );
+        assert!(date_list.len() == 2);
+        assert!(date_list[0] == Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap());
+
+        let date_list = get_accrual_date_list(
+            start_date,
+            end_date,
+            start_date,
+            &LeaveAccrualFrequency::Quarterly,
+            end_date,
+        );
+        assert!(date_list.len() == 4);
+
+        let policy = LeaveAccrualPolicy {
+            id: Uuid::new_v4(),
+            leave_type_id: Uuid::new_v4(),
+            amount: BigDecimal::from_f32(1.5).unwrap(),
+            frequency: LeaveAccrualFrequency::Monthly,
+            max_balance: Some(BigDecimal::from_i32(10).unwrap()),
+        };
+        assert!(
+            policy.get_credit(&BigDecimal::from_i32(4).unwrap())
+                == BigDecimal::from_f32(1.5).unwrap()
+        );
+        assert!(
+            policy.get_credit(&BigDecimal::from_f32(9.5).unwrap())
+                == BigDecimal::from_f32(0.5).unwrap()
+        );
+        assert!(policy.get_credit(&BigDecimal::from_i32(12).unwrap()) == BigDecimal::default());
+    }
+
+    #[tokio::test]
+    async fn test_pro_rata_leave_count() {
+        let leave_group = LeaveGroup {
+            id: Uuid::new_v4(),
+            label: "2025".to_string(),
+            start_date: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            end_date: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        };
+        let count = BigDecimal::from_i32(12).unwrap();
+        let half = BigDecimal::from_f32(0.5).unwrap();
+        let full = BigDecimal::from_i32(1).unwrap();
+
+        let res = compute_pro_rata_count(&count, &leave_group, leave_group.start_date, &half);
+        assert!(res == count);
+        let res = compute_pro_rata_count(
+            &count,
+            &leave_group,
+            Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+            &half,
+        );
+        assert!(res == BigDecimal::default());
+
+        let join_date = Utc.with_ymd_and_hms(2025, 7, 2, 12, 0, 0).unwrap();
+        let res = compute_pro_rata_count(&count, &leave_group, join_date, &half);
+        assert!(res == BigDecimal::from_i32(6).unwrap());
+        let join_date = Utc.with_ymd_and_hms(2025, 10, 10, 0, 0, 0).unwrap();
+        let res = compute_pro_rata_count(&count, &leave_group, join_date, &half);
+        assert!(res == BigDecimal::from_f32(2.5).unwrap());
+        let res = compute_pro_rata_count(&count, &leave_group, join_date, &full);
+        assert!(res == BigDecimal::from_i32(3).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_user_leave_allocation_result() {
+        let first_user_id = Uuid::new_v4();
+        let second_user_id = Uuid::new_v4();
+        let type_id = Uuid::new_v4();
+        let data = vec![UserLeaveCreationData {
+            type_id,
+            count: BigDecimal::from_i32(12).unwrap(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        let result = build_bulk_user_leave_allocation_result(
+            &[first_user_id, second_user_id],
+            &data,
+            &[(second_user_id, type_id)],
+        );
+        assert!(result.created_list.len() == 1);
+        assert!(result.created_list[0].user_id == second_user_id);
+        assert!(result.skipped_list.len() == 1);
+        assert!(result.skipped_list[0].user_id == first_user_id);
+        assert!(result.skipped_list[0].type_id == type_id);
+    }
+
+    #[tokio::test]
+    async fn test_leave_allocation_change() {
+        let count = BigDecimal::from_i32(12).unwrap();
+        let change = LeaveAllocationChange {
+            change_type: LeaveAllocationChangeType::Percentage,
+            value: BigDecimal::from_i32(10).unwrap(),
+        };
+        assert!(change.apply(&count) == BigDecimal::new(132.into(), 1));
+
+        let change = LeaveAllocationChange {
+            change_type: LeaveAllocationChangeType::Fixed,
+            value: BigDecimal::from_i32(-2).unwrap(),
+        };
+        assert!(change.apply(&count) == BigDecimal::from_i32(10).unwrap());
+
+        let change = LeaveAllocationChange {
+            change_type: LeaveAllocationChangeType::Fixed,
+            value: BigDecimal::from_i32(-20).unwrap(),
+        };
+        assert!(change.apply(&count) == BigDecimal::default());
+    }
+
+    #[tokio::test]
+    async fn test_leave_type_policy_validation() {
+        let user_id = Uuid::new_v4();
+        let mut user_leave = get_dummy_user_leave_data(
+            user_id,
+            BigDecimal::from_i32(0).unwrap(),
+            BigDecimal::from_i32(1).unwrap(),
+        );
+        assert!(!user_leave.can_use(&BigDecimal::from_i32(2).unwrap()));
+        user_leave.policy = Some(LeaveTypePolicy {
+            id: Uuid::new_v4(),
+            leave_type_id: user_leave.leave_type.id,
+            min_notice_days: Some(2),
+            max_consecutive_days: Some(3),
+            max_requests_per_month: Some(4),
+            allow_negative_balance: true,
+            max_negative_balance: Some(BigDecimal::from_i32(2).unwrap()),
+            allow_backdated: false,
+            require_attachment: true,
+        });
+        assert!(user_leave.can_use(&BigDecimal::from_i32(3).unwrap()));
+        assert!(!user_leave.can_use(&BigDecimal::from_i32(4).unwrap()));
+
+        let period_id = user_leave.periods[0].id;
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 7, day).expect("invalid date");
+        let leave_request = |day_list: &[u32], attachment_url: Option<&str>| CreateLeaveRequest {
+            to: None,
+            cc: None,
+            reason: None,
+            user_id: Some(user_id),
+            leave_data: day_list
+                .iter()
+                .map(|day| CreateLeaveData {
+                    period_id,
+                    date: date(*day),
+                })
+                .collect(),
+            user_leave_id: user_leave.id,
+            leave_range: None,
+            send_mail: false,
+            attachment_url: attachment_url.map(|a| a.to_string()),
+        };
+        let attachment_url = Some("https://example.com/medical.pdf");
+        let today = date(7);
+
+        let request = leave_request(&[10, 11], None);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+        let request = leave_request(&[10, 11], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        let request = leave_request(&[4], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+        let request = leave_request(&[8], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+
+        // The weekend between Friday and Monday does not break the run of leave days.
+        let request = leave_request(&[10, 11, 14], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        let request = leave_request(&[10, 11, 14, 15], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+        let holiday_list = vec![HolidayData {
+            id: Uuid::new_v4(),
+            label: "Founders Day".to_string(),
+            date: date(11),
+        }];
+        let request = leave_request(&[9, 10, 14, 15], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[],
+                &holiday_list,
+                None,
+                None
+            )
+            .is_err()
+        );
+
+        let created_on = Utc::now();
+        let active_leave = |day: u32, request_index: i64| ActiveLeaveData {
+            id: Uuid::new_v4(),
+            date: date(day),
+            created_on: created_on + Duration::seconds(request_index),
+        };
+        let request = leave_request(&[10, 11], attachment_url);
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[active_leave(1, 0), active_leave(2, 1)],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[active_leave(1, 0), active_leave(2, 1), active_leave(3, 2)],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_leave_type_policy(
+                &request.leave_data,
+                request.attachment_url.as_deref(),
+                &user_leave,
+                today,
+                &[
+                    active_leave(1, 0),
+                    active_leave(2, 1),
+                    active_leave(3, 2),
+                    active_leave(4, 3)
+                ],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_leave_type_policy_with_active_leaves() {
+        let user_id = Uuid::new_v4();
+        let mut user_leave = get_dummy_user_leave_data(
+            user_id,
+            BigDecimal::from_i32(0).unwrap(),
+            BigDecimal::from_i32(10).unwrap(),
+        );
+        user_leave.policy = Some(LeaveTypePolicy {
+            id: Uuid::new_v4(),
+            leave_type_id: user_leave.leave_type.id,
+            min_notice_days: None,
+            max_consecutive_days: Some(3),
+            max_requests_per_month: Some(2),
+            allow_negative_balance: false,
+            max_negative_balance: None,
+            allow_backdated: false,
+            require_attachment: false,
+        });
+        let period_id = user_leave.periods[0].id;
+        // 2025-07-07 is a Monday
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 7, day).expect("invalid date");
+        let leave_data = |day_list: &[u32]| -> Vec<CreateLeaveData> {
+            day_list
+                .iter()
+                .map(|day| CreateLeaveData {
+                    period_id,
+                    date: date(*day),
+                })
+                .collect()
+        };
+        let created_on = Utc::now();
+        let active_leave = |day: u32, request_index: i64| ActiveLeaveData {
+            id: Uuid::new_v4(),
+            date: date(day),
+            created_on: created_on + Duration::seconds(request_index),
+        };
+        let today = date(1);
+
+        // Leaves already taken right before or after the new dates extend the run.
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[10, 11]),
+                None,
+                &user_leave,
+                today,
+                &[active_leave(8, 0), active_leave(9, 0)],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[10, 11]),
+                None,
+                &user_leave,
+                today,
+                &[active_leave(14, 0), active_leave(15, 0)],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[10, 11]),
+                None,
+                &user_leave,
+                today,
+                &[active_leave(14, 0)],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        // A longer run elsewhere in the month does not block the request.
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[10]),
+                None,
+                &user_leave,
+                today,
+                &[
+                    active_leave(21, 0),
+                    active_leave(22, 0),
+                    active_leave(23, 0),
+                    active_leave(24, 0)
+                ],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+
+        // The dates of one request count as a single request.
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[15]),
+                None,
+                &user_leave,
+                today,
+                &[active_leave(1, 0), active_leave(2, 0), active_leave(3, 0)],
+                &[],
+                None,
+                None
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_leave_type_policy(
+                &leave_data(&[15, 16]),
+                None,
+                &user_leave,
+                today,
+                &[active_leave(1, 0), active_leave(3, 1)],
+                &[],
+                None,
+                None
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_leave_type_eligibility() {
+        let department_id = Uuid::new_v4();
+        let role_id = Uuid::new_v4();
+        let now = Utc::now();
+        let membership = BusinessUserMembership {
+            role_id,
+            department_id_list: vec![department_id],
+            joined_on: now - Duration::days(100),
+        };
+        let mut eligibility = LeaveTypeEligibility {
+            min_tenure_days: Some(90),
+            department_id_list: vec![department_id, Uuid::new_v4()],
+            role_id_list: vec![],
+        };
+        assert!(eligibility.ineligibility_reason(&membership, now).is_none());
+
+        eligibility.min_tenure_days = Some(180);
+        assert!(eligibility.ineligibility_reason(&membership, now).is_some());
+
+        eligibility.min_tenure_days = None;
+        eligibility.department_id_list = vec![Uuid::new_v4()];
+        assert!(eligibility.ineligibility_reason(&membership, now).is_some());
+
+        eligibility.department_id_list = vec![];
+        eligibility.role_id_list = vec![Uuid::new_v4()];
+        assert!(eligibility.ineligibility_reason(&membership, now).is_some());
+        eligibility.role_id_list.push(role_id);
+        assert!(eligibility.ineligibility_reason(&membership, now).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_eligible_user_partition() {
+        let department_id = Uuid::new_v4();
+        let now = Utc::now();
+        let senior_user_id = Uuid::new_v4();
+        let junior_user_id = Uuid::new_v4();
+        let former_user_id = Uuid::new_v4();
+        let membership_map = HashMap::from([
+            (
+                senior_user_id,
+                BusinessUserMembership {
+                    role_id: Uuid::new_v4(),
+                    department_id_list: vec![department_id],
+                    joined_on: now - Duration::days(400),
+                },
+            ),
+            (
+                junior_user_id,
+                BusinessUserMembership {
+                    role_id: Uuid::new_v4(),
+                    department_id_list: vec![department_id],
+                    joined_on: now - Duration::days(10),
+                },
+            ),
+        ]);
+        let mut leave_type = LeaveTypeData {
+            id: Uuid::new_v4(),
+            label: "Sabbatical".to_string(),
+            period_list: vec![],
+            allowed_dates: None,
+            eligibility: None,
+        };
+        let user_id_list = vec![senior_user_id, junior_user_id, former_user_id];
+
+        let (eligible_list, ineligible_list) =
+            partition_eligible_user_id_list(&leave_type, &user_id_list, &membership_map, now);
+        assert!(eligible_list == vec![senior_user_id, junior_user_id]);
+        assert!(ineligible_list == vec![former_user_id]);
+
+        leave_type.eligibility = Some(LeaveTypeEligibility {
+            min_tenure_days: Some(365),
+            department_id_list: vec![],
+            role_id_list: vec![],
+        });
+        let (eligible_list, ineligible_list) =
+            partition_eligible_user_id_list(&leave_type, &user_id_list, &membership_map, now);
+        assert!(eligible_list == vec![senior_user_id]);
+        assert!(ineligible_list == vec![junior_user_id, former_user_id]);
+    }
+
+    #[tokio::test]
+    async fn test_leave_blackout_coverage() {
+        let department_id = Uuid::new_v4();
+        let leave_type_id = Uuid::new_v4();
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 12, day).expect("invalid date");
+        let blackout_list = vec![
+            LeaveBlackout {
+                id: Uuid::new_v4(),
+                department_id: Some(department_id),
+                reason: "Release freeze".to_string(),
+                start_date: date(10),
+                end_date: date(12),
+                leave_type_id_list: vec![],
+            },
+            LeaveBlackout {
+                id: Uuid::new_v4(),
+                department_id: None,
+                reason: "Year end closing".to_string(),
+                start_date: date(29),
+                end_date: date(31),
+                leave_type_id_list: vec![Uuid::new_v4()],
+            },
+        ];
+        assert!(
+            find_leave_blackout(
+                &blackout_list,
+                &[date(9), date(13)],
+                leave_type_id,
+                &[department_id]
+            )
+            .is_none()
+        );
+        let (blackout, blackout_date) = find_leave_blackout(
+            &blackout_list,
+            &[date(9), date(12)],
+            leave_type_id,
+            &[department_id],
+        )
+        .expect("blackout not found");
+        assert!(blackout.id == blackout_list[0].id);
+        assert!(blackout_date == date(12));
+        assert!(
+            find_leave_blackout(
+                &blackout_list,
+                &[date(12)],
+                leave_type_id,
+                &[Uuid::new_v4()]
+            )
+            .is_none()
+        );
+        assert!(find_leave_blackout(&blackout_list, &[date(30)], leave_type_id, &[]).is_none());
+        assert!(
+            find_leave_blackout(
+                &blackout_list,
+                &[date(30)],
+                blackout_list[1].leave_type_id_list[0],
+                &[]
+            )
+            .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_leave_auto_approval_rule() {
+        let user_id = Uuid::new_v4();
+        let user_leave = get_dummy_user_leave_data(
+            user_id,
+            BigDecimal::from_i32(0).unwrap(),
+            BigDecimal::from_i32(10).unwrap(),
+        );
+        let rule_list = vec![
+            LeaveAutoApprovalRule {
+                id: Uuid::new_v4(),
+                label: "Other leave".to_string(),
+                leave_type_id: Some(Uuid::new_v4()),
+                max_days: None,
+                min_notice_days: Some(0),
+            },
+            LeaveAutoApprovalRule {
+                id: Uuid::new_v4(),
+                label: "Short casual leave".to_string(),
+                leave_type_id: Some(user_leave.leave_type.id),
+                max_days: Some(BigDecimal::from_i32(1).unwrap()),
+                min_notice_days: Some(3),
+            },
+        ];
+        let period_id = user_leave.periods[0].id;
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 7, day).expect("invalid date");
+        let leave_request = |day_list: &[u32]| CreateLeaveRequest {
+            to: None,
+            cc: None,
+            reason: None,
+            user_id: Some(user_id),
+            leave_data: day_list
+                .iter()
+                .map(|day| CreateLeaveData {
+                    period_id,
+                    date: date(*day),
+                })
+                .collect(),
+            user_leave_id: user_leave.id,
+            leave_range: None,
+            send_mail: false,
+            attachment_url: None,
+        };
+        let today = date(7);
+
+        let rule = find_leave_auto_approval_rule(
+            &rule_list,
+            &leave_request(&[10]).leave_data,
+            &user_leave,
+            today,
+        )
+        .expect("rule not matched");
+        assert!(rule.id == rule_list[1].id);
+        assert!(
+            find_leave_auto_approval_rule(
+                &rule_list,
+                &leave_request(&[9]).leave_data,
+                &user_leave,
+                today
+            )
+            .is_none()
+        );
+        assert!(
+            find_leave_auto_approval_rule(
+                &rule_list,
+                &leave_request(&[10, 11]).leave_data,
+                &user_leave,
+                today
+            )
+            .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_leave_reminder_schedule() {
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 7, day).expect("invalid date");
+        let created_on = Utc.with_ymd_and_hms(2025, 7, 7, 9, 0, 0).unwrap();
+
+        let schedule =
+            get_leave_reminder_schedule(created_on, &[date(10), date(9)], Some(24), Some(48));
+        let expected = vec![
+            (
+                SchedulerMessageType::LeaveReminder,
+                Utc.with_ymd_and_hms(2025, 7, 8, 9, 0, 0).unwrap(),
+            ),
+            (
+                SchedulerMessageType::LeaveEscalation,
+                Utc.with_ymd_and_hms(2025, 7, 9, 9, 0, 0).unwrap(),
+            ),
+            (
+                SchedulerMessageType::LeaveExpiry,
+                Utc.with_ymd_and_hms(2025, 7, 10, 0, 0, 0).unwrap(),
+            ),
+            (
+                SchedulerMessageType::LeaveExpiry,
+                Utc.with_ymd_and_hms(2025, 7, 11, 0, 0, 0).unwrap(),
+            ),
+        ];
+        assert_eq!(schedule, expected);
+
+        // Escalation falls after the leave is over and hours which are unset are skipped
+        let schedule = get_leave_reminder_schedule(created_on, &[date(8)], None, Some(48));
+        assert_eq!(
+            schedule,
+            vec![(
+                SchedulerMessageType::LeaveExpiry,
+                Utc.with_ymd_and_hms(2025, 7, 9, 0, 0, 0).unwrap(),
+            )]
+        );
+
+        // Leave dates already over are not expired
+        assert!(get_leave_reminder_schedule(created_on, &[date(6)], Some(24), None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_leave_comment_participant() {
+        let user_id = Uuid::new_v4();
+        let receiver_id = Uuid::new_v4();
+        let leave = LeaveRequestData {
+            id: Uuid::new_v4(),
+            user_leave_id: Uuid::new_v4(),
+            date: Utc::now(),
+            reason: None,
+            status: LeaveStatus::Requested,
+            user_id,
+            receiver_id,
+            email_message_id: None,
+            cc: Some(vec![EmailObject::new("testuser80@example.com".to_string())]),
+            created_on: None,
+            attachment_url: None,
+            comment_count: 0,
+            leave_type: "Casual".to_string(),
+            period: LeavePeriodData {
+                id: Uuid::new_v4(),
+                label: "Full Day".to_string(),
+                value: BigDecimal::from(1),
+            },
+        };
+        let email = EmailObject::new("testuser81@example.com".to_string());
+
+        assert!(leave.is_participant(user_id, &email));
+        assert!(leave.is_participant(receiver_id, &email));
+        assert!(leave.is_participant(
+            Uuid::new_v4(),
+            &EmailObject::new("testuser80@example.com".to_string())
+        ));
+        assert!(!leave.is_participant(Uuid::new_v4(), &email));
+    }
 }
@@ -1,38 +1,140 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
+use actix::Addr;
 use anyhow::{Context, anyhow};
-use bigdecimal::BigDecimal;
-use chrono::{DateTime, TimeZone, Utc};
+use bigdecimal::{BigDecimal, RoundingMode};
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeZone, Utc, Weekday};
 use sqlx::{Execute, Executor, PgPool, Postgres, QueryBuilder, Transaction};
 use uuid::Uuid;
 
 use crate::{
     errors::GenericError,
+    pulsar_client::{PulsarClient, PulsarTopic, SchedulerMessageData, SchedulerMessageType},
     routes::{
+        business::{
+            schemas::{BusinessLocation, BusinessUserMembership},
+            utils::{
+                fetch_user_id_list_with_business_permission, get_business_user_membership,
+                get_business_user_membership_map, get_user_business_join_date,
+                validate_user_business_permission,
+            },
+        },
         leave::models::{
             LeaveGroupModel, LeavePeriodWithTypeModel, LeaveTypeModel, UserLeaveModel,
         },
+        setting::{
+            schemas::{SettingKey, SettingsExt},
+            utils::get_setting_value,
+        },
         user::{schemas::MinimalUserAccount, utils::get_minimal_user_list},
+        web_socket::{schemas::ProcessType, utils::send_notification},
     },
-    schemas::{AlertStatus, AllowedPermission, PermissionType},
+    schemas::{AlertStatus, AllowedPermission, PermissionType, Status},
     slack_client::{SlackBlockType, SlackClient, SlackNotificationPayload, SlackTextType},
     utils::snake_to_title_case,
+    websocket_client::{Server, WebSocketActionType},
 };
 
 use super::{
-    models::{LeaveDataModel, LeavePeriodModel, MinimalLeaveModel},
+    models::{
+        AccrualUserLeaveModel, CarriedUserLeaveModel, CarryForwardSourceModel, HolidayModel,
+        LeaveAccrualPolicyModel, LeaveApprovalModel, LeaveApprovalStageModel,
+        LeaveApproverCandidateModel, LeaveAutoApprovalRuleModel, LeaveBlackoutModel,
+        LeaveCarryForwardRuleModel, LeaveCommentModel, LeaveDataModel, LeaveLedgerModel,
+        LeavePeriodModel, LeaveReminderTargetModel, LeaveTypePolicyModel, MinimalLeaveModel,
+        UserLeaveAllocationModel, UserLeaveDateModel,
+    },
     schemas::{
-        BulkLeavePeriodInsert, BulkLeaveRequestInsert, BulkLeaveTypeInsert,
-        BulkLeaveTypePeriodInsert, BulkUserLeaveInsert, CreateLeaveRequest, FetchLeaveQuery,
-        LeaveGroup, LeaveGroupCreationRequest, LeavePeriodCreationData, LeavePeriodData,
-        LeaveRequestData, LeaveStatus, LeaveTypeCreationData, LeaveTypeCreationRequest,
-        LeaveTypeData, UserLeave, UserLeaveCreationData,
+        ActiveLeaveData, BulkHolidayInsert, BulkLeavePeriodInsert, BulkLeaveRequestInsert,
+        BulkLeaveTypeInsert, BulkLeaveTypePeriodInsert, BulkUserLeaveAllocationItem,
+        BulkUserLeaveAllocationResult, BulkUserLeaveInsert, CreateLeaveData, CreateLeaveRange,
+        CreateLeaveRequest, FetchLeaveQuery, HolidayCreationData, HolidayData,
+        LeaveAccrualFrequency, LeaveAccrualPolicy, LeaveAccrualPolicyData, LeaveAccrualSummary,
+        LeaveAllocationChange, LeaveApproval, LeaveApprovalStage, LeaveApprovalStageData,
+        LeaveApprovalStatus, LeaveAutoApprovalRule, LeaveAutoApprovalRuleData,
+        LeaveBalanceAdjustmentRequest, LeaveBalanceAdjustmentTarget, LeaveBlackout,
+        LeaveBlackoutCreationRequest, LeaveCarryForwardRule, LeaveCarryForwardRuleData,
+        LeaveComment, LeaveGroup, LeaveGroupCloneResult, LeaveGroupCreationRequest,
+        LeaveLedgerEntry, LeaveLedgerEntryData, LeaveLedgerEntryType, LeavePeriodCreationData,
+        LeavePeriodData, LeaveReminderType, LeaveRequestData, LeaveRolloverSummary, LeaveStatus,
+        LeaveTypeCreationData, LeaveTypeCreationRequest, LeaveTypeData, LeaveTypePolicy,
+        LeaveTypePolicyData, PendingLeaveApprovalData, UserLeave, UserLeaveAllocation,
+        UserLeaveCreationData, UserLeaveLedger,
     },
 };
 use serde_json::Value;
+
+/// Number of days ahead of the leave date for which holidays are listed in the Slack digest.
+const HOLIDAY_DIGEST_DAYS: i64 = 7;
+/// Longest date range accepted in a single leave request.
+const MAX_LEAVE_RANGE_DAYS: i64 = 366;
+
+/// Expands a date range into one leave entry per working day, skipping holidays and the
+/// weekend of the user's location (Saturday and Sunday when no location is assigned).
+pub fn expand_leave_range(
+    range: &CreateLeaveRange,
+    holiday_list: &[HolidayData],
+    location: Option<&BusinessLocation>,
+) -> Result<Vec<CreateLeaveData>, anyhow::Error> {
+    if range.start_date > range.end_date {
+        return Err(anyhow!("Start date cannot be after end date"));
+    }
+    if (range.end_date - range.start_date).num_days() >= MAX_LEAVE_RANGE_DAYS {
+        return Err(anyhow!(
+            "Leave range cannot be longer than {} days",
+            MAX_LEAVE_RANGE_DAYS
+        ));
+    }
+    let holiday_set: HashSet<NaiveDate> = holiday_list.iter().map(|h| h.date).collect();
+    let leave_data: Vec<CreateLeaveData> = range
+        .start_date
+        .iter_days()
+        .take_while(|date| *date <= range.end_date)
+        .filter(|date| match location {
+            Some(location) => location.is_working_day(*date),
+            None => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+        })
+        .filter(|date| !holiday_set.contains(date))
+        .map(|date| {
+            let period_id = if date == range.start_date {
+                range.start_period_id.unwrap_or(range.period_id)
+            } else if date == range.end_date {
+                range.end_period_id.unwrap_or(range.period_id)
+            } else {
+                range.period_id
+            };
+            CreateLeaveData { period_id, date }
+        })
+        .collect();
+    if leave_data.is_empty() {
+        return Err(anyhow!(
+            "There are no working days between {} and {}",
+            range.start_date,
+            range.end_date
+        ));
+    }
+    Ok(leave_data)
+}
+
+/// Returns the leave entries of a request, a date range is expanded into its working days.
+pub fn get_leave_request_data(
+    leave_request_data: &CreateLeaveRequest,
+    holiday_list: &[HolidayData],
+    location: Option<&BusinessLocation>,
+) -> Result<Vec<CreateLeaveData>, anyhow::Error> {
+    match &leave_request_data.leave_range {
+        Some(_) if !leave_request_data.leave_data.is_empty() => Err(anyhow!(
+            "Provide either leave data or leave range, not both"
+        )),
+        Some(range) => expand_leave_range(range, holiday_list, location),
+        None => Ok(leave_request_data.leave_data.clone()),
+    }
+}
+
 #[tracing::instrument(name = "prepare bulk leave request data", skip(created_by))]
 pub async fn prepare_bulk_leave_request_data<'a>(
     leave_request_data: &'a CreateLeaveRequest,
+    leave_data: &'a [CreateLeaveData],
     user_leave_id: Uuid,
     created_by: Uuid,
     received_by: Uuid,
@@ -51,10 +153,11 @@ pub async fn prepare_bulk_leave_request_data<'a>(
     let mut email_message_id_list = vec![];
     let mut cc_list = vec![];
     let mut receiver_id_list = vec![];
-    if leave_request_data.leave_data.is_empty() {
+    let mut attachment_url_list = vec![];
+    if leave_data.is_empty() {
         return Ok(None);
     }
-    for leave_request in leave_request_data.leave_data.iter() {
+    for leave_request in leave_data.iter() {
         created_on_list.push(current_utc);
         created_by_list.push(created_by);
         id_list.push(Uuid::new_v4());
@@ -73,6 +176,7 @@ pub async fn prepare_bulk_leave_request_data<'a>(
                 .map(|cc| serde_json::to_value(cc).unwrap()),
         );
         receiver_id_list.push(received_by);
+        attachment_url_list.push(leave_request_data.attachment_url.as_deref());
     }
     Ok(Some(BulkLeaveRequestInsert {
         id: id_list,
@@ -87,6 +191,7 @@ pub async fn prepare_bulk_leave_request_data<'a>(
         cc: cc_list,
         user_leave_id: user_leave_id_list,
         leave_period_id: leave_period_list,
+        attachment_url: attachment_url_list,
     }))
 }
 
@@ -94,10 +199,10 @@ pub async fn prepare_bulk_leave_request_data<'a>(
 pub async fn save_leave_to_database<'a>(
     transaction: &mut Transaction<'_, Postgres>,
     data: BulkLeaveRequestInsert<'a>,
-) -> Result<bool, anyhow::Error> {
+) -> Result<Vec<Uuid>, anyhow::Error> {
     let query = sqlx::query!(
         r#"
-    INSERT INTO leave_request (id, created_by, created_on, leave_period_id, date, status, reason, email_message_id, cc, receiver_id, user_leave_id)
+    INSERT INTO leave_request (id, created_by, created_on, leave_period_id, date, status, reason, email_message_id, cc, receiver_id, user_leave_id, attachment_url)
     SELECT * FROM UNNEST(
         $1::uuid[], 
         $2::uuid[], 
@@ -109,8 +214,10 @@ pub async fn save_leave_to_database<'a>(
         $8::text[], 
         $9::jsonb[], 
         $10::uuid[],
-        $11::uuid[]
+        $11::uuid[],
+        $12::text[]
     ) ON CONFLICT DO NOTHING
+    RETURNING id
     "#,
         &data.id[..] as &[Uuid],
         &data.created_by[..] as &[Uuid],
@@ -123,28 +230,31 @@ pub async fn save_leave_to_database<'a>(
         &data.cc[..] as &[Option<Value>],
         &data.receiver_id[..] as &[Uuid],
         &data.user_leave_id[..] as &[Uuid],
+        &data.attachment_url[..] as &[Option<&str>],
     );
     let query_string = query.sql();
     println!("Generated SQL query for: {}", query_string);
-    let result = transaction.execute(query).await.map_err(|e| {
+    let rows = query.fetch_all(&mut **transaction).await.map_err(|e| {
         tracing::error!("Failed to execute query: {:?}", e);
         anyhow!(e).context("A database failure occurred while saving leave request")
     })?;
 
-    Ok(result.rows_affected() > 0)
+    Ok(rows.into_iter().map(|a| a.id).collect())
 }
 
 #[tracing::instrument(name = "save leave request", skip(transaction))]
 pub async fn save_leave_request(
     transaction: &mut Transaction<'_, Postgres>,
     leave_request_data: &CreateLeaveRequest,
+    leave_data: &[CreateLeaveData],
     user_leave_id: Uuid,
     created_by: Uuid,
     received_by: Uuid,
     email_message_id: Option<&str>,
-) -> Result<bool, anyhow::Error> {
+) -> Result<Vec<Uuid>, anyhow::Error> {
     let bulk_data = prepare_bulk_leave_request_data(
         leave_request_data,
+        leave_data,
         user_leave_id,
         created_by,
         received_by,
@@ -154,7 +264,7 @@ pub async fn save_leave_request(
     if let Some(data) = bulk_data {
         return save_leave_to_database(transaction, data).await;
     }
-    Ok(false)
+    Ok(vec![])
 }
 #[tracing::instrument(name = "Fetch leave models", skip(pool))]
 pub async fn fetch_leave_models<'a>(
@@ -173,11 +283,17 @@ pub async fn fetch_leave_models<'a>(
             l_r.cc, 
             l_r.created_on,
             l_r.user_leave_id,
+            l_r.receiver_id,
             lt.label AS leave_type,
             ulr.user_id,
             lp.id AS period_id,
             lp.label AS period_label,
-            lp.value AS period_value
+            lp.value AS period_value,
+            l_r.attachment_url,
+            (
+                SELECT COUNT(*) FROM leave_request_comment AS lrc
+                WHERE lrc.leave_id = l_r.id AND lrc.is_deleted = false
+            ) AS comment_count
         FROM 
             leave_request AS l_r
         LEFT JOIN 
@@ -214,6 +330,28 @@ pub async fn fetch_leave_models<'a>(
         query_builder.push_bind(leave_id);
     }
 
+    if let Some(leave_id_list) = query.leave_id_list {
+        query_builder.push(" AND l_r.id = ANY(");
+        query_builder.push_bind(leave_id_list);
+        query_builder.push(")");
+    }
+
+    if let Some(business_id) = query.business_id {
+        query_builder.push(" AND lt.business_id =");
+        query_builder.push_bind(business_id);
+    }
+
+    if let Some(status_list) = query.status_list.filter(|a| !a.is_empty()) {
+        query_builder.push(" AND l_r.status IN (");
+        for (index, status) in status_list.iter().enumerate() {
+            if index > 0 {
+                query_builder.push(", ");
+            }
+            query_builder.push_bind(status);
+        }
+        query_builder.push(")");
+    }
+
     if let Some(start) = query.start_date {
         if let Some(tz) = query.tz {
             // Interpret NaiveDateTime as a datetime in timezone tz
@@ -355,16 +493,36 @@ pub async fn get_leaves<'a>(
 // }
 
 pub fn validate_leave_request_creation(
-    body: &CreateLeaveRequest,
+    leave_data: &[CreateLeaveData],
     user_leave: &UserLeave,
+    holiday_list: &[HolidayData],
+    location: Option<&BusinessLocation>,
 ) -> Result<(), anyhow::Error> {
     let period_map: HashMap<Uuid, &LeavePeriodData> =
         user_leave.periods.iter().map(|p| (p.id, p)).collect();
+    let holiday_map: HashMap<NaiveDate, &HolidayData> =
+        holiday_list.iter().map(|h| (h.date, h)).collect();
 
     // Sum the requested leave values
     let mut new_leave_count = BigDecimal::from(0);
 
-    for item in &body.leave_data {
+    for item in leave_data {
+        if let Some(holiday) = holiday_map.get(&item.date) {
+            return Err(anyhow!(
+                "{} is a holiday ({}), leave is not required",
+                item.date.format("%Y-%m-%d"),
+                holiday.label
+            ));
+        }
+        if let Some(location) = location
+            && !location.is_working_day(item.date)
+        {
+            return Err(anyhow!(
+                "{} is not a working day for {}, leave is not required",
+                item.date.format("%Y-%m-%d"),
+                location.name
+            ));
+        }
         match period_map.get(&item.period_id) {
             Some(period_data) => {
                 new_leave_count += &period_data.value;
@@ -383,12 +541,13 @@ pub fn validate_leave_request_creation(
         }
     }
 
-    if (&user_leave.used_count + &new_leave_count) > user_leave.allocated_count {
+    if !user_leave.can_use(&new_leave_count) {
         return Err(anyhow!(
             "You have exceeded the allowed leave count of {} for the group.",
             user_leave.allocated_count
         ));
-    } else if new_leave_count > user_leave.allocated_count {
+    } else if !user_leave.allows_negative_balance() && new_leave_count > user_leave.allocated_count
+    {
         return Err(anyhow!(
             "You are applying for more than {} leaves.",
             user_leave.allocated_count
@@ -426,6 +585,50 @@ pub async fn update_leave_request_status(
     Ok(())
 }
 
+/// Changes the date, period and reason of a leave request. Returns false without updating when
+/// another leave request of the user leave already takes the same date and period.
+#[tracing::instrument(name = "update leave request", skip(transaction))]
+pub async fn update_leave_request(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    date: NaiveDate,
+    period_id: Uuid,
+    reason: Option<&str>,
+    updated_by: Uuid,
+) -> Result<bool, anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE leave_request l_r
+        SET
+        date = $1,
+        leave_period_id = $2,
+        reason = $3,
+        updated_on = $4,
+        updated_by = $5
+        WHERE l_r.id = $6 AND NOT EXISTS (
+            SELECT 1 FROM leave_request other
+            WHERE other.user_leave_id = l_r.user_leave_id
+                AND other.leave_period_id = $2
+                AND other.date = $1
+                AND other.is_deleted = false
+                AND other.id <> l_r.id
+        )
+        "#,
+        Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()),
+        period_id,
+        reason,
+        Utc::now(),
+        updated_by,
+        id
+    );
+
+    let result = transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while updating leave request")
+    })?;
+    Ok(result.rows_affected() > 0)
+}
+
 pub fn validate_leave_status_update(
     incoming_status: &LeaveStatus,
     current_status: &LeaveStatus,
@@ -456,25 +659,48 @@ pub fn validate_leave_status_update(
         ));
     }
 
+    if current_status == &LeaveStatus::Expired {
+        return Err(GenericError::ValidationError(
+            "Leave request has already expired.".to_string(),
+        ));
+    }
+
+    if incoming_status == &LeaveStatus::CancellationRequested
+        || incoming_status == &LeaveStatus::Expired
+    {
+        return Err(GenericError::ValidationError(
+            "Leave request cannot be moved to the given status.".to_string(),
+        ));
+    }
+
     if incoming_status == &LeaveStatus::Approved && current_status == &LeaveStatus::Approved {
         return Err(GenericError::InsufficientPrevilegeError(
             "Leave request is already approved.".to_string(),
         ));
     }
 
-    if incoming_status == &LeaveStatus::Cancelled && current_status != &LeaveStatus::Approved {
+    if incoming_status == &LeaveStatus::Cancelled
+        && current_status != &LeaveStatus::Approved
+        && current_status != &LeaveStatus::CancellationRequested
+    {
         return Err(GenericError::ValidationError(
             "Only approved leaves can be cancelled.".to_string(),
         ));
     }
 
-    if incoming_status == &LeaveStatus::Rejected && current_status != &LeaveStatus::Approved {
+    if incoming_status == &LeaveStatus::Rejected
+        && current_status != &LeaveStatus::Approved
+        && current_status != &LeaveStatus::InProgress
+    {
         return Err(GenericError::InsufficientPrevilegeError(
             "You don't have sufficient privilege to approve leave requests.".to_string(),
         ));
     }
 
-    if (&leave_period.value + &user_leave.used_count) > user_leave.allocated_count {
+    // Leave under a cancellation request is already counted as used
+    if current_status != &LeaveStatus::CancellationRequested
+        && !user_leave.can_use(&leave_period.value)
+    {
         return Err(GenericError::ValidationError(format!(
             "You have exceeded the allowed leave count of {} for the group.",
             user_leave.allocated_count
@@ -526,7 +752,8 @@ async fn get_approved_leaves_by_lock(
             l_r.id, 
             lp.label as period, 
             ulr.user_id,
-            lt.label as type
+            lt.label as type,
+            lt.business_id
         FROM 
             leave_request AS l_r
         INNER JOIN 
@@ -544,9 +771,11 @@ async fn get_approved_leaves_by_lock(
         WHERE 
             l_r.is_deleted = false
             AND l_r.date = $1
+            AND l_r.status = ANY($2)
         FOR UPDATE
         "#,
         leave_date,
+        &[LeaveStatus::Approved, LeaveStatus::CancellationRequested] as &[LeaveStatus],
     )
     .fetch_all(&mut **transaction)
     .await
@@ -558,6 +787,63 @@ async fn get_approved_leaves_by_lock(
     Ok(rows)
 }
 
+#[tracing::instrument(name = "fetch approved leave dates", skip(pool))]
+async fn fetch_approved_leave_date_models(
+    pool: &PgPool,
+    user_id_list: &[Uuid],
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Result<Vec<UserLeaveDateModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        UserLeaveDateModel,
+        r#"
+        SELECT
+            ulr.user_id,
+            l_r.date
+        FROM
+            leave_request AS l_r
+        INNER JOIN
+            user_leave_relationship AS ulr
+            ON l_r.user_leave_id = ulr.id
+        WHERE
+            l_r.is_deleted = false
+            AND l_r.status = ANY($1)
+            AND ulr.user_id = ANY($2::uuid[])
+            AND l_r.date >= $3
+            AND l_r.date < $4
+        "#,
+        &[LeaveStatus::Approved, LeaveStatus::CancellationRequested] as &[LeaveStatus],
+        user_id_list,
+        start_date,
+        end_date
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while fetching approved leaves")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_approved_leave_dates(
+    pool: &PgPool,
+    user_id_list: &[Uuid],
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Result<HashMap<Uuid, Vec<NaiveDate>>, anyhow::Error> {
+    let models = fetch_approved_leave_date_models(pool, user_id_list, start_date, end_date).await?;
+    let mut leave_map: HashMap<Uuid, Vec<NaiveDate>> = HashMap::new();
+    for model in models {
+        leave_map
+            .entry(model.user_id)
+            .or_default()
+            .push(model.date.date_naive());
+    }
+    Ok(leave_map)
+}
+
 #[tracing::instrument(name = "update leave alert status", skip(transaction))]
 pub async fn update_leave_alert_status(
     id_list: &Vec<Uuid>,
@@ -611,6 +897,20 @@ pub async fn send_slack_notification_for_approved_leave(
         let users = get_minimal_user_list(pool, None, 1000, 0, Some(&sender_id_list)).await?;
         let user_map: HashMap<Uuid, MinimalUserAccount> =
             users.into_iter().map(|x| (x.id, x)).collect();
+        let business_id_list: Vec<Uuid> = leave_data
+            .iter()
+            .map(|a| a.business_id)
+            .collect::<HashSet<Uuid>>()
+            .into_iter()
+            .collect();
+        let holiday_start_date = leave_date.date_naive();
+        let holidays = fetch_holiday_models_by_business_list(
+            pool,
+            &business_id_list,
+            holiday_start_date,
+            holiday_start_date + chrono::Duration::days(HOLIDAY_DIGEST_DAYS),
+        )
+        .await?;
 
         let mut notification = SlackNotificationPayload::new("Leave Notification".to_string())
             .add_section(
@@ -644,6 +944,18 @@ pub async fn send_slack_notification_for_approved_leave(
                 SlackTextType::Mrkdwn,
             );
         }
+        if !holidays.is_empty() {
+            let holiday_string = holidays
+                .iter()
+                .map(|h| format!("{} ({})", h.label, h.date.format("%Y-%m-%d")))
+                .collect::<Vec<String>>()
+                .join(", ");
+            notification = notification.add_section(
+                format!("🎉 *Upcoming Holidays*: {}", holiday_string),
+                SlackBlockType::Section,
+                SlackTextType::Mrkdwn,
+            );
+        }
         let result = slack_client
             .send_notification(notification.build(), &slack_client.channel.leave)
             .await;
@@ -679,6 +991,7 @@ pub async fn prepare_bulk_leave_type_data<'a>(
     let mut business_id_list = vec![];
     let mut created_by_list = vec![];
     let mut allowed_dates = vec![];
+    let mut eligibility_list = vec![];
     if leave_type_data.is_empty() {
         return None;
     }
@@ -710,6 +1023,12 @@ pub async fn prepare_bulk_leave_type_data<'a>(
         allowed_dates.push(leave_data.allowed_dates.as_ref().map(|dates| {
             serde_json::to_value(dates.iter().map(|a| a.into_model()).collect::<Vec<_>>()).unwrap()
         }));
+        eligibility_list.push(
+            leave_data
+                .eligibility
+                .as_ref()
+                .map(|a| serde_json::to_value(a).unwrap()),
+        );
     }
     Some(BulkLeaveTypeInsert {
         id: id_list,
@@ -718,6 +1037,7 @@ pub async fn prepare_bulk_leave_type_data<'a>(
         created_by: created_by_list,
         business_id: business_id_list,
         allowed_dates: allowed_dates,
+        eligibility: eligibility_list,
     })
 }
 
@@ -729,10 +1049,11 @@ pub async fn save_leave_type_to_database<'a>(
 ) -> Result<HashMap<String, Uuid>, anyhow::Error> {
     let query = sqlx::query!(
         r#"
-        INSERT INTO leave_type (id, created_by, created_on, label, business_id, allowed_dates)
-        SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::TIMESTAMP[],  $4::TEXT[], $5::uuid[], $6::jsonb[]) 
+        INSERT INTO leave_type (id, created_by, created_on, label, business_id, allowed_dates, eligibility)
+        SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::TIMESTAMP[],  $4::TEXT[], $5::uuid[], $6::jsonb[], $7::jsonb[]) 
         ON CONFLICT (id) DO UPDATE
         SET label = EXCLUDED.label,
+        eligibility = EXCLUDED.eligibility,
         updated_by = EXCLUDED.created_by,
         updated_on = EXCLUDED.created_on
         RETURNING id, label
@@ -743,6 +1064,7 @@ pub async fn save_leave_type_to_database<'a>(
         &data.label[..] as &[&str],
         &data.business_id[..] as &[Uuid],
         &data.allowed_dates[..] as &[Option<Value>],
+        &data.eligibility[..] as &[Option<Value>],
     );
     let query_string = query.sql();
     println!("Generated SQL query for: {}", query_string);
@@ -795,7 +1117,7 @@ async fn fetch_leave_type_models<'a>(
 ) -> Result<Vec<LeaveTypeModel>, anyhow::Error> {
     let mut query_builder = QueryBuilder::new(
         r#"
-        SELECT id, label, allowed_dates FROM leave_type WHERE business_id="#,
+        SELECT id, label, allowed_dates, eligibility FROM leave_type WHERE business_id="#,
     );
     query_builder.push_bind(business_id);
     if let Some(id_list) = id_list {
@@ -868,6 +1190,41 @@ pub async fn delete_leave_type(pool: &PgPool, id: Uuid) -> Result<(), anyhow::Er
 
     Ok(())
 }
+
+/// Ensures the member is eligible for every leave type in the list.
+pub fn validate_leave_type_eligibility(
+    leave_type_list: &[&LeaveTypeData],
+    membership: Option<&BusinessUserMembership>,
+    now: DateTime<Utc>,
+) -> Result<(), GenericError> {
+    let membership = membership.ok_or_else(|| {
+        GenericError::DataNotFound("User is not associated with the business".to_string())
+    })?;
+    let reason_list: Vec<String> = leave_type_list
+        .iter()
+        .filter_map(|a| a.ineligibility_reason(membership, now))
+        .collect();
+    if !reason_list.is_empty() {
+        return Err(GenericError::ValidationError(reason_list.join(", ")));
+    }
+    Ok(())
+}
+
+/// Splits the users into the ones eligible for the leave type and the rest, users who are not
+/// part of the business are never eligible.
+pub fn partition_eligible_user_id_list(
+    leave_type: &LeaveTypeData,
+    user_id_list: &[Uuid],
+    membership_map: &HashMap<Uuid, BusinessUserMembership>,
+    now: DateTime<Utc>,
+) -> (Vec<Uuid>, Vec<Uuid>) {
+    user_id_list.iter().copied().partition(|user_id| {
+        membership_map
+            .get(user_id)
+            .is_some_and(|membership| leave_type.ineligibility_reason(membership, now).is_none())
+    })
+}
+
 pub async fn leave_type_create_validation(
     pool: &PgPool,
     req: &LeaveTypeCreationRequest,
@@ -892,6 +1249,16 @@ pub async fn leave_type_create_validation(
             )));
         }
         period_id_list.extend(&item.period_id_list);
+        if item
+            .eligibility
+            .as_ref()
+            .is_some_and(|a| a.min_tenure_days.is_some_and(|d| d < 0))
+        {
+            return Err(GenericError::ValidationError(format!(
+                "Minimum tenure cannot be negative for type {}",
+                item.label
+            )));
+        }
     }
     if !label_list.is_empty() {
         let leave_types = get_leave_type(pool, business_id, None, Some(label_list), None)
@@ -1109,7 +1476,22 @@ async fn fetch_user_leave_models(
             u_l.user_id,
             l_g.business_id,
             lt.label AS leave_type_label,
-            l_g.label AS leave_group_label
+            l_g.label AS leave_group_label,
+            (
+                SELECT jsonb_build_object(
+                    'id', ltp.id,
+                    'leave_type_id', ltp.leave_type_id,
+                    'min_notice_days', ltp.min_notice_days,
+                    'max_consecutive_days', ltp.max_consecutive_days,
+                    'max_requests_per_month', ltp.max_requests_per_month,
+                    'allow_negative_balance', ltp.allow_negative_balance,
+                    'max_negative_balance', ltp.max_negative_balance::text,
+                    'allow_backdated', ltp.allow_backdated,
+                    'require_attachment', ltp.require_attachment
+                )
+                FROM leave_type_policy AS ltp
+                WHERE ltp.leave_type_id = lt.id
+            ) AS policy
         FROM user_leave_relationship AS u_l
         INNER JOIN leave_group AS l_g ON u_l.leave_group_id = l_g.id
         INNER JOIN leave_type AS lt ON u_l.leave_type_id = lt.id
@@ -1213,7 +1595,7 @@ pub async fn delete_user_leave(
 #[tracing::instrument(name = "prepare bulk leave type data", skip())]
 pub fn prepare_bulk_user_leave_data<'a>(
     leave_type_data: &'a Vec<UserLeaveCreationData>,
-    user_id: Uuid,
+    user_ids: &[Uuid],
     group_id: Uuid,
     created_by: Uuid,
 ) -> BulkUserLeaveInsert<'a> {
@@ -1225,14 +1607,16 @@ pub fn prepare_bulk_user_leave_data<'a>(
     let mut type_id_list = vec![];
     let mut allocated_count_list = vec![];
     let mut user_id_list = vec![];
-    for leave_data in leave_type_data.iter() {
-        created_on_list.push(current_utc);
-        created_by_list.push(created_by);
-        id_list.push(Uuid::new_v4());
-        group_id_list.push(group_id);
-        type_id_list.push(leave_data.type_id);
-        allocated_count_list.push(&leave_data.count);
-        user_id_list.push(user_id);
+    for user_id in user_ids.iter() {
+        for leave_data in leave_type_data.iter() {
+            created_on_list.push(current_utc);
+            created_by_list.push(created_by);
+            id_list.push(Uuid::new_v4());
+            group_id_list.push(group_id);
+            type_id_list.push(leave_data.type_id);
+            allocated_count_list.push(&leave_data.count);
+            user_id_list.push(*user_id);
+        }
     }
     BulkUserLeaveInsert {
         id: id_list,
@@ -1245,25 +1629,35 @@ pub fn prepare_bulk_user_leave_data<'a>(
     }
 }
 
+/// Saves the leave allocation of every user in `user_id_list` in a single insert. Existing user
+/// leaves are overwritten unless `skip_existing` is set. Returns the `(user_id, leave_type_id)`
+/// of the user leaves created or updated.
 pub async fn save_user_leave(
     pool: &PgPool,
     data: &Vec<UserLeaveCreationData>,
-    user_id: Uuid,
+    user_id_list: &[Uuid],
     group_id: Uuid,
     created_by: Uuid,
-) -> Result<(), anyhow::Error> {
-    if data.is_empty() {
-        return Ok(());
+    skip_existing: bool,
+) -> Result<Vec<(Uuid, Uuid)>, anyhow::Error> {
+    if data.is_empty() || user_id_list.is_empty() {
+        return Ok(vec![]);
     }
-    let data = prepare_bulk_user_leave_data(data, user_id, group_id, created_by);
+    let data = prepare_bulk_user_leave_data(data, user_id_list, group_id, created_by);
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
     let query = sqlx::query!(
         r#"
         INSERT INTO user_leave_relationship ( id, leave_type_id, leave_group_id, allocated_count, user_id, created_by, created_on)
         SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::decimal[],  $5::uuid[], $6::uuid[], $7::TIMESTAMP[])
         ON CONFLICT (user_id, leave_group_id, leave_type_id) DO UPDATE
-        SET allocated_count = EXCLUDED.allocated_count,
+        SET allocated_count = EXCLUDED.allocated_count + user_leave_relationship.carried_count + user_leave_relationship.accrued_count,
         updated_by = EXCLUDED.created_by,
         updated_on = EXCLUDED.created_on
+        WHERE NOT $8
+        RETURNING user_id, leave_type_id
         "#,
         &data.id[..] as &[Uuid],
         &data.type_id[..] as &[Uuid],
@@ -1272,14 +1666,172 @@ pub async fn save_user_leave(
         &data.user_id[..] as &[Uuid],
         &data.created_by[..] as &[Uuid],
         &data.created_on[..] as &[DateTime<Utc>],
+        skip_existing
     );
-    pool.execute(query).await.map_err(|e| {
+    let saved_list = query.fetch_all(&mut *transaction).await.map_err(|e| {
         tracing::error!("Failed to execute query: {:?}", e);
         anyhow!(e).context("A database failure occurred while saving user leave")
     })?;
+    save_allocation_ledger(
+        &mut transaction,
+        user_id_list,
+        group_id,
+        &data.type_id,
+        created_by,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to save user leave.")?;
+    Ok(saved_list
+        .into_iter()
+        .map(|a| (a.user_id, a.leave_type_id))
+        .collect())
+}
+
+/// Records the difference between the allocated count and the allocation recorded in the
+/// ledger, so that re-allocating a user leave only logs the change made.
+#[tracing::instrument(name = "save allocation ledger", skip(transaction))]
+pub async fn save_allocation_ledger(
+    transaction: &mut Transaction<'_, Postgres>,
+    user_id_list: &[Uuid],
+    group_id: Uuid,
+    type_id_list: &[Uuid],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_ledger (id, user_leave_id, entry_type, allocated_change, used_change, created_on, created_by)
+        SELECT uuid_generate_v4(), ulr.id, 'allocation', ulr.allocated_count - COALESCE(SUM(ll.allocated_change), 0), 0, $4, $5
+        FROM user_leave_relationship ulr
+        LEFT JOIN leave_ledger ll ON ll.user_leave_id = ulr.id
+        WHERE ulr.user_id = ANY($1) AND ulr.leave_group_id = $2 AND ulr.leave_type_id = ANY($3)
+        GROUP BY ulr.id, ulr.allocated_count
+        HAVING ulr.allocated_count - COALESCE(SUM(ll.allocated_change), 0) <> 0
+        "#,
+        user_id_list,
+        group_id,
+        type_id_list,
+        Utc::now(),
+        created_by
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving allocation ledger")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave ledger", skip(transaction))]
+pub async fn save_leave_ledger(
+    transaction: &mut Transaction<'_, Postgres>,
+    entry_list: &[LeaveLedgerEntryData<'_>],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    if entry_list.is_empty() {
+        return Ok(());
+    }
+    let current_utc = Utc::now();
+    let id_list: Vec<Uuid> = entry_list.iter().map(|_| Uuid::new_v4()).collect();
+    let user_leave_id_list: Vec<Uuid> = entry_list.iter().map(|a| a.user_leave_id).collect();
+    let entry_type_list: Vec<LeaveLedgerEntryType> =
+        entry_list.iter().map(|a| a.entry_type.to_owned()).collect();
+    let allocated_change_list: Vec<&BigDecimal> =
+        entry_list.iter().map(|a| &a.allocated_change).collect();
+    let used_change_list: Vec<&BigDecimal> = entry_list.iter().map(|a| &a.used_change).collect();
+    let leave_id_list: Vec<Option<Uuid>> = entry_list.iter().map(|a| a.leave_id).collect();
+    let reason_list: Vec<Option<&str>> = entry_list.iter().map(|a| a.reason).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_ledger (id, user_leave_id, entry_type, allocated_change, used_change, leave_id, reason, created_on, created_by)
+        SELECT id, user_leave_id, entry_type, allocated_change, used_change, leave_id, reason, $8, $9
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::leave_ledger_entry_type[], $4::decimal[], $5::decimal[], $6::uuid[], $7::text[])
+        AS t(id, user_leave_id, entry_type, allocated_change, used_change, leave_id, reason)
+        "#,
+        &id_list[..] as &[Uuid],
+        &user_leave_id_list[..] as &[Uuid],
+        &entry_type_list[..] as &[LeaveLedgerEntryType],
+        &allocated_change_list[..] as &[&BigDecimal],
+        &used_change_list[..] as &[&BigDecimal],
+        &leave_id_list[..] as &[Option<Uuid>],
+        &reason_list[..] as &[Option<&str>],
+        current_utc,
+        created_by
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave ledger")
+    })?;
     Ok(())
 }
 
+#[tracing::instrument(name = "fetch leave ledger", skip(pool))]
+pub async fn fetch_leave_ledger_models(
+    pool: &PgPool,
+    user_leave_id_list: &Vec<Uuid>,
+) -> Result<Vec<LeaveLedgerModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveLedgerModel,
+        r#"
+        SELECT id, user_leave_id, entry_type as "entry_type: LeaveLedgerEntryType",
+        allocated_change, used_change, leave_id, reason, created_by, created_on
+        FROM leave_ledger
+        WHERE user_leave_id = ANY($1)
+        ORDER BY created_on, id
+        "#,
+        user_leave_id_list
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave ledger")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_ledger(
+    pool: &PgPool,
+    user_leave_id_list: &Vec<Uuid>,
+) -> Result<Vec<LeaveLedgerEntry>, anyhow::Error> {
+    let models = fetch_leave_ledger_models(pool, user_leave_id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+/// Attaches the ledger entries to each user leave and checks the stored counts against the
+/// totals derived from the ledger.
+pub fn build_user_leave_ledger(
+    user_leave_list: Vec<UserLeave>,
+    entry_list: Vec<LeaveLedgerEntry>,
+) -> Vec<UserLeaveLedger> {
+    let mut entry_map: HashMap<Uuid, Vec<LeaveLedgerEntry>> = HashMap::new();
+    for entry in entry_list {
+        entry_map
+            .entry(entry.user_leave_id)
+            .or_default()
+            .push(entry);
+    }
+    user_leave_list
+        .into_iter()
+        .map(|user_leave| {
+            let entry_list = entry_map.remove(&user_leave.id).unwrap_or_default();
+            let ledger_allocated_count: BigDecimal =
+                entry_list.iter().map(|a| &a.allocated_change).sum();
+            let ledger_used_count: BigDecimal = entry_list.iter().map(|a| &a.used_change).sum();
+            UserLeaveLedger {
+                user_leave_id: user_leave.id,
+                leave_type: user_leave.leave_type,
+                is_reconciled: ledger_allocated_count == user_leave.allocated_count
+                    && ledger_used_count == user_leave.used_count,
+                allocated_count: user_leave.allocated_count,
+                used_count: user_leave.used_count,
+                entry_list,
+            }
+        })
+        .collect()
+}
+
 #[tracing::instrument(name = "reactivate user account", skip(transaction))]
 pub async fn update_user_leave_count(
     transaction: &mut Transaction<'_, Postgres>,
@@ -1309,6 +1861,73 @@ pub async fn update_user_leave_count(
     Ok(())
 }
 
+#[tracing::instrument(name = "update user leave allocation", skip(transaction))]
+pub async fn update_user_leave_allocation(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    count: &BigDecimal,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE user_leave_relationship
+        SET
+        allocated_count = allocated_count + $1,
+        updated_on = $2,
+        updated_by = $3
+        WHERE id = $4
+        "#,
+        count,
+        Utc::now(),
+        updated_by,
+        id
+    );
+
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while updating leave allocation")
+    })?;
+    Ok(())
+}
+
+pub fn validate_leave_balance_adjustment(
+    body: &LeaveBalanceAdjustmentRequest,
+    user_leave: &UserLeave,
+) -> Result<(), GenericError> {
+    if body.amount == BigDecimal::default() {
+        return Err(GenericError::ValidationError(
+            "Adjustment amount cannot be zero.".to_string(),
+        ));
+    }
+    if body.reason.trim().is_empty() {
+        return Err(GenericError::ValidationError(
+            "Reason is mandatory for a leave balance adjustment.".to_string(),
+        ));
+    }
+    let (allocated_count, used_count) = match body.target {
+        LeaveBalanceAdjustmentTarget::Allocated => (
+            &user_leave.allocated_count + &body.amount,
+            user_leave.used_count.clone(),
+        ),
+        LeaveBalanceAdjustmentTarget::Used => (
+            user_leave.allocated_count.clone(),
+            &user_leave.used_count + &body.amount,
+        ),
+    };
+    if used_count < BigDecimal::default() {
+        return Err(GenericError::ValidationError(
+            "Used leave count cannot go below zero.".to_string(),
+        ));
+    }
+    if used_count > allocated_count {
+        return Err(GenericError::ValidationError(format!(
+            "Adjustment leaves {} used against an allocation of {}.",
+            used_count, allocated_count
+        )));
+    }
+    Ok(())
+}
+
 #[tracing::instrument(name = "prepare bulk leave period data", skip(created_by))]
 pub async fn prepare_bulk_leave_period_data<'a>(
     leave_type_data: &'a Vec<LeavePeriodCreationData>,
@@ -1608,3 +2227,2923 @@ pub async fn fetch_leave_periods_by_association(
 
     Ok(rows)
 }
+
+#[tracing::instrument(name = "prepare bulk holiday data", skip(created_by))]
+pub fn prepare_bulk_holiday_data<'a>(
+    holiday_data: &'a [HolidayCreationData],
+    business_id: Uuid,
+    created_by: Uuid,
+) -> Option<BulkHolidayInsert<'a>> {
+    if holiday_data.is_empty() {
+        return None;
+    }
+    let current_utc = Utc::now();
+    let mut id_list = vec![];
+    let mut label_list = vec![];
+    let mut date_list = vec![];
+    let mut created_on_list = vec![];
+    let mut created_by_list = vec![];
+    let mut business_id_list = vec![];
+    for holiday in holiday_data.iter() {
+        id_list.push(holiday.id.unwrap_or_else(Uuid::new_v4));
+        label_list.push(holiday.label.as_str());
+        date_list.push(holiday.date);
+        created_on_list.push(current_utc);
+        created_by_list.push(created_by);
+        business_id_list.push(business_id);
+    }
+    Some(BulkHolidayInsert {
+        id: id_list,
+        label: label_list,
+        date: date_list,
+        created_on: created_on_list,
+        created_by: created_by_list,
+        business_id: business_id_list,
+    })
+}
+
+#[tracing::instrument(name = "save holiday", skip(pool))]
+pub async fn save_holiday(
+    pool: &PgPool,
+    holiday_data: &[HolidayCreationData],
+    created_by: Uuid,
+    business_id: Uuid,
+) -> Result<bool, anyhow::Error> {
+    let Some(data) = prepare_bulk_holiday_data(holiday_data, business_id, created_by) else {
+        return Ok(false);
+    };
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO business_holiday (id, created_by, created_on, label, date, business_id)
+        SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::timestamptz[], $4::TEXT[], $5::date[], $6::uuid[])
+        ON CONFLICT (id) DO UPDATE
+        SET label = EXCLUDED.label,
+        date = EXCLUDED.date,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        "#,
+        &data.id[..] as &[Uuid],
+        &data.created_by[..] as &[Uuid],
+        &data.created_on[..] as &[DateTime<Utc>],
+        &data.label[..] as &[&str],
+        &data.date[..] as &[NaiveDate],
+        &data.business_id[..] as &[Uuid],
+    );
+    let result = pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving holiday")
+    })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[tracing::instrument(name = "Fetch holiday models", skip(pool))]
+async fn fetch_holiday_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    id_list: Option<&Vec<Uuid>>,
+    date_list: Option<&Vec<NaiveDate>>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+) -> Result<Vec<HolidayModel>, anyhow::Error> {
+    let mut query_builder = QueryBuilder::new(
+        r#"
+        SELECT id, label, date FROM business_holiday WHERE business_id="#,
+    );
+    query_builder.push_bind(business_id);
+    if let Some(id_list) = id_list
+        && !id_list.is_empty()
+    {
+        query_builder.push(" AND id = ANY(");
+        query_builder.push_bind(id_list);
+        query_builder.push(")");
+    }
+    if let Some(date_list) = date_list
+        && !date_list.is_empty()
+    {
+        query_builder.push(" AND date = ANY(");
+        query_builder.push_bind(date_list);
+        query_builder.push(")");
+    }
+    if let Some(start_date) = start_date {
+        query_builder.push(" AND date >= ");
+        query_builder.push_bind(start_date);
+    }
+    if let Some(end_date) = end_date {
+        query_builder.push(" AND date <= ");
+        query_builder.push_bind(end_date);
+    }
+    query_builder.push(" ORDER BY date");
+
+    let query = query_builder.build_query_as::<HolidayModel>();
+    let holidays = query.fetch_all(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching holiday")
+    })?;
+
+    Ok(holidays)
+}
+
+#[tracing::instrument(name = "get holiday", skip(pool))]
+pub async fn get_holiday(
+    pool: &PgPool,
+    business_id: Uuid,
+    id_list: Option<&Vec<Uuid>>,
+    date_list: Option<&Vec<NaiveDate>>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+) -> Result<Vec<HolidayData>, anyhow::Error> {
+    let data_models =
+        fetch_holiday_models(pool, business_id, id_list, date_list, start_date, end_date).await?;
+    let data = data_models.into_iter().map(|a| a.into_schema()).collect();
+    Ok(data)
+}
+
+async fn fetch_holiday_models_by_business_list(
+    pool: &PgPool,
+    business_id_list: &[Uuid],
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<HolidayModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        HolidayModel,
+        r#"
+        SELECT id, label, date
+        FROM business_holiday
+        WHERE business_id = ANY($1::uuid[])
+          AND date >= $2
+          AND date <= $3
+        ORDER BY date
+        "#,
+        business_id_list,
+        start_date,
+        end_date
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while fetching holiday")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn holiday_create_validation(
+    pool: &PgPool,
+    holiday_data: &[HolidayCreationData],
+    business_id: Uuid,
+) -> Result<(), GenericError> {
+    if holiday_data.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Incoming Data is empty".to_string(),
+        ));
+    }
+    let date_list: Vec<NaiveDate> = holiday_data.iter().map(|a| a.date).collect();
+    if date_list.iter().collect::<HashSet<_>>().len() != date_list.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate holiday dates found in the request".to_string(),
+        ));
+    }
+    let incoming_id_list: HashSet<Uuid> = holiday_data.iter().filter_map(|a| a.id).collect();
+    let existing_holidays = get_holiday(pool, business_id, None, Some(&date_list), None, None)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching holiday".to_string(),
+                e,
+            )
+        })?;
+    let conflicting_dates = existing_holidays
+        .iter()
+        .filter(|a| !incoming_id_list.contains(&a.id))
+        .map(|a| a.date.format("%Y-%m-%d").to_string())
+        .collect::<Vec<String>>();
+    if !conflicting_dates.is_empty() {
+        return Err(GenericError::ValidationError(format!(
+            "Holiday already exists on: {}",
+            conflicting_dates.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "delete holiday", skip(pool))]
+pub async fn delete_holiday(pool: &PgPool, id: Uuid) -> Result<(), anyhow::Error> {
+    sqlx::query("DELETE FROM business_holiday WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to execute query: {:?}", e);
+            anyhow!(e).context("A database failure occurred while deleting holiday")
+        })?;
+
+    Ok(())
+}
+
+pub fn validate_leave_approval_stage_data(
+    stage_list: &[LeaveApprovalStageData],
+) -> Result<(), GenericError> {
+    if stage_list.iter().any(|a| a.label.trim().is_empty()) {
+        return Err(GenericError::ValidationError(
+            "Approval stage label cannot be empty".to_string(),
+        ));
+    }
+    let label_set: HashSet<&str> = stage_list.iter().map(|a| a.label.trim()).collect();
+    if label_set.len() != stage_list.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate approval stage labels found".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Replaces the approval stages of a leave type, or the business wide default when no leave type is given.
+#[tracing::instrument(name = "save leave approval stage", skip(transaction))]
+pub async fn save_leave_approval_stage(
+    transaction: &mut Transaction<'_, Postgres>,
+    business_id: Uuid,
+    leave_type_id: Option<Uuid>,
+    stage_list: &[LeaveApprovalStageData],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        DELETE FROM leave_approval_stage
+        WHERE business_id = $1 AND leave_type_id IS NOT DISTINCT FROM $2
+        "#,
+        business_id,
+        leave_type_id
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave approval stage")
+    })?;
+    if stage_list.is_empty() {
+        return Ok(());
+    }
+
+    let current_utc = Utc::now();
+    let id_list: Vec<Uuid> = stage_list.iter().map(|_| Uuid::new_v4()).collect();
+    let stage_order_list: Vec<i32> = (1..=stage_list.len() as i32).collect();
+    let label_list: Vec<&str> = stage_list.iter().map(|a| a.label.trim()).collect();
+    let role_id_list: Vec<Uuid> = stage_list.iter().map(|a| a.approver_role_id).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_approval_stage (id, stage_order, label, approver_role_id, business_id, leave_type_id, created_on, created_by)
+        SELECT id, stage_order, label, approver_role_id, $5, $6, $7, $8
+        FROM UNNEST($1::uuid[], $2::int[], $3::text[], $4::uuid[]) AS t(id, stage_order, label, approver_role_id)
+        "#,
+        &id_list[..] as &[Uuid],
+        &stage_order_list[..] as &[i32],
+        &label_list[..] as &[&str],
+        &role_id_list[..] as &[Uuid],
+        business_id,
+        leave_type_id,
+        current_utc,
+        created_by
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave approval stage")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave approval stage models", skip(pool))]
+pub async fn fetch_leave_approval_stage_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id: Option<Uuid>,
+    with_default: bool,
+) -> Result<Vec<LeaveApprovalStageModel>, anyhow::Error> {
+    let mut query_builder = QueryBuilder::<Postgres>::new(
+        "SELECT id, leave_type_id, stage_order, label, approver_role_id FROM leave_approval_stage WHERE business_id = ",
+    );
+    query_builder.push_bind(business_id);
+    query_builder.push(" AND (leave_type_id IS NOT DISTINCT FROM ");
+    query_builder.push_bind(leave_type_id);
+    if with_default {
+        query_builder.push(" OR leave_type_id IS NULL");
+    }
+    query_builder.push(") ORDER BY stage_order");
+
+    let query = query_builder.build_query_as::<LeaveApprovalStageModel>();
+    let rows = query.fetch_all(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave approval stage")
+    })?;
+    Ok(rows)
+}
+
+/// With `with_default`, stages of the leave type are returned when configured,
+/// otherwise the business wide default stages.
+pub async fn get_leave_approval_stages(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id: Option<Uuid>,
+    with_default: bool,
+) -> Result<Vec<LeaveApprovalStage>, anyhow::Error> {
+    let stage_list: Vec<LeaveApprovalStage> =
+        fetch_leave_approval_stage_models(pool, business_id, leave_type_id, with_default)
+            .await?
+            .into_iter()
+            .map(|a| a.into_schema())
+            .collect();
+    let has_type_stages =
+        leave_type_id.is_some() && stage_list.iter().any(|a| a.leave_type_id == leave_type_id);
+    Ok(stage_list
+        .into_iter()
+        .filter(|a| a.leave_type_id.is_some() == has_type_stages)
+        .collect())
+}
+
+#[tracing::instrument(name = "save leave request approval", skip(transaction))]
+pub async fn save_leave_approval(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_id_list: &[Uuid],
+    stage_list: &[LeaveApprovalStage],
+) -> Result<(), anyhow::Error> {
+    if leave_id_list.is_empty() || stage_list.is_empty() {
+        return Ok(());
+    }
+    let mut id_list = vec![];
+    let mut leave_list = vec![];
+    let mut stage_order_list = vec![];
+    let mut label_list = vec![];
+    let mut role_id_list = vec![];
+    for leave_id in leave_id_list {
+        for stage in stage_list {
+            id_list.push(Uuid::new_v4());
+            leave_list.push(*leave_id);
+            stage_order_list.push(stage.stage_order);
+            label_list.push(stage.label.as_str());
+            role_id_list.push(stage.approver_role_id);
+        }
+    }
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_request_approval (id, leave_id, stage_order, label, approver_role_id, created_on)
+        SELECT id, leave_id, stage_order, label, approver_role_id, $6
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::text[], $5::uuid[]) AS t(id, leave_id, stage_order, label, approver_role_id)
+        "#,
+        &id_list[..] as &[Uuid],
+        &leave_list[..] as &[Uuid],
+        &stage_order_list[..] as &[i32],
+        &label_list[..] as &[&str],
+        &role_id_list[..] as &[Uuid],
+        Utc::now()
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave request approval")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave request approval models", skip(pool))]
+pub async fn fetch_leave_approval_models(
+    pool: &PgPool,
+    leave_id_list: &Vec<Uuid>,
+) -> Result<Vec<LeaveApprovalModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveApprovalModel,
+        r#"
+        SELECT id, leave_id, stage_order, label, approver_role_id,
+        status as "status: LeaveApprovalStatus", acted_by, acted_on
+        FROM leave_request_approval
+        WHERE leave_id = ANY($1)
+        ORDER BY leave_id, stage_order
+        "#,
+        leave_id_list
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave request approval")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_approvals(
+    pool: &PgPool,
+    leave_id_list: &Vec<Uuid>,
+) -> Result<Vec<LeaveApproval>, anyhow::Error> {
+    let models = fetch_leave_approval_models(pool, leave_id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "update leave request approval status", skip(transaction))]
+pub async fn update_leave_approval_status(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    status: &LeaveApprovalStatus,
+    acted_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE leave_request_approval
+        SET status = $1, acted_by = $2, acted_on = $3
+        WHERE id = $4
+        "#,
+        status as &LeaveApprovalStatus,
+        acted_by,
+        Utc::now(),
+        id
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e)
+            .context("A database failure occurred while updating leave request approval status")
+    })?;
+    Ok(())
+}
+
+/// Picks the pending approval stage acted upon and the status the leave request moves to.
+/// Requests without approval stages keep the single step behaviour.
+pub fn resolve_leave_approval<'a>(
+    incoming_status: &LeaveStatus,
+    approval_list: &'a [LeaveApproval],
+    approver_role_id: Option<Uuid>,
+) -> Result<(Option<&'a LeaveApproval>, LeaveStatus), GenericError> {
+    let pending_stage = approval_list
+        .iter()
+        .filter(|a| a.status == LeaveApprovalStatus::Pending)
+        .min_by_key(|a| a.stage_order);
+    let stage = match (incoming_status, pending_stage) {
+        (LeaveStatus::Approved | LeaveStatus::Rejected, Some(stage)) => stage,
+        _ => return Ok((None, incoming_status.to_owned())),
+    };
+    if approver_role_id != Some(stage.approver_role_id) {
+        return Err(GenericError::InsufficientPrevilegeError(format!(
+            "Leave request is pending at the {} stage, which you are not allowed to act upon.",
+            stage.label
+        )));
+    }
+    let status = match incoming_status {
+        LeaveStatus::Approved
+            if approval_list.iter().any(|a| {
+                a.status == LeaveApprovalStatus::Pending && a.stage_order > stage.stage_order
+            }) =>
+        {
+            LeaveStatus::InProgress
+        }
+        _ => incoming_status.to_owned(),
+    };
+    Ok((Some(stage), status))
+}
+
+/// Validates and applies a status change on a single leave request within the transaction.
+/// Returns the status the leave moved to along with the change made to the used leave count.
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    name = "apply leave status update",
+    skip(transaction, user_leave, approval_list, permissions)
+)]
+pub async fn apply_leave_status_update(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave: &LeaveRequestData,
+    user_leave: &UserLeave,
+    approval_list: &[LeaveApproval],
+    incoming_status: &LeaveStatus,
+    permissions: &AllowedPermission,
+    approver_role_id: Option<Uuid>,
+    updated_by: Uuid,
+) -> Result<(LeaveStatus, BigDecimal), GenericError> {
+    validate_leave_status_update(
+        incoming_status,
+        &leave.status,
+        permissions,
+        user_leave,
+        &leave.period,
+    )?;
+    let (approval_stage, status) =
+        resolve_leave_approval(incoming_status, approval_list, approver_role_id)?;
+    if let Some(stage) = approval_stage {
+        let approval_status = if incoming_status == &LeaveStatus::Rejected {
+            LeaveApprovalStatus::Rejected
+        } else {
+            LeaveApprovalStatus::Approved
+        };
+        update_leave_approval_status(transaction, stage.id, &approval_status, updated_by)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while updating leave approval".to_string(),
+                    e,
+                )
+            })?;
+    }
+    update_leave_request_status(transaction, leave.id, &status, updated_by)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while updating leave request".to_string(),
+                e,
+            )
+        })?;
+    // Leave count is only consumed once the last approval stage is cleared
+    let adjustment = match (&status, &leave.status) {
+        // Declining a cancellation request keeps the leave as it was counted
+        (LeaveStatus::Approved, LeaveStatus::CancellationRequested) => BigDecimal::default(),
+        (LeaveStatus::Approved, _) => leave.period.value.clone(),
+        (
+            LeaveStatus::Cancelled | LeaveStatus::Rejected,
+            LeaveStatus::Approved | LeaveStatus::CancellationRequested,
+        ) => -&leave.period.value,
+        _ => BigDecimal::default(),
+    };
+    if adjustment != BigDecimal::default() {
+        update_user_leave_count(transaction, leave.user_leave_id, &adjustment, updated_by)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while updating leave count".to_string(),
+                    e,
+                )
+            })?;
+        let entry_type = if status == LeaveStatus::Approved {
+            LeaveLedgerEntryType::Usage
+        } else {
+            LeaveLedgerEntryType::Reversal
+        };
+        let entry = LeaveLedgerEntryData {
+            user_leave_id: leave.user_leave_id,
+            entry_type,
+            allocated_change: BigDecimal::default(),
+            used_change: adjustment.clone(),
+            leave_id: Some(leave.id),
+            reason: None,
+        };
+        save_leave_ledger(transaction, &[entry], updated_by)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while saving leave ledger".to_string(),
+                    e,
+                )
+            })?;
+    }
+    Ok((status, adjustment))
+}
+
+/// Groups the pending leave requests by requester, ordering both the requesters and their
+/// leave requests by leave date.
+pub fn group_pending_leave_approvals(
+    mut leave_list: Vec<LeaveRequestData>,
+    user_list: &[MinimalUserAccount],
+) -> Vec<PendingLeaveApprovalData> {
+    leave_list.sort_by_key(|a| a.date);
+    let mut group_list: Vec<PendingLeaveApprovalData> = vec![];
+    for leave in leave_list {
+        match group_list.iter_mut().find(|a| a.user_id == leave.user_id) {
+            Some(group) => group.leave_list.push(leave),
+            None => {
+                let display_name = user_list
+                    .iter()
+                    .find(|a| a.id == leave.user_id)
+                    .map(|a| a.display_name.to_owned())
+                    .unwrap_or_default();
+                group_list.push(PendingLeaveApprovalData {
+                    user_id: leave.user_id,
+                    display_name,
+                    leave_list: vec![leave],
+                });
+            }
+        }
+    }
+    group_list
+}
+
+/// Schedules the leave alert once for every distinct approved leave date.
+pub async fn schedule_leave_alert(
+    producer_client: &PulsarClient,
+    date_list: &[DateTime<Utc>],
+) -> Result<(), anyhow::Error> {
+    let date_set: BTreeSet<&DateTime<Utc>> = date_list.iter().collect();
+    if date_set.is_empty() {
+        return Ok(());
+    }
+    let mut producer = producer_client
+        .get_producer(producer_client.get_product_topic(PulsarTopic::Scheduler))
+        .await;
+    for date in date_set {
+        let msg = SchedulerMessageData {
+            partition_key: None,
+            date: date.to_owned(),
+            message_type: SchedulerMessageType::LeaveAlert,
+            department_id: None,
+            leave_id_list: vec![],
+        };
+        let msg = producer
+            .create_message()
+            .with_content(msg)
+            .deliver_at(date.to_owned().into())?;
+        msg.send_non_blocking().await?;
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave approver candidate", skip(pool))]
+pub async fn fetch_leave_approver_candidate_model(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<LeaveApproverCandidateModel>, anyhow::Error> {
+    let row = sqlx::query_as!(
+        LeaveApproverCandidateModel,
+        r#"
+        SELECT
+            (
+                SELECT da.head_id FROM business_user_department_relationship budr
+                INNER JOIN department_account da ON da.id = budr.department_id
+                WHERE budr.user_id = bur.user_id AND budr.business_id = bur.business_id
+                AND da.is_deleted = false AND da.head_id IS NOT NULL AND da.head_id <> bur.user_id
+                ORDER BY budr.created_on
+                LIMIT 1
+            ) AS "department_head_id?",
+            bur.reporting_manager_id
+        FROM business_user_relationship bur
+        WHERE bur.business_id = $1 AND bur.user_id = $2
+        "#,
+        business_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave approver candidate")
+    })?;
+    Ok(row)
+}
+
+/// Picks the first candidate, in order of priority, who is allowed to approve the leave.
+pub fn pick_leave_approver(
+    requester_id: Uuid,
+    candidate_list: &[Option<Uuid>],
+    approver_id_list: &[Uuid],
+) -> Option<Uuid> {
+    candidate_list
+        .iter()
+        .flatten()
+        .find(|id| **id != requester_id && approver_id_list.contains(id))
+        .copied()
+}
+
+/// Routes a leave request to the requester's department head, then the reporting manager
+/// and finally the business level fallback approver.
+#[tracing::instrument(name = "resolve leave approver", skip(pool))]
+pub async fn resolve_leave_approver(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Uuid>, anyhow::Error> {
+    let setting_key_list = vec![SettingKey::LeaveFallbackApprover.to_string()];
+    let (candidate_res, setting_res) = tokio::join!(
+        fetch_leave_approver_candidate_model(pool, business_id, user_id),
+        get_setting_value(pool, &setting_key_list, Some(business_id), None, false),
+    );
+    let candidate = candidate_res?;
+    let fallback_approver_id = setting_res?
+        .get_setting(&SettingKey::LeaveFallbackApprover.to_string())
+        .and_then(|a| Uuid::parse_str(&a).ok());
+    let candidate_list = vec![
+        candidate.as_ref().and_then(|a| a.department_head_id),
+        candidate.as_ref().and_then(|a| a.reporting_manager_id),
+        fallback_approver_id,
+    ];
+    let candidate_id_list: Vec<Uuid> = candidate_list.iter().flatten().copied().collect();
+    if candidate_id_list.is_empty() {
+        return Ok(None);
+    }
+    let approver_id_list = fetch_user_id_list_with_business_permission(
+        pool,
+        business_id,
+        &candidate_id_list,
+        &PermissionType::ApproveLeaveRequest.to_string(),
+    )
+    .await?;
+    Ok(pick_leave_approver(
+        user_id,
+        &candidate_list,
+        &approver_id_list,
+    ))
+}
+
+pub fn validate_leave_carry_forward_rule_data(
+    data: &[LeaveCarryForwardRuleData],
+) -> Result<(), GenericError> {
+    if data
+        .iter()
+        .any(|a| a.max_carry_forward < BigDecimal::default())
+    {
+        return Err(GenericError::ValidationError(
+            "Maximum carry forward cannot be negative".to_string(),
+        ));
+    }
+    if data.iter().any(|a| a.expiry_days.is_some_and(|d| d <= 0)) {
+        return Err(GenericError::ValidationError(
+            "Carry forward expiry days must be greater than zero".to_string(),
+        ));
+    }
+    let type_set: HashSet<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    if type_set.len() != data.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate leave types found in carry forward rules".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave carry forward rule", skip(pool))]
+pub async fn save_leave_carry_forward_rule(
+    pool: &PgPool,
+    data: &[LeaveCarryForwardRuleData],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    if data.is_empty() {
+        return Ok(());
+    }
+    let id_list: Vec<Uuid> = data.iter().map(|_| Uuid::new_v4()).collect();
+    let leave_type_id_list: Vec<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    let max_carry_forward_list: Vec<&BigDecimal> =
+        data.iter().map(|a| &a.max_carry_forward).collect();
+    let expiry_days_list: Vec<Option<i32>> = data.iter().map(|a| a.expiry_days).collect();
+    let lapse_remaining_list: Vec<bool> = data.iter().map(|a| a.lapse_remaining).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_carry_forward_rule (id, leave_type_id, max_carry_forward, expiry_days, lapse_remaining, created_on, created_by)
+        SELECT id, leave_type_id, max_carry_forward, expiry_days, lapse_remaining, $6, $7
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::decimal[], $4::int[], $5::bool[])
+        AS t(id, leave_type_id, max_carry_forward, expiry_days, lapse_remaining)
+        ON CONFLICT (leave_type_id) DO UPDATE
+        SET max_carry_forward = EXCLUDED.max_carry_forward,
+        expiry_days = EXCLUDED.expiry_days,
+        lapse_remaining = EXCLUDED.lapse_remaining,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        "#,
+        &id_list[..] as &[Uuid],
+        &leave_type_id_list[..] as &[Uuid],
+        &max_carry_forward_list[..] as &[&BigDecimal],
+        &expiry_days_list[..] as &[Option<i32>],
+        &lapse_remaining_list[..] as &[bool],
+        Utc::now(),
+        created_by
+    );
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave carry forward rule")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave carry forward rule", skip(pool))]
+pub async fn fetch_leave_carry_forward_rule_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveCarryForwardRuleModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveCarryForwardRuleModel,
+        r#"
+        SELECT lcfr.id, lcfr.leave_type_id, lcfr.max_carry_forward, lcfr.expiry_days, lcfr.lapse_remaining
+        FROM leave_carry_forward_rule lcfr
+        INNER JOIN leave_type lt ON lt.id = lcfr.leave_type_id
+        WHERE lt.business_id = $1 AND ($2::uuid[] IS NULL OR lcfr.leave_type_id = ANY($2))
+        "#,
+        business_id,
+        leave_type_id_list.map(|a| &a[..]) as Option<&[Uuid]>
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave carry forward rule")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_carry_forward_rules(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveCarryForwardRule>, anyhow::Error> {
+    let models =
+        fetch_leave_carry_forward_rule_models(pool, business_id, leave_type_id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete leave carry forward rule", skip(pool))]
+pub async fn delete_leave_carry_forward_rule(
+    pool: &PgPool,
+    business_id: Uuid,
+    id: Uuid,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM leave_carry_forward_rule lcfr
+        USING leave_type lt
+        WHERE lcfr.leave_type_id = lt.id AND lcfr.id = $1 AND lt.business_id = $2
+        "#,
+        id,
+        business_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave carry forward rule")
+    })?;
+    Ok(())
+}
+
+/// Fetches the user leaves of a leave group having a carry forward rule and not carried yet.
+#[tracing::instrument(name = "fetch carry forward source", skip(transaction))]
+async fn fetch_carry_forward_source_models(
+    transaction: &mut Transaction<'_, Postgres>,
+    group_id: Uuid,
+) -> Result<Vec<CarryForwardSourceModel>, anyhow::Error> {
+    let query = sqlx::query_as!(
+        CarryForwardSourceModel,
+        r#"
+        SELECT ulr.id, ulr.user_id, ulr.leave_type_id, ulr.allocated_count, ulr.used_count
+        FROM user_leave_relationship ulr
+        INNER JOIN leave_carry_forward_rule lcfr ON lcfr.leave_type_id = ulr.leave_type_id
+        WHERE ulr.leave_group_id = $1
+        AND NOT EXISTS (
+            SELECT 1 FROM user_leave_relationship target WHERE target.carried_from_id = ulr.id
+        )
+        FOR UPDATE OF ulr
+        "#,
+        group_id
+    );
+    let rows = query.fetch_all(&mut **transaction).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching carry forward source")
+    })?;
+    Ok(rows)
+}
+
+/// Moves the closing balance of every user leave in the source group into the target group as
+/// per the carry forward rule of its leave type. User leaves already carried are skipped, so the
+/// rollover can be run again safely.
+#[tracing::instrument(name = "rollover leave group", skip(pool))]
+pub async fn rollover_leave_group(
+    pool: &PgPool,
+    business_id: Uuid,
+    source_group: &LeaveGroup,
+    target_group: &LeaveGroup,
+    created_by: Uuid,
+) -> Result<LeaveRolloverSummary, anyhow::Error> {
+    let rule_list = get_leave_carry_forward_rules(pool, business_id, None).await?;
+    let rule_map: HashMap<Uuid, &LeaveCarryForwardRule> =
+        rule_list.iter().map(|a| (a.leave_type_id, a)).collect();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let source_list = fetch_carry_forward_source_models(&mut transaction, source_group.id).await?;
+
+    let mut summary = LeaveRolloverSummary::default();
+    let current_utc = Utc::now();
+    let carried_reason = format!("Carried forward from {}", source_group.label);
+    let encashed_reason = format!("Encashed at the end of {}", source_group.label);
+    let mut id_list = vec![];
+    let mut user_id_list = vec![];
+    let mut type_id_list = vec![];
+    let mut carried_count_list = vec![];
+    let mut expire_on_list = vec![];
+    let mut carried_from_id_list = vec![];
+    let mut encashment_list = vec![];
+    for source in source_list.iter() {
+        let Some(rule) = rule_map.get(&source.leave_type_id) else {
+            continue;
+        };
+        let (carry, remaining) =
+            rule.split_balance(&(&source.allocated_count - &source.used_count));
+        id_list.push(Uuid::new_v4());
+        user_id_list.push(source.user_id);
+        type_id_list.push(source.leave_type_id);
+        expire_on_list.push(
+            rule.expiry_days
+                .map(|a| target_group.start_date + chrono::Duration::days(a as i64)),
+        );
+        carried_from_id_list.push(source.id);
+        if carry > BigDecimal::default() {
+            summary.carried_count += 1;
+        }
+        carried_count_list.push(carry);
+        if remaining > BigDecimal::default() {
+            if rule.lapse_remaining {
+                summary.lapsed_count += 1;
+            } else {
+                summary.encashed_count += 1;
+                encashment_list.push((source.id, remaining));
+            }
+        }
+    }
+    if id_list.is_empty() {
+        return Ok(summary);
+    }
+
+    let target_group_id_list = vec![target_group.id; id_list.len()];
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO user_leave_relationship (id, user_id, leave_type_id, leave_group_id, allocated_count, carried_count, carry_forward_expire_on, carried_from_id, created_by, created_on)
+        SELECT id, user_id, leave_type_id, leave_group_id, carried_count, carried_count, carry_forward_expire_on, carried_from_id, $8, $9
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::uuid[], $5::decimal[], $6::timestamptz[], $7::uuid[])
+        AS t(id, user_id, leave_type_id, leave_group_id, carried_count, carry_forward_expire_on, carried_from_id)
+        ON CONFLICT (user_id, leave_group_id, leave_type_id) DO UPDATE
+        SET allocated_count = user_leave_relationship.allocated_count + EXCLUDED.carried_count,
+        carried_count = EXCLUDED.carried_count,
+        carry_forward_expire_on = EXCLUDED.carry_forward_expire_on,
+        carried_from_id = EXCLUDED.carried_from_id,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        RETURNING id, carried_count
+        "#,
+        &id_list[..] as &[Uuid],
+        &user_id_list[..] as &[Uuid],
+        &type_id_list[..] as &[Uuid],
+        &target_group_id_list[..] as &[Uuid],
+        &carried_count_list[..] as &[BigDecimal],
+        &expire_on_list[..] as &[Option<DateTime<Utc>>],
+        &carried_from_id_list[..] as &[Uuid],
+        created_by,
+        current_utc
+    );
+    let carried_list = query.fetch_all(&mut *transaction).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while carrying forward user leave")
+    })?;
+
+    let mut entry_list: Vec<LeaveLedgerEntryData> = carried_list
+        .into_iter()
+        .filter(|a| a.carried_count > BigDecimal::default())
+        .map(|a| LeaveLedgerEntryData {
+            user_leave_id: a.id,
+            entry_type: LeaveLedgerEntryType::CarryForward,
+            allocated_change: a.carried_count,
+            used_change: BigDecimal::default(),
+            leave_id: None,
+            reason: Some(carried_reason.as_str()),
+        })
+        .collect();
+    for (user_leave_id, remaining) in encashment_list {
+        update_user_leave_count(&mut transaction, user_leave_id, &remaining, created_by).await?;
+        entry_list.push(LeaveLedgerEntryData {
+            user_leave_id,
+            entry_type: LeaveLedgerEntryType::Encashment,
+            allocated_change: BigDecimal::default(),
+            used_change: remaining,
+            leave_id: None,
+            reason: Some(encashed_reason.as_str()),
+        });
+    }
+    save_leave_ledger(&mut transaction, &entry_list, created_by).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to rollover leave group.")?;
+    Ok(summary)
+}
+
+/// Carried days are consumed before the regular allocation, so only the carried days left
+/// unused lapse on expiry.
+pub fn compute_carry_forward_lapse(
+    carried_count: &BigDecimal,
+    used_count: &BigDecimal,
+) -> BigDecimal {
+    let lapse = carried_count - used_count;
+    if lapse > BigDecimal::default() {
+        lapse
+    } else {
+        BigDecimal::default()
+    }
+}
+
+/// Removes the unused carried days of every user leave whose carry forward has expired.
+#[tracing::instrument(name = "expire carried forward leave", skip(pool))]
+pub async fn expire_carried_forward_leave(
+    pool: &PgPool,
+    date: DateTime<Utc>,
+    created_by: Uuid,
+) -> Result<usize, anyhow::Error> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let expired_list = sqlx::query_as!(
+        CarriedUserLeaveModel,
+        r#"
+        SELECT id, carried_count, used_count
+        FROM user_leave_relationship
+        WHERE carry_forward_expire_on <= $1 AND carried_count > 0
+        FOR UPDATE
+        "#,
+        date
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching expired carry forward")
+    })?;
+
+    let mut entry_list = vec![];
+    for user_leave in expired_list.iter() {
+        let lapse = compute_carry_forward_lapse(&user_leave.carried_count, &user_leave.used_count);
+        let query = sqlx::query!(
+            r#"
+            UPDATE user_leave_relationship
+            SET allocated_count = allocated_count - $1,
+            carried_count = carried_count - $1,
+            carry_forward_expire_on = NULL,
+            updated_on = $2,
+            updated_by = $3
+            WHERE id = $4
+            "#,
+            &lapse,
+            Utc::now(),
+            created_by,
+            user_leave.id
+        );
+        transaction.execute(query).await.map_err(|e| {
+            tracing::error!("Failed to execute query: {:?}", e);
+            anyhow!(e).context("A database failure occurred while expiring carry forward")
+        })?;
+        if lapse > BigDecimal::default() {
+            entry_list.push(LeaveLedgerEntryData {
+                user_leave_id: user_leave.id,
+                entry_type: LeaveLedgerEntryType::CarryForward,
+                allocated_change: -lapse,
+                used_change: BigDecimal::default(),
+                leave_id: None,
+                reason: Some("Carried forward days expired"),
+            });
+        }
+    }
+    save_leave_ledger(&mut transaction, &entry_list, created_by).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to expire carry forward.")?;
+    Ok(entry_list.len())
+}
+
+pub fn validate_leave_accrual_policy_data(
+    data: &[LeaveAccrualPolicyData],
+) -> Result<(), GenericError> {
+    if data.iter().any(|a| a.amount <= BigDecimal::default()) {
+        return Err(GenericError::ValidationError(
+            "Accrual amount must be greater than zero".to_string(),
+        ));
+    }
+    if data.iter().any(|a| {
+        a.max_balance
+            .as_ref()
+            .is_some_and(|b| b <= &BigDecimal::default())
+    }) {
+        return Err(GenericError::ValidationError(
+            "Accrual maximum balance must be greater than zero".to_string(),
+        ));
+    }
+    let type_set: HashSet<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    if type_set.len() != data.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate leave types found in accrual policies".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave accrual policy", skip(pool))]
+pub async fn save_leave_accrual_policy(
+    pool: &PgPool,
+    data: &[LeaveAccrualPolicyData],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    if data.is_empty() {
+        return Ok(());
+    }
+    let id_list: Vec<Uuid> = data.iter().map(|_| Uuid::new_v4()).collect();
+    let leave_type_id_list: Vec<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    let amount_list: Vec<&BigDecimal> = data.iter().map(|a| &a.amount).collect();
+    let frequency_list: Vec<LeaveAccrualFrequency> =
+        data.iter().map(|a| a.frequency.clone()).collect();
+    let max_balance_list: Vec<Option<BigDecimal>> =
+        data.iter().map(|a| a.max_balance.clone()).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_accrual_policy (id, leave_type_id, amount, frequency, max_balance, created_on, created_by)
+        SELECT id, leave_type_id, amount, frequency, max_balance, $6, $7
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::decimal[], $4::leave_accrual_frequency[], $5::decimal[])
+        AS t(id, leave_type_id, amount, frequency, max_balance)
+        ON CONFLICT (leave_type_id) DO UPDATE
+        SET amount = EXCLUDED.amount,
+        frequency = EXCLUDED.frequency,
+        max_balance = EXCLUDED.max_balance,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        "#,
+        &id_list[..] as &[Uuid],
+        &leave_type_id_list[..] as &[Uuid],
+        &amount_list[..] as &[&BigDecimal],
+        &frequency_list[..] as &[LeaveAccrualFrequency],
+        &max_balance_list[..] as &[Option<BigDecimal>],
+        Utc::now(),
+        created_by
+    );
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave accrual policy")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave accrual policy", skip(pool))]
+pub async fn fetch_leave_accrual_policy_models(
+    pool: &PgPool,
+    business_id: Option<Uuid>,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveAccrualPolicyModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveAccrualPolicyModel,
+        r#"
+        SELECT lap.id, lap.leave_type_id, lap.amount, lap.frequency as "frequency: LeaveAccrualFrequency", lap.max_balance
+        FROM leave_accrual_policy lap
+        INNER JOIN leave_type lt ON lt.id = lap.leave_type_id
+        WHERE ($1::uuid IS NULL OR lt.business_id = $1) AND ($2::uuid[] IS NULL OR lap.leave_type_id = ANY($2))
+        "#,
+        business_id,
+        leave_type_id_list.map(|a| &a[..]) as Option<&[Uuid]>
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave accrual policy")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_accrual_policies(
+    pool: &PgPool,
+    business_id: Option<Uuid>,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveAccrualPolicy>, anyhow::Error> {
+    let models = fetch_leave_accrual_policy_models(pool, business_id, leave_type_id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete leave accrual policy", skip(pool))]
+pub async fn delete_leave_accrual_policy(
+    pool: &PgPool,
+    business_id: Uuid,
+    id: Uuid,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM leave_accrual_policy lap
+        USING leave_type lt
+        WHERE lap.leave_type_id = lt.id AND lap.id = $1 AND lt.business_id = $2
+        "#,
+        id,
+        business_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave accrual policy")
+    })?;
+    Ok(())
+}
+
+/// Lists the accrual dates of a leave group falling on or before `till`. An accrual date is the
+/// start of an accrual period and periods which ended before the user leave was created are
+/// skipped, so users joining mid-way only accrue from their first period.
+pub fn get_accrual_date_list(
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+    created_on: DateTime<Utc>,
+    frequency: &LeaveAccrualFrequency,
+    till: DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    let mut date_list = vec![];
+    let mut period_start = start_date;
+    while period_start < end_date && period_start <= till {
+        let Some(period_end) = period_start.checked_add_months(Months::new(frequency.months()))
+        else {
+            break;
+        };
+        if period_end > created_on {
+            date_list.push(period_start);
+        }
+        period_start = period_end;
+    }
+    date_list
+}
+
+/// Fetches the user leaves of leave types having an accrual policy whose leave group has
+/// started by the given date.
+#[tracing::instrument(name = "fetch accrual user leave", skip(transaction))]
+async fn fetch_accrual_user_leave_models(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_type_id_list: &[Uuid],
+    date: DateTime<Utc>,
+) -> Result<Vec<AccrualUserLeaveModel>, anyhow::Error> {
+    let query = sqlx::query_as!(
+        AccrualUserLeaveModel,
+        r#"
+        SELECT ulr.id, ulr.leave_type_id, ulr.allocated_count, ulr.used_count, ulr.created_on,
+        lg.start_date, lg.end_date
+        FROM user_leave_relationship ulr
+        INNER JOIN leave_group lg ON lg.id = ulr.leave_group_id
+        WHERE ulr.leave_type_id = ANY($1) AND lg.start_date <= $2
+        FOR UPDATE OF ulr
+        "#,
+        leave_type_id_list,
+        date
+    );
+    let rows = query.fetch_all(&mut **transaction).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching accrual user leave")
+    })?;
+    Ok(rows)
+}
+
+#[tracing::instrument(name = "fetch leave accrual date", skip(transaction))]
+async fn fetch_leave_accrual_date_set(
+    transaction: &mut Transaction<'_, Postgres>,
+    user_leave_id_list: &[Uuid],
+) -> Result<HashSet<(Uuid, DateTime<Utc>)>, anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        SELECT user_leave_id, accrual_on
+        FROM leave_accrual
+        WHERE user_leave_id = ANY($1)
+        "#,
+        user_leave_id_list
+    );
+    let rows = query.fetch_all(&mut **transaction).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave accrual")
+    })?;
+    Ok(rows
+        .into_iter()
+        .map(|a| (a.user_leave_id, a.accrual_on))
+        .collect())
+}
+
+/// Credits every accrual date due on or before `date` which has not been recorded yet. Each
+/// processed date is stored in `leave_accrual`, so missed runs can be replayed safely.
+#[tracing::instrument(name = "accrue user leave", skip(pool))]
+pub async fn accrue_user_leave(
+    pool: &PgPool,
+    date: DateTime<Utc>,
+    created_by: Uuid,
+) -> Result<LeaveAccrualSummary, anyhow::Error> {
+    let mut summary = LeaveAccrualSummary::default();
+    let policy_list = get_leave_accrual_policies(pool, None, None).await?;
+    if policy_list.is_empty() {
+        return Ok(summary);
+    }
+    let policy_map: HashMap<Uuid, &LeaveAccrualPolicy> =
+        policy_list.iter().map(|a| (a.leave_type_id, a)).collect();
+    let leave_type_id_list: Vec<Uuid> = policy_map.keys().copied().collect();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let user_leave_list =
+        fetch_accrual_user_leave_models(&mut transaction, &leave_type_id_list, date).await?;
+    let user_leave_id_list: Vec<Uuid> = user_leave_list.iter().map(|a| a.id).collect();
+    let accrued_set = fetch_leave_accrual_date_set(&mut transaction, &user_leave_id_list).await?;
+
+    let mut id_list = vec![];
+    let mut accrual_user_leave_id_list = vec![];
+    let mut accrual_on_list = vec![];
+    let mut amount_list = vec![];
+    let mut credited_id_list = vec![];
+    let mut credited_count_list = vec![];
+    let mut credit_list = vec![];
+    for user_leave in user_leave_list.iter() {
+        let Some(policy) = policy_map.get(&user_leave.leave_type_id) else {
+            continue;
+        };
+        let mut balance = &user_leave.allocated_count - &user_leave.used_count;
+        let mut total_credit = BigDecimal::default();
+        for accrual_on in get_accrual_date_list(
+            user_leave.start_date,
+            user_leave.end_date,
+            user_leave.created_on,
+            &policy.frequency,
+            date,
+        ) {
+            if accrued_set.contains(&(user_leave.id, accrual_on)) {
+                continue;
+            }
+            let credit = policy.get_credit(&balance);
+            balance += &credit;
+            total_credit += &credit;
+            id_list.push(Uuid::new_v4());
+            accrual_user_leave_id_list.push(user_leave.id);
+            accrual_on_list.push(accrual_on);
+            if credit > BigDecimal::default() {
+                credit_list.push((
+                    user_leave.id,
+                    credit.clone(),
+                    format!("Accrual for {}", accrual_on.format("%Y-%m-%d")),
+                ));
+            }
+            amount_list.push(credit);
+        }
+        if total_credit > BigDecimal::default() {
+            credited_id_list.push(user_leave.id);
+            credited_count_list.push(total_credit);
+        }
+    }
+    summary.accrual_count = id_list.len();
+    summary.credited_count = credited_id_list.len();
+    if id_list.is_empty() {
+        return Ok(summary);
+    }
+
+    let current_utc = Utc::now();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_accrual (id, user_leave_id, accrual_on, amount, created_on, created_by)
+        SELECT id, user_leave_id, accrual_on, amount, $5, $6
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::timestamptz[], $4::decimal[])
+        AS t(id, user_leave_id, accrual_on, amount)
+        ON CONFLICT (user_leave_id, accrual_on) DO NOTHING
+        "#,
+        &id_list[..] as &[Uuid],
+        &accrual_user_leave_id_list[..] as &[Uuid],
+        &accrual_on_list[..] as &[DateTime<Utc>],
+        &amount_list[..] as &[BigDecimal],
+        current_utc,
+        created_by
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave accrual")
+    })?;
+    let query = sqlx::query!(
+        r#"
+        UPDATE user_leave_relationship ulr
+        SET allocated_count = ulr.allocated_count + t.credit,
+        accrued_count = ulr.accrued_count + t.credit,
+        updated_on = $3,
+        updated_by = $4
+        FROM UNNEST($1::uuid[], $2::decimal[]) AS t(id, credit)
+        WHERE ulr.id = t.id
+        "#,
+        &credited_id_list[..] as &[Uuid],
+        &credited_count_list[..] as &[BigDecimal],
+        current_utc,
+        created_by
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while crediting accrued leave")
+    })?;
+    let entry_list: Vec<LeaveLedgerEntryData> = credit_list
+        .iter()
+        .map(|(user_leave_id, credit, reason)| LeaveLedgerEntryData {
+            user_leave_id: *user_leave_id,
+            entry_type: LeaveLedgerEntryType::Allocation,
+            allocated_change: credit.clone(),
+            used_change: BigDecimal::default(),
+            leave_id: None,
+            reason: Some(reason.as_str()),
+        })
+        .collect();
+    save_leave_ledger(&mut transaction, &entry_list, created_by).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to accrue user leave.")?;
+    Ok(summary)
+}
+
+/// Scales a full leave group allocation down to the share of the group left after
+/// `start_date`, rounded half up to the nearest multiple of `rounding`.
+pub fn compute_pro_rata_count(
+    count: &BigDecimal,
+    leave_group: &LeaveGroup,
+    start_date: DateTime<Utc>,
+    rounding: &BigDecimal,
+) -> BigDecimal {
+    if start_date <= leave_group.start_date {
+        return count.clone();
+    }
+    if start_date >= leave_group.end_date {
+        return BigDecimal::default();
+    }
+    let total_seconds = (leave_group.end_date - leave_group.start_date).num_seconds();
+    let remaining_seconds = (leave_group.end_date - start_date).num_seconds();
+    let raw_count = count * BigDecimal::from(remaining_seconds) / BigDecimal::from(total_seconds);
+    if rounding <= &BigDecimal::default() {
+        return raw_count.with_scale_round(1, RoundingMode::HalfUp);
+    }
+    let rounded_count = (raw_count / rounding).with_scale_round(0, RoundingMode::HalfUp) * rounding;
+    rounded_count.min(count.clone())
+}
+
+/// Computes the count to be allocated for each leave type, applying pro-rata where requested.
+/// The join date of the user is fetched only when a pro-rata entry has no explicit start date.
+pub async fn get_user_leave_allocation(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+    leave_group: &LeaveGroup,
+    data: &[UserLeaveCreationData],
+    leave_type_list: &[LeaveTypeData],
+) -> Result<Vec<UserLeaveAllocation>, GenericError> {
+    let join_date = if data
+        .iter()
+        .any(|a| a.pro_rata.as_ref().is_some_and(|p| p.start_date.is_none()))
+    {
+        get_user_business_join_date(pool, business_id, user_id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user join date".to_string(),
+                    e,
+                )
+            })?
+    } else {
+        None
+    };
+    let leave_type_map: HashMap<Uuid, &LeaveTypeData> =
+        leave_type_list.iter().map(|a| (a.id, a)).collect();
+    let mut allocation_list = vec![];
+    for item in data.iter() {
+        let Some(pro_rata) = item.pro_rata.as_ref() else {
+            allocation_list.push(UserLeaveAllocation {
+                type_id: item.type_id,
+                requested_count: item.count.clone(),
+                allocated_count: item.count.clone(),
+                start_date: None,
+            });
+            continue;
+        };
+        let rounding = leave_type_map
+            .get(&item.type_id)
+            .and_then(|a| {
+                a.period_list
+                    .iter()
+                    .find(|p| p.id == pro_rata.rounding_period_id)
+            })
+            .map(|a| &a.value)
+            .ok_or_else(|| {
+                GenericError::ValidationError(
+                    "Rounding period is not allowed for the leave type".to_string(),
+                )
+            })?;
+        let start_date = pro_rata.start_date.or(join_date).ok_or_else(|| {
+            GenericError::DataNotFound("User is not associated with the business".to_string())
+        })?;
+        allocation_list.push(UserLeaveAllocation {
+            type_id: item.type_id,
+            requested_count: item.count.clone(),
+            allocated_count: compute_pro_rata_count(&item.count, leave_group, start_date, rounding),
+            start_date: Some(start_date),
+        });
+    }
+    Ok(allocation_list)
+}
+
+/// Splits every `(user, leave type)` pair of a bulk allocation into the ones created and the
+/// ones skipped because the user leave already existed.
+pub fn build_bulk_user_leave_allocation_result(
+    user_id_list: &[Uuid],
+    data: &[UserLeaveCreationData],
+    created_list: &[(Uuid, Uuid)],
+) -> BulkUserLeaveAllocationResult {
+    let created_set: HashSet<&(Uuid, Uuid)> = created_list.iter().collect();
+    let mut result = BulkUserLeaveAllocationResult {
+        created_list: vec![],
+        skipped_list: vec![],
+    };
+    for user_id in user_id_list.iter() {
+        for item in data.iter() {
+            let allocation = BulkUserLeaveAllocationItem {
+                user_id: *user_id,
+                type_id: item.type_id,
+            };
+            if created_set.contains(&(*user_id, item.type_id)) {
+                result.created_list.push(allocation);
+            } else {
+                result.skipped_list.push(allocation);
+            }
+        }
+    }
+    result
+}
+
+/// Fetches the allocation made to each user leave of a leave group, excluding the carried and
+/// accrued days.
+#[tracing::instrument(name = "fetch user leave allocation", skip(pool))]
+pub async fn fetch_user_leave_allocation_models(
+    pool: &PgPool,
+    group_id: Uuid,
+) -> Result<Vec<UserLeaveAllocationModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        UserLeaveAllocationModel,
+        r#"
+        SELECT user_id, leave_type_id, allocated_count - carried_count - accrued_count AS "allocated_count!"
+        FROM user_leave_relationship
+        WHERE leave_group_id = $1
+        "#,
+        group_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching user leave allocation")
+    })?;
+    Ok(rows)
+}
+
+/// Copies the user leave allocations of the source leave group into the target group, applying
+/// the allocation change if any. Users sharing the same allocation are saved together, users no
+/// longer eligible for a leave type are skipped and returned along with the saved count.
+pub async fn clone_user_leave_allocation(
+    pool: &PgPool,
+    business_id: Uuid,
+    source_group_id: Uuid,
+    target_group_id: Uuid,
+    allocation_change: Option<&LeaveAllocationChange>,
+    created_by: Uuid,
+) -> Result<(usize, Vec<BulkUserLeaveAllocationItem>), anyhow::Error> {
+    let allocation_list = fetch_user_leave_allocation_models(pool, source_group_id).await?;
+    let mut user_map: BTreeMap<(Uuid, BigDecimal), Vec<Uuid>> = BTreeMap::new();
+    for allocation in allocation_list.iter() {
+        let count = match allocation_change {
+            Some(change) => change.apply(&allocation.allocated_count),
+            None => allocation.allocated_count.clone(),
+        };
+        user_map
+            .entry((allocation.leave_type_id, count))
+            .or_default()
+            .push(allocation.user_id);
+    }
+    let type_id_list: Vec<Uuid> = user_map.keys().map(|(type_id, _)| *type_id).collect();
+    let user_id_list: Vec<Uuid> = allocation_list.iter().map(|a| a.user_id).collect();
+    let leave_type_list = get_leave_type(pool, business_id, Some(type_id_list), None, None).await?;
+    let membership_map = get_business_user_membership_map(pool, business_id, &user_id_list).await?;
+    let now = Utc::now();
+    let mut saved_count = 0;
+    let mut skipped_list = vec![];
+    for ((type_id, count), user_id_list) in user_map.iter() {
+        let (user_id_list, ineligible_user_id_list) =
+            match leave_type_list.iter().find(|a| a.id == *type_id) {
+                Some(leave_type) => {
+                    partition_eligible_user_id_list(leave_type, user_id_list, &membership_map, now)
+                }
+                None => (vec![], user_id_list.to_owned()),
+            };
+        skipped_list.extend(ineligible_user_id_list.into_iter().map(|user_id| {
+            BulkUserLeaveAllocationItem {
+                user_id,
+                type_id: *type_id,
+            }
+        }));
+        let data = vec![UserLeaveCreationData {
+            type_id: *type_id,
+            count: count.clone(),
+            status: Status::Active,
+            pro_rata: None,
+        }];
+        saved_count += save_user_leave(
+            pool,
+            &data,
+            &user_id_list,
+            target_group_id,
+            created_by,
+            true,
+        )
+        .await?
+        .len();
+    }
+    Ok((saved_count, skipped_list))
+}
+
+/// Fills a cloned leave group with the allocations of the source group and carries forward
+/// the closing balances when asked to.
+pub async fn populate_cloned_leave_group(
+    pool: &PgPool,
+    business_id: Uuid,
+    source_group: &LeaveGroup,
+    target_group: &LeaveGroup,
+    allocation_change: Option<&LeaveAllocationChange>,
+    carry_forward: bool,
+    created_by: Uuid,
+) -> Result<LeaveGroupCloneResult, anyhow::Error> {
+    let (allocation_count, skipped_list) = clone_user_leave_allocation(
+        pool,
+        business_id,
+        source_group.id,
+        target_group.id,
+        allocation_change,
+        created_by,
+    )
+    .await
+    .context("Failed to clone user leave allocation")?;
+    let rollover = if carry_forward {
+        let summary =
+            rollover_leave_group(pool, business_id, source_group, target_group, created_by)
+                .await
+                .context("Failed to carry forward leave balance")?;
+        Some(summary)
+    } else {
+        None
+    };
+    Ok(LeaveGroupCloneResult {
+        group_id: target_group.id,
+        allocation_count,
+        skipped_list,
+        rollover,
+    })
+}
+
+pub fn validate_leave_type_policy_data(data: &[LeaveTypePolicyData]) -> Result<(), GenericError> {
+    if data.iter().any(|a| {
+        [
+            a.min_notice_days,
+            a.max_consecutive_days,
+            a.max_requests_per_month,
+        ]
+        .iter()
+        .any(|d| d.is_some_and(|d| d < 0))
+    }) {
+        return Err(GenericError::ValidationError(
+            "Leave type policy limits cannot be negative".to_string(),
+        ));
+    }
+    if data.iter().any(|a| {
+        a.max_negative_balance
+            .as_ref()
+            .is_some_and(|b| b < &BigDecimal::default())
+    }) {
+        return Err(GenericError::ValidationError(
+            "Maximum negative balance should be given as a positive value".to_string(),
+        ));
+    }
+    if data
+        .iter()
+        .any(|a| !a.allow_negative_balance && a.max_negative_balance.is_some())
+    {
+        return Err(GenericError::ValidationError(
+            "Maximum negative balance requires negative balance to be allowed".to_string(),
+        ));
+    }
+    let type_set: HashSet<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    if type_set.len() != data.len() {
+        return Err(GenericError::ValidationError(
+            "Duplicate leave types found in leave type policies".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave type policy", skip(pool))]
+pub async fn save_leave_type_policy(
+    pool: &PgPool,
+    data: &[LeaveTypePolicyData],
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    if data.is_empty() {
+        return Ok(());
+    }
+    let id_list: Vec<Uuid> = data.iter().map(|_| Uuid::new_v4()).collect();
+    let leave_type_id_list: Vec<Uuid> = data.iter().map(|a| a.leave_type_id).collect();
+    let min_notice_days_list: Vec<Option<i32>> = data.iter().map(|a| a.min_notice_days).collect();
+    let max_consecutive_days_list: Vec<Option<i32>> =
+        data.iter().map(|a| a.max_consecutive_days).collect();
+    let max_requests_per_month_list: Vec<Option<i32>> =
+        data.iter().map(|a| a.max_requests_per_month).collect();
+    let allow_negative_balance_list: Vec<bool> =
+        data.iter().map(|a| a.allow_negative_balance).collect();
+    let max_negative_balance_list: Vec<Option<BigDecimal>> = data
+        .iter()
+        .map(|a| a.max_negative_balance.clone())
+        .collect();
+    let allow_backdated_list: Vec<bool> = data.iter().map(|a| a.allow_backdated).collect();
+    let require_attachment_list: Vec<bool> = data.iter().map(|a| a.require_attachment).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_type_policy (id, leave_type_id, min_notice_days, max_consecutive_days, max_requests_per_month,
+        allow_negative_balance, max_negative_balance, allow_backdated, require_attachment, created_on, created_by)
+        SELECT id, leave_type_id, min_notice_days, max_consecutive_days, max_requests_per_month,
+        allow_negative_balance, max_negative_balance, allow_backdated, require_attachment, $10, $11
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::int[], $5::int[], $6::bool[], $7::decimal[], $8::bool[], $9::bool[])
+        AS t(id, leave_type_id, min_notice_days, max_consecutive_days, max_requests_per_month,
+        allow_negative_balance, max_negative_balance, allow_backdated, require_attachment)
+        ON CONFLICT (leave_type_id) DO UPDATE
+        SET min_notice_days = EXCLUDED.min_notice_days,
+        max_consecutive_days = EXCLUDED.max_consecutive_days,
+        max_requests_per_month = EXCLUDED.max_requests_per_month,
+        allow_negative_balance = EXCLUDED.allow_negative_balance,
+        max_negative_balance = EXCLUDED.max_negative_balance,
+        allow_backdated = EXCLUDED.allow_backdated,
+        require_attachment = EXCLUDED.require_attachment,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        "#,
+        &id_list[..] as &[Uuid],
+        &leave_type_id_list[..] as &[Uuid],
+        &min_notice_days_list[..] as &[Option<i32>],
+        &max_consecutive_days_list[..] as &[Option<i32>],
+        &max_requests_per_month_list[..] as &[Option<i32>],
+        &allow_negative_balance_list[..] as &[bool],
+        &max_negative_balance_list[..] as &[Option<BigDecimal>],
+        &allow_backdated_list[..] as &[bool],
+        &require_attachment_list[..] as &[bool],
+        Utc::now(),
+        created_by
+    );
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave type policy")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave type policy", skip(pool))]
+pub async fn fetch_leave_type_policy_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveTypePolicyModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveTypePolicyModel,
+        r#"
+        SELECT ltp.id, ltp.leave_type_id, ltp.min_notice_days, ltp.max_consecutive_days, ltp.max_requests_per_month,
+        ltp.allow_negative_balance, ltp.max_negative_balance, ltp.allow_backdated, ltp.require_attachment
+        FROM leave_type_policy ltp
+        INNER JOIN leave_type lt ON lt.id = ltp.leave_type_id
+        WHERE lt.business_id = $1 AND ($2::uuid[] IS NULL OR ltp.leave_type_id = ANY($2))
+        "#,
+        business_id,
+        leave_type_id_list.map(|a| &a[..]) as Option<&[Uuid]>
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave type policy")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_type_policies(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id_list: Option<&Vec<Uuid>>,
+) -> Result<Vec<LeaveTypePolicy>, anyhow::Error> {
+    let models = fetch_leave_type_policy_models(pool, business_id, leave_type_id_list).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete leave type policy", skip(pool))]
+pub async fn delete_leave_type_policy(
+    pool: &PgPool,
+    business_id: Uuid,
+    id: Uuid,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM leave_type_policy ltp
+        USING leave_type lt
+        WHERE ltp.leave_type_id = lt.id AND ltp.id = $1 AND lt.business_id = $2
+        "#,
+        id,
+        business_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave type policy")
+    })?;
+    Ok(())
+}
+
+/// Fetches the leave requests of a user leave which are neither rejected nor cancelled.
+#[tracing::instrument(name = "fetch active leave list", skip(pool))]
+pub async fn fetch_active_leave_list(
+    pool: &PgPool,
+    user_leave_id: Uuid,
+) -> Result<Vec<ActiveLeaveData>, anyhow::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT id, date, created_on
+        FROM leave_request
+        WHERE user_leave_id = $1
+            AND is_deleted = false
+            AND status <> ALL($2)
+        "#,
+        user_leave_id,
+        &[
+            LeaveStatus::Rejected,
+            LeaveStatus::Cancelled,
+            LeaveStatus::Expired
+        ] as &[LeaveStatus],
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching active leave requests")
+    })?;
+    Ok(rows
+        .into_iter()
+        .map(|a| ActiveLeaveData {
+            id: a.id,
+            date: a.date.date_naive(),
+            created_on: a.created_on,
+        })
+        .collect())
+}
+
+/// Returns the longest run of leave dates that includes one of `date_list`, treating holidays
+/// and non working days in between as part of the run. Dates of `active_date_list` extend the
+/// runs but do not count on their own.
+pub fn count_max_consecutive_leave_days(
+    date_list: &[NaiveDate],
+    active_date_list: &[NaiveDate],
+    holiday_list: &[HolidayData],
+    location: Option<&BusinessLocation>,
+) -> usize {
+    let new_date_set: HashSet<NaiveDate> = date_list.iter().copied().collect();
+    let date_set: BTreeSet<NaiveDate> = date_list
+        .iter()
+        .chain(active_date_list.iter())
+        .copied()
+        .collect();
+    let holiday_set: HashSet<NaiveDate> = holiday_list.iter().map(|h| h.date).collect();
+    let is_off_day = |date: NaiveDate| {
+        holiday_set.contains(&date)
+            || match location {
+                Some(location) => !location.is_working_day(date),
+                None => matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+            }
+    };
+    let mut max_run = 0;
+    let mut run = 0;
+    let mut has_new_date = false;
+    let mut previous_date: Option<NaiveDate> = None;
+    for date in date_set.iter() {
+        let is_continued = previous_date.is_some_and(|previous| {
+            previous
+                .iter_days()
+                .skip(1)
+                .take_while(|d| d < date)
+                .all(is_off_day)
+        });
+        if is_continued {
+            run += 1;
+        } else {
+            run = 1;
+            has_new_date = false;
+        }
+        has_new_date |= new_date_set.contains(date);
+        if has_new_date {
+            max_run = max_run.max(run);
+        }
+        previous_date = Some(*date);
+    }
+    max_run
+}
+
+/// Enforces the policy of the leave type on a leave request. `active_leave_list` holds the
+/// leaves already requested against the user leave, `holiday_list` has to cover them too.
+/// `request_created_on` is set when an existing request is modified, so that its other dates
+/// are not counted as a separate request.
+#[allow(clippy::too_many_arguments)]
+pub fn validate_leave_type_policy(
+    leave_data: &[CreateLeaveData],
+    attachment_url: Option<&str>,
+    user_leave: &UserLeave,
+    today: NaiveDate,
+    active_leave_list: &[ActiveLeaveData],
+    holiday_list: &[HolidayData],
+    location: Option<&BusinessLocation>,
+    request_created_on: Option<DateTime<Utc>>,
+) -> Result<(), anyhow::Error> {
+    let Some(policy) = user_leave.policy.as_ref() else {
+        return Ok(());
+    };
+    let label = &user_leave.leave_type.label;
+    if policy.require_attachment && attachment_url.is_none_or(|a| a.trim().is_empty()) {
+        return Err(anyhow!("An attachment is required for {}", label));
+    }
+    for item in leave_data.iter() {
+        if item.date < today && !policy.allow_backdated {
+            return Err(anyhow!(
+                "Backdated requests are not allowed for {}, {} has already passed",
+                label,
+                item.date.format("%Y-%m-%d")
+            ));
+        }
+        let notice_days = policy.min_notice_days.unwrap_or_default() as i64;
+        if item.date >= today && (item.date - today).num_days() < notice_days {
+            return Err(anyhow!(
+                "{} has to be requested at least {} day/s in advance",
+                label,
+                notice_days
+            ));
+        }
+    }
+    let date_list: Vec<NaiveDate> = leave_data.iter().map(|a| a.date).collect();
+    if let Some(max_consecutive_days) = policy.max_consecutive_days {
+        let active_date_list: Vec<NaiveDate> = active_leave_list.iter().map(|a| a.date).collect();
+        if count_max_consecutive_leave_days(&date_list, &active_date_list, holiday_list, location)
+            > max_consecutive_days as usize
+        {
+            return Err(anyhow!(
+                "{} cannot be taken for more than {} consecutive day/s",
+                label,
+                max_consecutive_days
+            ));
+        }
+    }
+    if let Some(max_requests_per_month) = policy.max_requests_per_month {
+        // Requests are told apart by their creation time, the requested dates count once in
+        // every month they touch.
+        let mut month_request_map: BTreeMap<(i32, u32), HashSet<Option<DateTime<Utc>>>> =
+            BTreeMap::new();
+        for leave in active_leave_list.iter() {
+            month_request_map
+                .entry((leave.date.year(), leave.date.month()))
+                .or_default()
+                .insert(Some(leave.created_on));
+        }
+        for date in date_list.iter() {
+            month_request_map
+                .entry((date.year(), date.month()))
+                .or_default()
+                .insert(request_created_on);
+        }
+        for date in date_list.iter() {
+            if month_request_map[&(date.year(), date.month())].len()
+                > max_requests_per_month as usize
+            {
+                return Err(anyhow!(
+                    "Only {} {} request/s are allowed in {}",
+                    max_requests_per_month,
+                    label,
+                    date.format("%B %Y")
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_leave_blackout(req: &LeaveBlackoutCreationRequest) -> Result<(), GenericError> {
+    if req.reason.trim().is_empty() {
+        return Err(GenericError::ValidationError(
+            "Please provide a reason for the blackout".to_string(),
+        ));
+    }
+    if req.end_date < req.start_date {
+        return Err(GenericError::ValidationError(
+            "End date cannot be before start date".to_string(),
+        ));
+    }
+    if (req.end_date - req.start_date).num_days() >= MAX_LEAVE_RANGE_DAYS {
+        return Err(GenericError::ValidationError(format!(
+            "Blackout cannot span more than {} days",
+            MAX_LEAVE_RANGE_DAYS
+        )));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave blackout", skip(pool))]
+pub async fn save_leave_blackout(
+    pool: &PgPool,
+    req: &LeaveBlackoutCreationRequest,
+    business_id: Uuid,
+    created_by: Uuid,
+) -> Result<Option<Uuid>, anyhow::Error> {
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO leave_blackout (id, business_id, department_id, reason, start_date, end_date,
+        leave_type_id_list, created_on, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (id) DO UPDATE
+        SET department_id = EXCLUDED.department_id,
+        reason = EXCLUDED.reason,
+        start_date = EXCLUDED.start_date,
+        end_date = EXCLUDED.end_date,
+        leave_type_id_list = EXCLUDED.leave_type_id_list,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        WHERE leave_blackout.business_id = EXCLUDED.business_id
+        RETURNING id
+        "#,
+        req.id.unwrap_or_else(Uuid::new_v4),
+        business_id,
+        req.department_id,
+        req.reason.trim(),
+        req.start_date,
+        req.end_date,
+        &req.leave_type_id_list[..] as &[Uuid],
+        Utc::now(),
+        created_by
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave blackout")
+    })?;
+    Ok(id)
+}
+
+#[tracing::instrument(name = "fetch leave blackout", skip(pool))]
+pub async fn fetch_leave_blackout_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    department_id: Option<Uuid>,
+) -> Result<Vec<LeaveBlackoutModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveBlackoutModel,
+        r#"
+        SELECT id, department_id, reason, start_date, end_date, leave_type_id_list
+        FROM leave_blackout
+        WHERE business_id = $1
+            AND ($2::date IS NULL OR end_date >= $2)
+            AND ($3::date IS NULL OR start_date <= $3)
+            AND ($4::uuid IS NULL OR department_id IS NULL OR department_id = $4)
+        ORDER BY start_date
+        "#,
+        business_id,
+        start_date,
+        end_date,
+        department_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave blackout")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_blackouts(
+    pool: &PgPool,
+    business_id: Uuid,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    department_id: Option<Uuid>,
+) -> Result<Vec<LeaveBlackout>, anyhow::Error> {
+    let models =
+        fetch_leave_blackout_models(pool, business_id, start_date, end_date, department_id).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete leave blackout", skip(pool))]
+pub async fn delete_leave_blackout(
+    pool: &PgPool,
+    business_id: Uuid,
+    id: Uuid,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM leave_blackout
+        WHERE id = $1 AND business_id = $2
+        "#,
+        id,
+        business_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave blackout")
+    })?;
+    Ok(())
+}
+
+/// Returns the first requested date falling within a blackout along with the blackout.
+pub fn find_leave_blackout<'a>(
+    blackout_list: &'a [LeaveBlackout],
+    date_list: &[NaiveDate],
+    leave_type_id: Uuid,
+    department_id_list: &[Uuid],
+) -> Option<(&'a LeaveBlackout, NaiveDate)> {
+    date_list.iter().find_map(|date| {
+        blackout_list
+            .iter()
+            .find(|a| a.covers(*date, leave_type_id, department_id_list))
+            .map(|a| (a, *date))
+    })
+}
+
+/// Runs the checks that depend on the leave type of a user leave against the requested dates:
+/// eligibility, blackouts and the leave type policy. `user_id` is the user taking the leave and
+/// `acting_user_id` the user creating or modifying the request, whose permission decides if a
+/// blackout can be overridden. `modified_leave_id` is set when an existing leave is moved to
+/// one of `leave_data`.
+#[tracing::instrument(
+    name = "validate leave request rules",
+    skip(pool, user_leave, leave_data)
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn validate_leave_request_rules(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+    acting_user_id: Uuid,
+    user_leave: &UserLeave,
+    leave_data: &[CreateLeaveData],
+    attachment_url: Option<&str>,
+    location: Option<&BusinessLocation>,
+    modified_leave_id: Option<Uuid>,
+) -> Result<(), GenericError> {
+    let (leave_type_res, membership_res) = tokio::join!(
+        get_leave_type(
+            pool,
+            business_id,
+            Some(vec![user_leave.leave_type.id]),
+            None,
+            None
+        ),
+        get_business_user_membership(pool, business_id, user_id),
+    );
+    let leave_type_list = leave_type_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching leave type".to_string(),
+            e,
+        )
+    })?;
+    let membership = membership_res.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching user membership".to_string(),
+            e,
+        )
+    })?;
+    validate_leave_type_eligibility(
+        &leave_type_list.iter().collect::<Vec<_>>(),
+        membership.as_ref(),
+        Utc::now(),
+    )?;
+
+    let requested_date_list: Vec<NaiveDate> = leave_data.iter().map(|a| a.date).collect();
+    if let (Some(first_date), Some(last_date)) = (
+        requested_date_list.iter().min(),
+        requested_date_list.iter().max(),
+    ) {
+        let blackout_list =
+            get_leave_blackouts(pool, business_id, Some(*first_date), Some(*last_date), None)
+                .await
+                .map_err(|e| {
+                    GenericError::DatabaseError(
+                        "Something went wrong while fetching leave blackout".to_string(),
+                        e,
+                    )
+                })?;
+        let department_id_list = membership
+            .as_ref()
+            .map_or(&[][..], |a| &a.department_id_list[..]);
+        if let Some((blackout, date)) = find_leave_blackout(
+            &blackout_list,
+            &requested_date_list,
+            user_leave.leave_type.id,
+            department_id_list,
+        ) {
+            let override_permission_list = validate_user_business_permission(
+                pool,
+                acting_user_id,
+                business_id,
+                &vec![PermissionType::OverrideLeaveBlackout.to_string()],
+            )
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching user permission".to_string(),
+                    e,
+                )
+            })?;
+            if override_permission_list.is_empty() {
+                return Err(GenericError::ValidationError(format!(
+                    "{} falls within the leave blackout from {} to {}: {}",
+                    date, blackout.start_date, blackout.end_date, blackout.reason
+                )));
+            }
+        }
+    }
+
+    let Some(policy) = &user_leave.policy else {
+        return Ok(());
+    };
+    let mut active_leave_list =
+        if policy.max_consecutive_days.is_some() || policy.max_requests_per_month.is_some() {
+            fetch_active_leave_list(pool, user_leave.id)
+                .await
+                .map_err(|e| {
+                    GenericError::DatabaseError(
+                        "Something went wrong while fetching leave requests".to_string(),
+                        e,
+                    )
+                })?
+        } else {
+            vec![]
+        };
+    // The modified leave is replaced by the requested date while staying part of its request
+    let request_created_on = modified_leave_id.and_then(|id| {
+        let index = active_leave_list.iter().position(|a| a.id == id)?;
+        Some(active_leave_list.swap_remove(index).created_on)
+    });
+    // Holidays between the requested and the active dates are needed to find runs of
+    // consecutive days.
+    let policy_date_list: Vec<NaiveDate> = requested_date_list
+        .iter()
+        .copied()
+        .chain(active_leave_list.iter().map(|a| a.date))
+        .collect();
+    let policy_holiday_list = match (policy_date_list.iter().min(), policy_date_list.iter().max()) {
+        (Some(first_date), Some(last_date)) if policy.max_consecutive_days.is_some() => {
+            get_holiday(
+                pool,
+                business_id,
+                None,
+                None,
+                Some(*first_date),
+                Some(*last_date),
+            )
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching holiday".to_string(),
+                    e,
+                )
+            })?
+        }
+        _ => vec![],
+    };
+    validate_leave_type_policy(
+        leave_data,
+        attachment_url,
+        user_leave,
+        Utc::now().date_naive(),
+        &active_leave_list,
+        &policy_holiday_list,
+        location,
+        request_created_on,
+    )
+    .map_err(|e| GenericError::ValidationError(e.to_string()))
+}
+
+pub fn validate_leave_auto_approval_rule_data(
+    data: &[LeaveAutoApprovalRuleData],
+) -> Result<(), GenericError> {
+    if data.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Incoming Data is empty".to_string(),
+        ));
+    }
+    for item in data.iter() {
+        if item.label.trim().is_empty() {
+            return Err(GenericError::ValidationError(
+                "Please provide a label for the auto approval rule".to_string(),
+            ));
+        }
+        if item.max_days.is_none() && item.min_notice_days.is_none() {
+            return Err(GenericError::ValidationError(format!(
+                "Please set the maximum days or minimum notice days for rule {}",
+                item.label
+            )));
+        }
+        if item
+            .max_days
+            .as_ref()
+            .is_some_and(|a| a <= &BigDecimal::default())
+            || item.min_notice_days.is_some_and(|a| a < 0)
+        {
+            return Err(GenericError::ValidationError(format!(
+                "Invalid limits for rule {}",
+                item.label
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave auto approval rule", skip(pool))]
+pub async fn save_leave_auto_approval_rule(
+    pool: &PgPool,
+    data: &[LeaveAutoApprovalRuleData],
+    business_id: Uuid,
+    created_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let id_list: Vec<Uuid> = data
+        .iter()
+        .map(|a| a.id.unwrap_or_else(Uuid::new_v4))
+        .collect();
+    let label_list: Vec<&str> = data.iter().map(|a| a.label.trim()).collect();
+    let leave_type_id_list: Vec<Option<Uuid>> = data.iter().map(|a| a.leave_type_id).collect();
+    let max_days_list: Vec<Option<BigDecimal>> = data.iter().map(|a| a.max_days.clone()).collect();
+    let min_notice_days_list: Vec<Option<i32>> = data.iter().map(|a| a.min_notice_days).collect();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO leave_auto_approval_rule (id, label, leave_type_id, max_days, min_notice_days,
+        business_id, created_on, created_by)
+        SELECT id, label, leave_type_id, max_days, min_notice_days, $6, $7, $8
+        FROM UNNEST($1::uuid[], $2::text[], $3::uuid[], $4::decimal[], $5::int[])
+        AS t(id, label, leave_type_id, max_days, min_notice_days)
+        ON CONFLICT (id) DO UPDATE
+        SET label = EXCLUDED.label,
+        leave_type_id = EXCLUDED.leave_type_id,
+        max_days = EXCLUDED.max_days,
+        min_notice_days = EXCLUDED.min_notice_days,
+        updated_by = EXCLUDED.created_by,
+        updated_on = EXCLUDED.created_on
+        WHERE leave_auto_approval_rule.business_id = EXCLUDED.business_id
+        "#,
+        &id_list[..] as &[Uuid],
+        &label_list[..] as &[&str],
+        &leave_type_id_list[..] as &[Option<Uuid>],
+        &max_days_list[..] as &[Option<BigDecimal>],
+        &min_notice_days_list[..] as &[Option<i32>],
+        business_id,
+        Utc::now(),
+        created_by
+    );
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave auto approval rule")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch leave auto approval rule", skip(pool))]
+pub async fn fetch_leave_auto_approval_rule_models(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id: Option<Uuid>,
+) -> Result<Vec<LeaveAutoApprovalRuleModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveAutoApprovalRuleModel,
+        r#"
+        SELECT id, label, leave_type_id, max_days, min_notice_days
+        FROM leave_auto_approval_rule
+        WHERE business_id = $1
+            AND ($2::uuid IS NULL OR leave_type_id IS NULL OR leave_type_id = $2)
+        ORDER BY created_on
+        "#,
+        business_id,
+        leave_type_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave auto approval rule")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_auto_approval_rules(
+    pool: &PgPool,
+    business_id: Uuid,
+    leave_type_id: Option<Uuid>,
+) -> Result<Vec<LeaveAutoApprovalRule>, anyhow::Error> {
+    let models = fetch_leave_auto_approval_rule_models(pool, business_id, leave_type_id).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+#[tracing::instrument(name = "delete leave auto approval rule", skip(pool))]
+pub async fn delete_leave_auto_approval_rule(
+    pool: &PgPool,
+    business_id: Uuid,
+    id: Uuid,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM leave_auto_approval_rule
+        WHERE id = $1 AND business_id = $2
+        "#,
+        id,
+        business_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave auto approval rule")
+    })?;
+    Ok(())
+}
+
+/// Picks the first rule, in the order of creation, approving the leave request.
+pub fn find_leave_auto_approval_rule<'a>(
+    rule_list: &'a [LeaveAutoApprovalRule],
+    leave_data: &[CreateLeaveData],
+    user_leave: &UserLeave,
+    today: NaiveDate,
+) -> Option<&'a LeaveAutoApprovalRule> {
+    let first_date = leave_data.iter().map(|a| a.date).min()?;
+    let leave_count: BigDecimal = leave_data
+        .iter()
+        .filter_map(|a| user_leave.periods.iter().find(|p| p.id == a.period_id))
+        .map(|a| &a.value)
+        .sum();
+    let notice_days = (first_date - today).num_days();
+    rule_list
+        .iter()
+        .find(|a| a.matches(user_leave.leave_type.id, &leave_count, notice_days))
+}
+
+/// Approves freshly created leave requests on behalf of an auto approval rule, consuming the
+/// leave count the same way an approver would. Returns the dates of the approved leaves.
+#[tracing::instrument(name = "auto approve leave request", skip(transaction))]
+pub async fn auto_approve_leave_request(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_id_list: &[Uuid],
+    user_leave_id: Uuid,
+    rule: &LeaveAutoApprovalRule,
+    updated_by: Uuid,
+) -> Result<Vec<DateTime<Utc>>, anyhow::Error> {
+    let rows = sqlx::query!(
+        r#"
+        UPDATE leave_request l_r
+        SET status = $1, updated_on = $2, updated_by = $3
+        FROM leave_period lp
+        WHERE lp.id = l_r.leave_period_id AND l_r.id = ANY($4)
+        RETURNING l_r.id, l_r.date, lp.value
+        "#,
+        &LeaveStatus::Approved as &LeaveStatus,
+        Utc::now(),
+        updated_by,
+        leave_id_list
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while auto approving leave request")
+    })?;
+    let leave_count: BigDecimal = rows.iter().map(|a| &a.value).sum();
+    update_user_leave_count(transaction, user_leave_id, &leave_count, updated_by).await?;
+    let reason = format!("Auto approved by rule {}", rule.label);
+    let entry_list: Vec<LeaveLedgerEntryData> = rows
+        .iter()
+        .map(|a| LeaveLedgerEntryData {
+            user_leave_id,
+            entry_type: LeaveLedgerEntryType::Usage,
+            allocated_change: BigDecimal::default(),
+            used_change: a.value.clone(),
+            leave_id: Some(a.id),
+            reason: Some(&reason),
+        })
+        .collect();
+    save_leave_ledger(transaction, &entry_list, updated_by).await?;
+    Ok(rows.into_iter().map(|a| a.date).collect())
+}
+
+/// Works out when a pending leave request is reminded, escalated and expired. Every leave date
+/// expires once the day is over, reminders falling after the last expiry are dropped.
+pub fn get_leave_reminder_schedule(
+    created_on: DateTime<Utc>,
+    date_list: &[NaiveDate],
+    reminder_hours: Option<i64>,
+    escalation_hours: Option<i64>,
+) -> Vec<(SchedulerMessageType, DateTime<Utc>)> {
+    let expiry_set: BTreeSet<DateTime<Utc>> = date_list
+        .iter()
+        .filter_map(|a| a.succ_opt())
+        .map(|a| Utc.from_utc_datetime(&a.and_hms_opt(0, 0, 0).unwrap()))
+        .filter(|a| *a > created_on)
+        .collect();
+    let Some(last_expiry) = expiry_set.last().copied() else {
+        return vec![];
+    };
+    let mut schedule: Vec<(SchedulerMessageType, DateTime<Utc>)> = [
+        (SchedulerMessageType::LeaveReminder, reminder_hours),
+        (SchedulerMessageType::LeaveEscalation, escalation_hours),
+    ]
+    .into_iter()
+    .filter_map(|(message_type, hours)| {
+        hours
+            .filter(|a| *a > 0)
+            .map(|a| (message_type, created_on + chrono::Duration::hours(a)))
+    })
+    .filter(|(_, date)| *date < last_expiry)
+    .collect();
+    schedule.extend(
+        expiry_set
+            .into_iter()
+            .map(|a| (SchedulerMessageType::LeaveExpiry, a)),
+    );
+    schedule
+}
+
+/// Schedules the reminder, escalation and expiry of a newly created leave request. Reminders
+/// and escalations are only scheduled once their hours are set for the business.
+pub async fn schedule_leave_reminder(
+    pool: &PgPool,
+    producer_client: &PulsarClient,
+    business_id: Uuid,
+    leave_id_list: &[Uuid],
+    date_list: &[NaiveDate],
+) -> Result<(), anyhow::Error> {
+    let setting_key_list = vec![
+        SettingKey::LeaveReminderHours.to_string(),
+        SettingKey::LeaveEscalationHours.to_string(),
+    ];
+    let configs =
+        get_setting_value(pool, &setting_key_list, Some(business_id), None, false).await?;
+    let get_hours = |key: SettingKey| {
+        configs
+            .get_setting(&key.to_string())
+            .and_then(|a| a.trim().parse::<i64>().ok())
+    };
+    let schedule = get_leave_reminder_schedule(
+        Utc::now(),
+        date_list,
+        get_hours(SettingKey::LeaveReminderHours),
+        get_hours(SettingKey::LeaveEscalationHours),
+    );
+    if schedule.is_empty() {
+        return Ok(());
+    }
+    let mut producer = producer_client
+        .get_producer(producer_client.get_product_topic(PulsarTopic::Scheduler))
+        .await;
+    for (message_type, date) in schedule {
+        let msg = SchedulerMessageData {
+            partition_key: None,
+            date,
+            message_type,
+            department_id: None,
+            leave_id_list: leave_id_list.to_vec(),
+        };
+        let msg = producer
+            .create_message()
+            .with_content(msg)
+            .deliver_at(date.into())?;
+        msg.send_non_blocking().await?;
+    }
+    Ok(())
+}
+
+/// Records the reminder against the leave requests still pending, limited to the given leave
+/// date for expiries. Only the leave requests not reminded of the same type before are returned.
+#[tracing::instrument(name = "save leave reminder", skip(transaction))]
+pub async fn save_leave_reminder(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_id_list: &[Uuid],
+    reminder_type: &LeaveReminderType,
+    leave_date: Option<DateTime<Utc>>,
+) -> Result<Vec<LeaveReminderTargetModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveReminderTargetModel,
+        r#"
+        WITH pending AS (
+            SELECT l_r.id, l_r.date, l_r.receiver_id, ulr.user_id, lt.business_id
+            FROM leave_request l_r
+            INNER JOIN user_leave_relationship ulr ON ulr.id = l_r.user_leave_id
+            INNER JOIN leave_type lt ON lt.id = ulr.leave_type_id
+            WHERE l_r.id = ANY($1)
+                AND l_r.is_deleted = false
+                AND l_r.status = ANY($2)
+                AND ($3::TIMESTAMPTZ IS NULL OR (l_r.date >= $3 AND l_r.date < $3 + INTERVAL '1 day'))
+            FOR UPDATE OF l_r
+        ),
+        recorded AS (
+            INSERT INTO leave_request_reminder (id, leave_id, reminder_type, created_on)
+            SELECT uuid_generate_v4(), id, $4, $5 FROM pending
+            ON CONFLICT (leave_id, reminder_type) DO NOTHING
+            RETURNING leave_id
+        )
+        SELECT p.id, p.date, p.receiver_id, p.user_id, p.business_id
+        FROM pending p
+        INNER JOIN recorded r ON r.leave_id = p.id
+        "#,
+        leave_id_list,
+        &[LeaveStatus::Requested, LeaveStatus::InProgress] as &[LeaveStatus],
+        leave_date,
+        reminder_type as &LeaveReminderType,
+        Utc::now()
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave reminder")
+    })?;
+    Ok(rows)
+}
+
+#[tracing::instrument(name = "escalate leave request", skip(transaction))]
+pub async fn escalate_leave_request(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_id_list: &[Uuid],
+    receiver_id: Uuid,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE leave_request
+        SET receiver_id = $1, updated_on = $2, updated_by = $3
+        WHERE id = ANY($4) AND receiver_id <> $1
+        "#,
+        receiver_id,
+        Utc::now(),
+        updated_by,
+        leave_id_list
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while escalating leave request")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "expire leave request", skip(transaction))]
+pub async fn expire_leave_request(
+    transaction: &mut Transaction<'_, Postgres>,
+    leave_id_list: &[Uuid],
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE leave_request
+        SET status = $1, updated_on = $2, updated_by = $3
+        WHERE id = ANY($4)
+        "#,
+        &LeaveStatus::Expired as &LeaveStatus,
+        Utc::now(),
+        updated_by,
+        leave_id_list
+    );
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while expiring leave request")
+    })?;
+    Ok(())
+}
+
+/// Picks the business level fallback approver, provided they are allowed to approve the leave.
+#[tracing::instrument(name = "get leave fallback approver", skip(pool))]
+pub async fn get_leave_fallback_approver(
+    pool: &PgPool,
+    business_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Uuid>, anyhow::Error> {
+    let setting_key_list = vec![SettingKey::LeaveFallbackApprover.to_string()];
+    let fallback_approver_id =
+        get_setting_value(pool, &setting_key_list, Some(business_id), None, false)
+            .await?
+            .get_setting(&SettingKey::LeaveFallbackApprover.to_string())
+            .and_then(|a| Uuid::parse_str(&a).ok());
+    let Some(fallback_approver_id) = fallback_approver_id else {
+        return Ok(None);
+    };
+    let approver_id_list = fetch_user_id_list_with_business_permission(
+        pool,
+        business_id,
+        &vec![fallback_approver_id],
+        &PermissionType::ApproveLeaveRequest.to_string(),
+    )
+    .await?;
+    Ok(pick_leave_approver(
+        user_id,
+        &[Some(fallback_approver_id)],
+        &approver_id_list,
+    ))
+}
+
+/// Acts upon the leave requests still pending once a scheduled reminder falls due: reminding
+/// the receiver, escalating to the fallback approver or expiring the request.
+#[tracing::instrument(
+    name = "send leave reminder",
+    skip(pool, websocket_srv, producer_client)
+)]
+pub async fn send_leave_reminder(
+    pool: &PgPool,
+    websocket_srv: &Addr<Server>,
+    producer_client: &PulsarClient,
+    reminder_type: LeaveReminderType,
+    leave_id_list: &[Uuid],
+    leave_date: Option<DateTime<Utc>>,
+) -> Result<(), anyhow::Error> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    let target_list =
+        save_leave_reminder(&mut transaction, leave_id_list, &reminder_type, leave_date).await?;
+    let Some(target) = target_list.first() else {
+        return Ok(());
+    };
+    let id_list: Vec<Uuid> = target_list.iter().map(|a| a.id).collect();
+    let display_name = get_minimal_user_list(pool, None, 1, 0, Some(&vec![target.user_id]))
+        .await?
+        .first()
+        .map(|a| a.display_name.to_owned())
+        .unwrap_or_default();
+    let (user_id_list, action_type, message) = match reminder_type {
+        LeaveReminderType::Reminder => {
+            let receiver_id_set: BTreeSet<Uuid> =
+                target_list.iter().map(|a| a.receiver_id).collect();
+            (
+                receiver_id_set.into_iter().collect(),
+                WebSocketActionType::LeaveRequest,
+                format!(
+                    "Reminder: Leave Request send by {} is awaiting your action",
+                    display_name
+                ),
+            )
+        }
+        LeaveReminderType::Escalation => {
+            match get_leave_fallback_approver(pool, target.business_id, target.user_id).await? {
+                Some(approver_id) => {
+                    escalate_leave_request(&mut transaction, &id_list, approver_id, Uuid::nil())
+                        .await?;
+                    (
+                        vec![approver_id],
+                        WebSocketActionType::LeaveRequest,
+                        format!(
+                            "Leave Request send by {} is escalated to you after no action",
+                            display_name
+                        ),
+                    )
+                }
+                None => {
+                    // Dropping the transaction leaves the escalation unrecorded
+                    tracing::warn!("No leave fallback approver to escalate the leave request");
+                    return Ok(());
+                }
+            }
+        }
+        LeaveReminderType::Expiry => {
+            expire_leave_request(&mut transaction, &id_list, Uuid::nil()).await?;
+            (
+                vec![target.user_id],
+                WebSocketActionType::LeaveRequestStatusUpdation,
+                format!(
+                    "Leave Request for {} expired without action",
+                    target.date.date_naive()
+                ),
+            )
+        }
+    };
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to save leave reminder.")?;
+    if !user_id_list.is_empty() {
+        let _ = send_notification(
+            pool,
+            websocket_srv,
+            action_type,
+            ProcessType::Deferred,
+            user_id_list,
+            message,
+            Some(target.business_id),
+            producer_client,
+        )
+        .await;
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save leave comment", skip(pool, comment))]
+pub async fn save_leave_comment(
+    pool: &PgPool,
+    leave_id: Uuid,
+    comment: &str,
+    created_by: Uuid,
+    created_on: DateTime<Utc>,
+) -> Result<Uuid, anyhow::Error> {
+    let id = Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO leave_request_comment (id, leave_id, comment, created_on, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        id,
+        leave_id,
+        comment,
+        created_on,
+        created_by
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving leave comment")
+    })?;
+    Ok(id)
+}
+
+#[tracing::instrument(name = "fetch leave comment models", skip(pool))]
+pub async fn fetch_leave_comment_models(
+    pool: &PgPool,
+    leave_id: Uuid,
+) -> Result<Vec<LeaveCommentModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        LeaveCommentModel,
+        r#"
+        SELECT lrc.id, lrc.leave_id, lrc.comment, lrc.created_by, ua.display_name, lrc.created_on
+        FROM leave_request_comment AS lrc
+        INNER JOIN user_account AS ua ON ua.id = lrc.created_by
+        WHERE lrc.leave_id = $1 AND lrc.is_deleted = false
+        ORDER BY lrc.created_on
+        "#,
+        leave_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching leave comment")
+    })?;
+    Ok(rows)
+}
+
+pub async fn get_leave_comments(
+    pool: &PgPool,
+    leave_id: Uuid,
+) -> Result<Vec<LeaveComment>, anyhow::Error> {
+    let models = fetch_leave_comment_models(pool, leave_id).await?;
+    Ok(models.into_iter().map(|a| a.into_schema()).collect())
+}
+
+/// Soft deletes a comment, only its author is allowed to. Returns false when no such comment
+/// of the user is found.
+#[tracing::instrument(name = "delete leave comment", skip(pool))]
+pub async fn delete_leave_comment(
+    pool: &PgPool,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> Result<bool, anyhow::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE leave_request_comment
+        SET is_deleted = true, deleted_on = $1, deleted_by = $2
+        WHERE id = $3 AND created_by = $2 AND is_deleted = false
+        "#,
+        Utc::now(),
+        deleted_by,
+        id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while deleting leave comment")
+    })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Returns the requester, the receiver and the cc'd users having an account.
+#[tracing::instrument(name = "get leave participant id list", skip(pool, leave))]
+pub async fn get_leave_participant_id_list(
+    pool: &PgPool,
+    leave: &LeaveRequestData,
+) -> Result<Vec<Uuid>, anyhow::Error> {
+    let email_list: Vec<String> = leave
+        .cc
+        .iter()
+        .flatten()
+        .map(|a| a.get().to_string())
+        .collect();
+    let mut user_id_list = vec![leave.user_id, leave.receiver_id];
+    if !email_list.is_empty() {
+        let cc_user_id_list = sqlx::query_scalar!(
+            r#"
+            SELECT id FROM user_account
+            WHERE email = ANY($1) AND is_deleted = false
+            "#,
+            &email_list
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to execute query: {:?}", e);
+            anyhow!(e).context("A database failure occurred while fetching cc'd users")
+        })?;
+        user_id_list.extend(cc_user_id_list);
+    }
+    user_id_list.sort();
+    user_id_list.dedup();
+    Ok(user_id_list)
+}
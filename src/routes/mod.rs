@@ -1,6 +1,7 @@
 pub mod business;
 pub mod department;
 pub mod leave;
+pub mod on_call;
 pub mod permission;
 pub mod role;
 pub mod setting;
@@ -0,0 +1,676 @@
+use crate::{
+    errors::GenericError,
+    pulsar_client::PulsarClient,
+    routes::{
+        business::schemas::BusinessAccount,
+        department::schemas::DepartmentAccount,
+        leave::utils::get_approved_leave_dates,
+        user::schemas::UserAccount,
+        web_socket::{schemas::ProcessType, utils::send_notification},
+    },
+    schemas::{AllowedPermission, GenericResponse, PermissionType},
+    websocket_client::{Server, WebSocketActionType},
+};
+use actix::Addr;
+use actix_web::web;
+use anyhow::Context;
+use chrono::{TimeZone, Utc};
+use sqlx::PgPool;
+use utoipa::TupleUnit;
+use uuid::Uuid;
+
+use super::{
+    schemas::{
+        CreateOnCallRequest, CreateOnCallSwapRequest, CurrentOnCallData, FetchOnCallQuery,
+        FetchOnCallRequest, FetchOnCallSwapQuery, FetchOnCallSwapRequest,
+        GenerateOnCallRotationRequest, OnCallData, OnCallSwapData, OnCallSwapStatus,
+        UpdateOnCallRequest, UpdateOnCallSwapStatusRequest,
+    },
+    utils::{
+        delete_on_call, generate_on_call_rotation, get_current_on_call, get_on_call,
+        get_on_call_rotation_end_date, get_on_call_swap, on_call_create_validation, save_on_call,
+        save_on_call_swap, schedule_on_call_handoff_alert, swap_on_call_user, update_on_call,
+        update_on_call_swap_status, validate_on_call_rotation_request,
+        validate_on_call_swap_approval, validate_on_call_swap_creation,
+        validate_on_call_swap_status_update,
+    },
+};
+
+#[utoipa::path(
+    post,
+    description = "API for creating on-call shifts for a department",
+    tag = "On Call",
+    summary = "On Call Creation API",
+    path = "/on-call/create",
+    request_body(content = CreateOnCallRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully created on-call shifts", body= GenericResponse<Vec<OnCallData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "On Call creation request",
+    skip(pool, producer_client),
+    fields()
+)]
+pub async fn on_call_create_req(
+    body: CreateOnCallRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<Vec<OnCallData>>>, GenericError> {
+    let shifts: Vec<OnCallData> = body
+        .data
+        .into_iter()
+        .map(|a| OnCallData {
+            id: Uuid::new_v4(),
+            user_id: a.user_id,
+            department_id: department_account.id,
+            start_time: a.start_time,
+            end_time: a.end_time,
+        })
+        .collect();
+    on_call_create_validation(&pool, &shifts, business_account.id, department_account.id).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    save_on_call(&mut transaction, &shifts, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving on-call shifts".to_string(),
+                e,
+            )
+        })?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to store on-call shifts")?;
+    schedule_on_call_handoff_alert(&producer_client, &shifts)
+        .await
+        .map_err(GenericError::UnexpectedError)?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully created on-call shifts",
+        shifts,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing on-call shifts of a department",
+    tag = "On Call",
+    summary = "On Call List API",
+    path = "/on-call/list",
+    request_body(content = FetchOnCallRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched on-call shifts", body= GenericResponse<Vec<OnCallData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(err, name = "On Call listing request", skip(pool), fields())]
+pub async fn on_call_list_req(
+    body: FetchOnCallRequest,
+    pool: web::Data<PgPool>,
+    department_account: DepartmentAccount,
+) -> Result<web::Json<GenericResponse<Vec<OnCallData>>>, GenericError> {
+    let user_id_list = body.user_id.map(|a| vec![a]);
+    let filter_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_user_id_list(user_id_list.as_deref())
+        .with_start_time(body.start_time)
+        .with_end_time(body.end_time)
+        .with_limit(Some(body.limit))
+        .with_offset(Some(body.offset));
+    let data = get_on_call(&pool, &filter_query).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching on-call shifts".to_string(),
+            e,
+        )
+    })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched on-call shifts",
+        data,
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for updating an on-call shift",
+    tag = "On Call",
+    summary = "On Call Updation API",
+    path = "/on-call/update",
+    request_body(content = UpdateOnCallRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated on-call shift", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "On Call updation request",
+    skip(pool, producer_client),
+    fields()
+)]
+pub async fn on_call_update_req(
+    body: UpdateOnCallRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let id_list = vec![body.id];
+    let filter_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_id_list(Some(&id_list));
+    get_on_call(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching on-call shift".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid On Call id".to_string()))?;
+
+    let shift = OnCallData {
+        id: body.id,
+        user_id: body.user_id,
+        department_id: department_account.id,
+        start_time: body.start_time,
+        end_time: body.end_time,
+    };
+    let shifts = vec![shift];
+    on_call_create_validation(&pool, &shifts, business_account.id, department_account.id).await?;
+    update_on_call(&pool, &body, user.id).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while updating on-call shift".to_string(),
+            e,
+        )
+    })?;
+    schedule_on_call_handoff_alert(&producer_client, &shifts)
+        .await
+        .map_err(GenericError::UnexpectedError)?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated on-call shift",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    description = "API for deleting an on-call shift",
+    tag = "On Call",
+    summary = "On Call Deletion API",
+    path = "/on-call/delete/{id}",
+    responses(
+        (status=200, description= "sucessfully deleted on-call shift", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+        ("id" = String, Path, description = "On Call ID"),
+      )
+)]
+#[tracing::instrument(err, name = "On Call deletion request", skip(pool), fields())]
+pub async fn on_call_delete_req(
+    path: web::Path<Uuid>,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    department_account: DepartmentAccount,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let on_call_id = path.into_inner();
+    let id_list = vec![on_call_id];
+    let filter_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_id_list(Some(&id_list));
+    get_on_call(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching on-call shift".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid On Call id".to_string()))?;
+
+    delete_on_call(&pool, on_call_id, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while deleting on-call shift".to_string(),
+                e,
+            )
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully deleted on-call shift",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for generating on-call rotation for a department",
+    tag = "On Call",
+    summary = "On Call Rotation Generation API",
+    path = "/on-call/rotation/generate",
+    request_body(content = GenerateOnCallRotationRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully generated on-call rotation", body= GenericResponse<Vec<OnCallData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "On Call rotation generation request",
+    skip(pool, producer_client),
+    fields()
+)]
+pub async fn on_call_rotation_generate_req(
+    body: GenerateOnCallRotationRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<Vec<OnCallData>>>, GenericError> {
+    validate_on_call_rotation_request(&body)?;
+    let start_date =
+        Utc.from_utc_datetime(&body.start_date.date_naive().and_hms_opt(0, 0, 0).unwrap());
+    let end_date = get_on_call_rotation_end_date(&body)?;
+    let leave_map = get_approved_leave_dates(&pool, &body.user_id_list, start_date, end_date)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching approved leaves".to_string(),
+                e,
+            )
+        })?;
+    let shifts = generate_on_call_rotation(&body, department_account.id, &leave_map)?;
+    on_call_create_validation(&pool, &shifts, business_account.id, department_account.id).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    save_on_call(&mut transaction, &shifts, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while saving on-call rotation".to_string(),
+                e,
+            )
+        })?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to store on-call rotation")?;
+    schedule_on_call_handoff_alert(&producer_client, &shifts)
+        .await
+        .map_err(GenericError::UnexpectedError)?;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully generated on-call rotation",
+        shifts,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    description = "API for fetching the current and next on-call shift of a department",
+    tag = "On Call",
+    summary = "Current On Call Fetch API",
+    path = "/on-call/current",
+    responses(
+        (status=200, description= "sucessfully fetched current on-call shift", body= GenericResponse<CurrentOnCallData>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(err, name = "Current On Call fetch request", skip(pool), fields())]
+pub async fn on_call_current_req(
+    pool: web::Data<PgPool>,
+    department_account: DepartmentAccount,
+) -> Result<web::Json<GenericResponse<CurrentOnCallData>>, GenericError> {
+    let data = get_current_on_call(&pool, department_account.id, Utc::now())
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching current on-call shift".to_string(),
+                e,
+            )
+        })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched current on-call shift",
+        data,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for requesting an on-call shift swap with another user",
+    tag = "On Call",
+    summary = "On Call Swap Request Creation API",
+    path = "/on-call/swap/create",
+    request_body(content = CreateOnCallSwapRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully created on-call swap request", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "On Call swap creation request",
+    skip(pool, websocket_srv, producer_client),
+    fields()
+)]
+pub async fn on_call_swap_create_req(
+    body: CreateOnCallSwapRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let id_list = vec![body.on_call_id, body.target_on_call_id];
+    let filter_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_id_list(Some(&id_list));
+    let shifts = get_on_call(&pool, &filter_query).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching on-call shift".to_string(),
+            e,
+        )
+    })?;
+    let shift = shifts
+        .iter()
+        .find(|a| a.id == body.on_call_id)
+        .ok_or_else(|| GenericError::DataNotFound("Invalid On Call id".to_string()))?;
+    let target_shift = shifts
+        .iter()
+        .find(|a| a.id == body.target_on_call_id)
+        .ok_or_else(|| GenericError::DataNotFound("Invalid target On Call id".to_string()))?;
+    validate_on_call_swap_creation(shift, target_shift, user.id)?;
+
+    save_on_call_swap(
+        &pool,
+        &body,
+        department_account.id,
+        target_shift.user_id,
+        user.id,
+    )
+    .await
+    .map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while saving on-call swap request".to_string(),
+            e,
+        )
+    })?;
+
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::OnCallSwapRequest,
+        ProcessType::Deferred,
+        vec![target_shift.user_id],
+        format!("On-call swap requested by {}", user.display_name),
+        Some(business_account.id),
+        &producer_client,
+    )
+    .await;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully created on-call swap request",
+        (),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    description = "API for listing on-call swap requests of a department",
+    tag = "On Call",
+    summary = "On Call Swap Request List API",
+    path = "/on-call/swap/list",
+    request_body(content = FetchOnCallSwapRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully fetched on-call swap requests", body= GenericResponse<Vec<OnCallSwapData>>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(err, name = "On Call swap listing request", skip(pool), fields())]
+pub async fn on_call_swap_list_req(
+    body: FetchOnCallSwapRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    department_account: DepartmentAccount,
+    permissions: AllowedPermission,
+) -> Result<web::Json<GenericResponse<Vec<OnCallSwapData>>>, GenericError> {
+    let user_id = if permissions
+        .permission_list
+        .contains(&PermissionType::ApproveOnCallSwap.to_string())
+    {
+        None
+    } else {
+        Some(user.id)
+    };
+    let filter_query = FetchOnCallSwapQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_user_id(user_id)
+        .with_status(body.status.as_ref())
+        .with_limit(Some(body.limit))
+        .with_offset(Some(body.offset));
+    let data = get_on_call_swap(&pool, &filter_query).await.map_err(|e| {
+        GenericError::DatabaseError(
+            "Something went wrong while fetching on-call swap requests".to_string(),
+            e,
+        )
+    })?;
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully fetched on-call swap requests",
+        data,
+    )))
+}
+
+#[utoipa::path(
+    patch,
+    description = "API for accepting, approving, rejecting or cancelling an on-call swap request",
+    tag = "On Call",
+    summary = "On Call Swap Request Status Updation API",
+    path = "/on-call/swap/status/update",
+    request_body(content = UpdateOnCallSwapStatusRequest, description = "Request Body"),
+    responses(
+        (status=200, description= "sucessfully updated on-call swap request status", body= GenericResponse<TupleUnit>),
+        (status=400, description= "Invalid Request body", body= GenericResponse<TupleUnit>),
+        (status=401, description= "Invalid Token", body= GenericResponse<TupleUnit>),
+	    (status=403, description= "Insufficient Previlege", body= GenericResponse<TupleUnit>),
+	    (status=410, description= "Data not found", body= GenericResponse<TupleUnit>),
+        (status=500, description= "Internal Server Error", body= GenericResponse<TupleUnit>)
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT token"),
+        ("x-request-id" = String, Header, description = "Request id"),
+        ("x-device-id" = String, Header, description = "Device id"),
+        ("x-business-id" = String, Header, description = "id of business_account"),
+        ("x-department-id" = String, Header, description = "id of department account"),
+      )
+)]
+#[tracing::instrument(
+    err,
+    name = "On Call swap status updation request",
+    skip(pool, websocket_srv, producer_client),
+    fields()
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn on_call_swap_status_update_req(
+    body: UpdateOnCallSwapStatusRequest,
+    pool: web::Data<PgPool>,
+    user: UserAccount,
+    business_account: BusinessAccount,
+    department_account: DepartmentAccount,
+    permissions: AllowedPermission,
+    websocket_srv: web::Data<Addr<Server>>,
+    producer_client: web::Data<PulsarClient>,
+) -> Result<web::Json<GenericResponse<()>>, GenericError> {
+    let filter_query = FetchOnCallSwapQuery::builder()
+        .with_department_id(Some(department_account.id))
+        .with_id(Some(body.id));
+    let swap = get_on_call_swap(&pool, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching on-call swap request".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GenericError::DataNotFound("Invalid On Call swap id".to_string()))?;
+    validate_on_call_swap_status_update(&body.status, &swap, user.id, &permissions)?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+    if body.status == OnCallSwapStatus::Approved {
+        validate_on_call_swap_approval(&mut transaction, &swap).await?;
+    }
+    update_on_call_swap_status(&mut transaction, body.id, &body.status, user.id)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while updating on-call swap request".to_string(),
+                e,
+            )
+        })?;
+    if body.status == OnCallSwapStatus::Approved {
+        swap_on_call_user(&mut transaction, &swap, user.id)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while swapping on-call shifts".to_string(),
+                    e,
+                )
+            })?;
+    }
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update on-call swap request")?;
+
+    let receiver_id_list: Vec<_> = vec![swap.requested_by, swap.target_user_id]
+        .into_iter()
+        .filter(|id| *id != user.id)
+        .collect();
+    let _ = send_notification(
+        &pool,
+        &websocket_srv,
+        WebSocketActionType::OnCallSwapStatusUpdation,
+        ProcessType::Deferred,
+        receiver_id_list,
+        format!(
+            "On-call swap request {:?} by {}",
+            body.status, user.display_name
+        ),
+        Some(business_account.id),
+        &producer_client,
+    )
+    .await;
+
+    Ok(web::Json(GenericResponse::success(
+        "sucessfully updated on-call swap request status",
+        (),
+    )))
+}
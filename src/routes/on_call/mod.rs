@@ -0,0 +1,6 @@
+pub mod handlers;
+mod models;
+pub mod routes;
+pub mod schemas;
+mod tests;
+pub mod utils;
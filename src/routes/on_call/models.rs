@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::schemas::{OnCallData, OnCallSwapData, OnCallSwapStatus};
+
+#[derive(Debug, FromRow)]
+pub struct OnCallModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub department_id: Uuid,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+impl OnCallModel {
+    pub fn into_schema(self) -> OnCallData {
+        OnCallData {
+            id: self.id,
+            user_id: self.user_id,
+            department_id: self.department_id,
+            start_time: self.start_time,
+            end_time: self.end_time,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct OnCallAlertModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub department_name: String,
+    pub business_id: Option<Uuid>,
+    pub is_incoming: bool,
+}
+
+#[derive(Debug, FromRow)]
+pub struct OnCallSwapModel {
+    pub id: Uuid,
+    pub on_call_id: Uuid,
+    pub target_on_call_id: Uuid,
+    pub department_id: Uuid,
+    pub requested_by: Uuid,
+    pub target_user_id: Uuid,
+    pub status: OnCallSwapStatus,
+    pub reason: Option<String>,
+    pub accepted_on: Option<DateTime<Utc>>,
+    pub approved_by: Option<Uuid>,
+    pub approved_on: Option<DateTime<Utc>>,
+    pub rejected_by: Option<Uuid>,
+    pub rejected_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl OnCallSwapModel {
+    pub fn into_schema(self) -> OnCallSwapData {
+        OnCallSwapData {
+            id: self.id,
+            on_call_id: self.on_call_id,
+            target_on_call_id: self.target_on_call_id,
+            department_id: self.department_id,
+            requested_by: self.requested_by,
+            target_user_id: self.target_user_id,
+            status: self.status,
+            reason: self.reason,
+            accepted_on: self.accepted_on,
+            approved_by: self.approved_by,
+            approved_on: self.approved_on,
+            rejected_by: self.rejected_by,
+            rejected_on: self.rejected_on,
+            created_on: self.created_on,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use actix_web::web;
+
+use crate::{
+    middlewares::{DepartmentAccountValidation, DepartmentPermissionValidation},
+    schemas::PermissionType,
+};
+
+use super::handlers::{
+    on_call_create_req, on_call_current_req, on_call_delete_req, on_call_list_req,
+    on_call_rotation_generate_req, on_call_swap_create_req, on_call_swap_list_req,
+    on_call_swap_status_update_req, on_call_update_req,
+};
+
+pub fn on_call_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/create",
+        web::post()
+            .to(on_call_create_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::CreateOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/list",
+        web::post()
+            .to(on_call_list_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::ListOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/current",
+        web::get()
+            .to(on_call_current_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::ListOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/update",
+        web::patch()
+            .to(on_call_update_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::UpdateOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/rotation/generate",
+        web::post()
+            .to(on_call_rotation_generate_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::CreateOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/delete/{id}",
+        web::delete()
+            .to(on_call_delete_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::DeleteOnCall.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/swap/create",
+        web::post()
+            .to(on_call_swap_create_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![PermissionType::RequestOnCallSwap.to_string()],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/swap/list",
+        web::post()
+            .to(on_call_swap_list_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![
+                    PermissionType::RequestOnCallSwap.to_string(),
+                    PermissionType::ApproveOnCallSwap.to_string(),
+                ],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+    cfg.route(
+        "/swap/status/update",
+        web::patch()
+            .to(on_call_swap_status_update_req)
+            .wrap(DepartmentPermissionValidation {
+                permission_list: vec![
+                    PermissionType::RequestOnCallSwap.to_string(),
+                    PermissionType::ApproveOnCallSwap.to_string(),
+                ],
+            })
+            .wrap(DepartmentAccountValidation),
+    );
+}
@@ -0,0 +1,373 @@
+use crate::errors::GenericError;
+use actix_http::Payload;
+use actix_web::{FromRequest, HttpRequest, web};
+use chrono::{DateTime, Duration, Utc};
+use futures::future::LocalBoxFuture;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOnCallData {
+    pub user_id: Uuid,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOnCallRequest {
+    pub data: Vec<CreateOnCallData>,
+}
+
+impl FromRequest for CreateOnCallRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOnCallRequest {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+impl FromRequest for UpdateOnCallRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchOnCallRequest {
+    pub user_id: Option<Uuid>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub limit: i32,
+    pub offset: i32,
+}
+
+impl FromRequest for FetchOnCallRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum OnCallRotationType {
+    Daily,
+    Weekly,
+}
+
+impl OnCallRotationType {
+    pub fn duration(&self) -> Duration {
+        match self {
+            OnCallRotationType::Daily => Duration::days(1),
+            OnCallRotationType::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateOnCallRotationRequest {
+    pub rotation_type: OnCallRotationType,
+    pub start_date: DateTime<Utc>,
+    pub user_id_list: Vec<Uuid>,
+    pub period_count: i32,
+}
+
+impl FromRequest for GenerateOnCallRotationRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct FetchOnCallQuery<'a> {
+    pub department_id: Option<Uuid>,
+    pub id_list: Option<&'a [Uuid]>,
+    pub user_id_list: Option<&'a [Uuid]>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub active_at: Option<DateTime<Utc>>,
+    pub start_after: Option<DateTime<Utc>>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+impl<'a> FetchOnCallQuery<'a> {
+    pub fn builder() -> Self {
+        Self {
+            department_id: None,
+            id_list: None,
+            user_id_list: None,
+            start_time: None,
+            end_time: None,
+            active_at: None,
+            start_after: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    pub fn with_department_id(mut self, department_id: Option<Uuid>) -> Self {
+        self.department_id = department_id;
+        self
+    }
+    pub fn with_id_list(mut self, id_list: Option<&'a [Uuid]>) -> Self {
+        self.id_list = id_list;
+        self
+    }
+    pub fn with_user_id_list(mut self, user_id_list: Option<&'a [Uuid]>) -> Self {
+        self.user_id_list = user_id_list;
+        self
+    }
+    pub fn with_start_time(mut self, start_time: Option<DateTime<Utc>>) -> Self {
+        self.start_time = start_time;
+        self
+    }
+    pub fn with_end_time(mut self, end_time: Option<DateTime<Utc>>) -> Self {
+        self.end_time = end_time;
+        self
+    }
+    pub fn with_active_at(mut self, active_at: Option<DateTime<Utc>>) -> Self {
+        self.active_at = active_at;
+        self
+    }
+    pub fn with_start_after(mut self, start_after: Option<DateTime<Utc>>) -> Self {
+        self.start_after = start_after;
+        self
+    }
+    pub fn with_limit(mut self, limit: Option<i32>) -> Self {
+        self.limit = limit;
+        self
+    }
+    pub fn with_offset(mut self, offset: Option<i32>) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OnCallData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub department_id: Uuid,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentOnCallData {
+    pub current: Vec<OnCallData>,
+    pub next: Option<OnCallData>,
+}
+
+#[derive(Debug)]
+pub struct BulkOnCallInsert {
+    pub id: Vec<Uuid>,
+    pub user_id: Vec<Uuid>,
+    pub department_id: Vec<Uuid>,
+    pub start_time: Vec<DateTime<Utc>>,
+    pub end_time: Vec<DateTime<Utc>>,
+    pub created_on: Vec<DateTime<Utc>>,
+    pub created_by: Vec<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, ToSchema, PartialEq, Clone)]
+#[sqlx(type_name = "on_call_swap_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OnCallSwapStatus {
+    Requested,
+    Accepted,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOnCallSwapRequest {
+    pub on_call_id: Uuid,
+    pub target_on_call_id: Uuid,
+    pub reason: Option<String>,
+}
+
+impl FromRequest for CreateOnCallSwapRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOnCallSwapStatusRequest {
+    pub id: Uuid,
+    pub status: OnCallSwapStatus,
+}
+
+impl FromRequest for UpdateOnCallSwapStatusRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchOnCallSwapRequest {
+    pub status: Option<OnCallSwapStatus>,
+    pub limit: i32,
+    pub offset: i32,
+}
+
+impl FromRequest for FetchOnCallSwapRequest {
+    type Error = GenericError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let fut = web::Json::<Self>::from_request(req, payload);
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(json) => Ok(json.into_inner()),
+                Err(e) => Err(GenericError::ValidationError(e.to_string())),
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct FetchOnCallSwapQuery<'a> {
+    pub department_id: Option<Uuid>,
+    pub id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub status: Option<&'a OnCallSwapStatus>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+impl<'a> FetchOnCallSwapQuery<'a> {
+    pub fn builder() -> Self {
+        Self {
+            department_id: None,
+            id: None,
+            user_id: None,
+            status: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    pub fn with_department_id(mut self, department_id: Option<Uuid>) -> Self {
+        self.department_id = department_id;
+        self
+    }
+    pub fn with_id(mut self, id: Option<Uuid>) -> Self {
+        self.id = id;
+        self
+    }
+    pub fn with_user_id(mut self, user_id: Option<Uuid>) -> Self {
+        self.user_id = user_id;
+        self
+    }
+    pub fn with_status(mut self, status: Option<&'a OnCallSwapStatus>) -> Self {
+        self.status = status;
+        self
+    }
+    pub fn with_limit(mut self, limit: Option<i32>) -> Self {
+        self.limit = limit;
+        self
+    }
+    pub fn with_offset(mut self, offset: Option<i32>) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OnCallSwapData {
+    pub id: Uuid,
+    pub on_call_id: Uuid,
+    pub target_on_call_id: Uuid,
+    pub department_id: Uuid,
+    pub requested_by: Uuid,
+    pub target_user_id: Uuid,
+    pub status: OnCallSwapStatus,
+    pub reason: Option<String>,
+    pub accepted_on: Option<DateTime<Utc>>,
+    pub approved_by: Option<Uuid>,
+    pub approved_on: Option<DateTime<Utc>>,
+    pub rejected_by: Option<Uuid>,
+    pub rejected_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
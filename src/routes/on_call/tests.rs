@@ -0,0 +1,371 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::DUMMY_INTERNATIONAL_DIALING_CODE,
+        routes::{
+            business::tests::tests::setup_business,
+            department::{tests::tests::setup_department, utils::hard_delete_department_account},
+            on_call::{
+                schemas::{
+                    FetchOnCallQuery, GenerateOnCallRotationRequest, OnCallData,
+                    OnCallRotationType, OnCallSwapData, OnCallSwapStatus, UpdateOnCallRequest,
+                },
+                utils::{
+                    MAX_ROTATION_PERIOD_COUNT, delete_on_call, generate_on_call_rotation,
+                    get_current_on_call, get_on_call, on_call_create_validation, save_on_call,
+                    update_on_call, validate_on_call_rotation_request, validate_on_call_shifts,
+                    validate_on_call_swap_creation, validate_on_call_swap_status_update,
+                },
+            },
+            user::{
+                tests::tests::setup_user,
+                utils::{hard_delete_business_account, hard_delete_user_account},
+            },
+        },
+        schemas::{AllowedPermission, PermissionType},
+        tests::tests::get_test_pool,
+    };
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+    use std::collections::HashMap;
+    use tokio::join;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_on_call_shift_validation() {
+        let user_id = Uuid::new_v4();
+        let department_id = Uuid::new_v4();
+        let start_time = Utc::now();
+        let shift = OnCallData {
+            id: Uuid::new_v4(),
+            user_id,
+            department_id,
+            start_time,
+            end_time: start_time + Duration::hours(8),
+        };
+        let next_shift = OnCallData {
+            id: Uuid::new_v4(),
+            user_id,
+            department_id,
+            start_time: start_time + Duration::hours(8),
+            end_time: start_time + Duration::hours(16),
+        };
+        assert!(validate_on_call_shifts(&[shift.clone(), next_shift.clone()], &[]).is_ok());
+
+        let overlapping_shift = OnCallData {
+            id: Uuid::new_v4(),
+            user_id,
+            department_id,
+            start_time: start_time + Duration::hours(4),
+            end_time: start_time + Duration::hours(12),
+        };
+        assert!(validate_on_call_shifts(&[shift.clone(), overlapping_shift.clone()], &[]).is_err());
+        assert!(validate_on_call_shifts(&[overlapping_shift.clone()], &[shift.clone()]).is_err());
+
+        let other_user_shift = OnCallData {
+            user_id: Uuid::new_v4(),
+            ..overlapping_shift.clone()
+        };
+        assert!(validate_on_call_shifts(&[other_user_shift], &[shift.clone()]).is_ok());
+
+        let updated_shift = OnCallData {
+            start_time: start_time + Duration::hours(1),
+            ..shift.clone()
+        };
+        assert!(validate_on_call_shifts(&[updated_shift], &[shift.clone()]).is_ok());
+
+        let invalid_shift = OnCallData {
+            end_time: start_time,
+            ..shift
+        };
+        assert!(validate_on_call_shifts(&[invalid_shift], &[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_on_call_rotation_generation() {
+        let department_id = Uuid::new_v4();
+        let user_id_list = vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let start_date = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        let data = GenerateOnCallRotationRequest {
+            rotation_type: OnCallRotationType::Weekly,
+            start_date,
+            user_id_list: user_id_list.clone(),
+            period_count: 4,
+        };
+        assert!(validate_on_call_rotation_request(&data).is_ok());
+
+        let shifts = generate_on_call_rotation(&data, department_id, &HashMap::new()).unwrap();
+        assert!(shifts.len() == 4);
+        assert!(shifts[0].user_id == user_id_list[0]);
+        assert!(shifts[1].user_id == user_id_list[1]);
+        assert!(shifts[3].user_id == user_id_list[0]);
+        assert!(shifts[1].start_time == start_date + Duration::weeks(1));
+        assert!(shifts[1].end_time == start_date + Duration::weeks(2));
+
+        let mut leave_map = HashMap::new();
+        leave_map.insert(
+            user_id_list[1],
+            vec![NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()],
+        );
+        let shifts = generate_on_call_rotation(&data, department_id, &leave_map).unwrap();
+        assert!(shifts[1].user_id == user_id_list[2]);
+        assert!(shifts[2].user_id == user_id_list[2]);
+
+        let leave_map: HashMap<Uuid, Vec<NaiveDate>> = user_id_list
+            .iter()
+            .map(|id| (*id, vec![NaiveDate::from_ymd_opt(2025, 1, 7).unwrap()]))
+            .collect();
+        assert!(generate_on_call_rotation(&data, department_id, &leave_map).is_err());
+
+        let invalid_data = GenerateOnCallRotationRequest {
+            rotation_type: OnCallRotationType::Daily,
+            start_date,
+            user_id_list: vec![user_id_list[0], user_id_list[0]],
+            period_count: 2,
+        };
+        assert!(validate_on_call_rotation_request(&invalid_data).is_err());
+
+        let invalid_data = GenerateOnCallRotationRequest {
+            rotation_type: OnCallRotationType::Daily,
+            start_date,
+            user_id_list: user_id_list.clone(),
+            period_count: 0,
+        };
+        assert!(validate_on_call_rotation_request(&invalid_data).is_err());
+
+        let invalid_data = GenerateOnCallRotationRequest {
+            rotation_type: OnCallRotationType::Weekly,
+            start_date,
+            user_id_list: user_id_list.clone(),
+            period_count: MAX_ROTATION_PERIOD_COUNT + 1,
+        };
+        assert!(validate_on_call_rotation_request(&invalid_data).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_on_call_swap_validation() {
+        let requester_id = Uuid::new_v4();
+        let target_user_id = Uuid::new_v4();
+        let department_id = Uuid::new_v4();
+        let start_time = Utc::now() + Duration::days(1);
+        let shift = OnCallData {
+            id: Uuid::new_v4(),
+            user_id: requester_id,
+            department_id,
+            start_time,
+            end_time: start_time + Duration::hours(8),
+        };
+        let target_shift = OnCallData {
+            id: Uuid::new_v4(),
+            user_id: target_user_id,
+            department_id,
+            start_time: start_time + Duration::days(1),
+            end_time: start_time + Duration::days(1) + Duration::hours(8),
+        };
+        assert!(validate_on_call_swap_creation(&shift, &target_shift, requester_id).is_ok());
+        assert!(validate_on_call_swap_creation(&shift, &target_shift, target_user_id).is_err());
+        assert!(validate_on_call_swap_creation(&shift, &shift, requester_id).is_err());
+
+        let mut swap = OnCallSwapData {
+            id: Uuid::new_v4(),
+            on_call_id: shift.id,
+            target_on_call_id: target_shift.id,
+            department_id,
+            requested_by: requester_id,
+            target_user_id,
+            status: OnCallSwapStatus::Requested,
+            reason: None,
+            accepted_on: None,
+            approved_by: None,
+            approved_on: None,
+            rejected_by: None,
+            rejected_on: None,
+            created_on: Utc::now(),
+        };
+        let member_permission = AllowedPermission {
+            permission_list: vec![PermissionType::RequestOnCallSwap.to_string()],
+        };
+        let manager_permission = AllowedPermission {
+            permission_list: vec![PermissionType::ApproveOnCallSwap.to_string()],
+        };
+        let manager_id = Uuid::new_v4();
+
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Accepted,
+                &swap,
+                target_user_id,
+                &member_permission
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Accepted,
+                &swap,
+                requester_id,
+                &member_permission
+            )
+            .is_err()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Approved,
+                &swap,
+                manager_id,
+                &manager_permission
+            )
+            .is_err()
+        );
+
+        swap.status = OnCallSwapStatus::Accepted;
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Approved,
+                &swap,
+                manager_id,
+                &manager_permission
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Approved,
+                &swap,
+                target_user_id,
+                &member_permission
+            )
+            .is_err()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Approved,
+                &swap,
+                target_user_id,
+                &manager_permission
+            )
+            .is_err()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Approved,
+                &swap,
+                requester_id,
+                &manager_permission
+            )
+            .is_err()
+        );
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Cancelled,
+                &swap,
+                requester_id,
+                &member_permission
+            )
+            .is_ok()
+        );
+
+        swap.status = OnCallSwapStatus::Approved;
+        assert!(
+            validate_on_call_swap_status_update(
+                &OnCallSwapStatus::Cancelled,
+                &swap,
+                requester_id,
+                &member_permission
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_on_call_create_fetch_update_and_delete() {
+        let pool = get_test_pool().await;
+        let mobile_no = "3100000070";
+        let user_res = setup_user(
+            &pool,
+            "testuser70",
+            "testuser70@example.com",
+            mobile_no,
+            "testuser@123",
+        )
+        .await;
+        assert!(user_res.is_ok());
+        let user_id = user_res.unwrap();
+        let business_id = setup_business(&pool, mobile_no, "business@example.com")
+            .await
+            .unwrap();
+        let department_id = setup_department(&pool, mobile_no, business_id)
+            .await
+            .unwrap();
+        let start_time = Utc::now();
+        let shifts = vec![OnCallData {
+            id: Uuid::new_v4(),
+            user_id,
+            department_id,
+            start_time,
+            end_time: start_time + Duration::hours(8),
+        }];
+        let validation_res =
+            on_call_create_validation(&pool, &shifts, business_id, department_id).await;
+        assert!(validation_res.is_ok());
+
+        let mut transaction = pool.begin().await.unwrap();
+        let save_res = save_on_call(&mut transaction, &shifts, user_id).await;
+        assert!(save_res.is_ok());
+        assert!(transaction.commit().await.is_ok());
+
+        let validation_res =
+            on_call_create_validation(&pool, &shifts, business_id, department_id).await;
+        assert!(validation_res.is_err());
+
+        let current_res = get_current_on_call(&pool, department_id, Utc::now()).await;
+        assert!(current_res.is_ok());
+        let current = current_res.unwrap();
+        assert!(current.current.iter().any(|a| a.id == shifts[0].id));
+        assert!(current.next.is_none());
+
+        let outsider_shift = vec![OnCallData {
+            user_id: Uuid::new_v4(),
+            ..shifts[0].clone()
+        }];
+        let validation_res =
+            on_call_create_validation(&pool, &outsider_shift, business_id, department_id).await;
+        assert!(validation_res.is_err());
+
+        let id_list = vec![shifts[0].id];
+        let filter_query = FetchOnCallQuery::builder()
+            .with_department_id(Some(department_id))
+            .with_id_list(Some(&id_list));
+        let fetch_res = get_on_call(&pool, &filter_query).await;
+        assert!(fetch_res.is_ok());
+        assert!(fetch_res.unwrap().first().is_some());
+
+        let update_data = UpdateOnCallRequest {
+            id: shifts[0].id,
+            user_id,
+            start_time: start_time + Duration::hours(1),
+            end_time: start_time + Duration::hours(9),
+        };
+        let update_res = update_on_call(&pool, &update_data, user_id).await;
+        assert!(update_res.is_ok());
+        let fetch_res = get_on_call(&pool, &filter_query).await.unwrap();
+        assert!(
+            fetch_res.first().unwrap().start_time.timestamp() == update_data.start_time.timestamp()
+        );
+
+        let delete_res = delete_on_call(&pool, shifts[0].id, user_id).await;
+        assert!(delete_res.is_ok());
+        let fetch_res = get_on_call(&pool, &filter_query).await.unwrap();
+        assert!(fetch_res.is_empty());
+
+        let mobile_no_with_code = format!("{}{}", DUMMY_INTERNATIONAL_DIALING_CODE, mobile_no);
+
+        let (delete_dep_res, delete_business_res, delete_user_res) = join!(
+            hard_delete_department_account(&pool, department_id),
+            hard_delete_business_account(&pool, business_id),
+            hard_delete_user_account(&pool, &mobile_no_with_code),
+        );
+        assert!(delete_dep_res.is_ok());
+        assert!(delete_business_res.is_ok());
+        assert!(delete_user_res.is_ok());
+    }
+}
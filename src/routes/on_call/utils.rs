@@ -0,0 +1,997 @@
+use std::collections::{BTreeSet, HashMap};
+
+use actix::Addr;
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    errors::GenericError,
+    pulsar_client::{PulsarClient, PulsarTopic, SchedulerMessageData, SchedulerMessageType},
+    routes::{
+        user::{schemas::MinimalUserAccount, utils::get_minimal_user_list},
+        web_socket::{schemas::ProcessType, utils::send_notification},
+    },
+    schemas::{AlertStatus, AllowedPermission, PermissionType},
+    slack_client::{SlackBlockType, SlackClient, SlackNotificationPayload, SlackTextType},
+    websocket_client::{Server, WebSocketActionType},
+};
+
+use super::{
+    models::{OnCallAlertModel, OnCallModel, OnCallSwapModel},
+    schemas::{
+        BulkOnCallInsert, CreateOnCallSwapRequest, CurrentOnCallData, FetchOnCallQuery,
+        FetchOnCallSwapQuery, GenerateOnCallRotationRequest, OnCallData, OnCallSwapData,
+        OnCallSwapStatus, UpdateOnCallRequest,
+    },
+};
+
+/// Largest number of slots generated by a single rotation request.
+pub const MAX_ROTATION_PERIOD_COUNT: i32 = 366;
+
+fn is_on_call_overlapping(a: &OnCallData, b: &OnCallData) -> bool {
+    a.user_id == b.user_id && a.start_time < b.end_time && b.start_time < a.end_time
+}
+
+pub fn validate_on_call_shifts(
+    shifts: &[OnCallData],
+    existing_shifts: &[OnCallData],
+) -> Result<(), GenericError> {
+    for (index, shift) in shifts.iter().enumerate() {
+        if shift.start_time >= shift.end_time {
+            return Err(GenericError::ValidationError(format!(
+                "Start time {} should be before end time {}",
+                shift.start_time, shift.end_time
+            )));
+        }
+        if shifts[index + 1..]
+            .iter()
+            .any(|other| is_on_call_overlapping(shift, other))
+        {
+            return Err(GenericError::ValidationError(format!(
+                "Overlapping on-call shifts found for user {} starting at {}",
+                shift.user_id, shift.start_time
+            )));
+        }
+        if let Some(existing) = existing_shifts
+            .iter()
+            .find(|existing| existing.id != shift.id && is_on_call_overlapping(shift, existing))
+        {
+            return Err(GenericError::ValidationError(format!(
+                "User {} already has an on-call shift from {} to {}",
+                existing.user_id, existing.start_time, existing.end_time
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "prepare bulk on call data", skip(created_by))]
+pub fn prepare_bulk_on_call_data(data: &[OnCallData], created_by: Uuid) -> BulkOnCallInsert {
+    let current_utc = Utc::now();
+    let mut id_list = vec![];
+    let mut user_id_list = vec![];
+    let mut department_id_list = vec![];
+    let mut start_time_list = vec![];
+    let mut end_time_list = vec![];
+    let mut created_on_list = vec![];
+    let mut created_by_list = vec![];
+    for on_call in data.iter() {
+        id_list.push(on_call.id);
+        user_id_list.push(on_call.user_id);
+        department_id_list.push(on_call.department_id);
+        start_time_list.push(on_call.start_time);
+        end_time_list.push(on_call.end_time);
+        created_on_list.push(current_utc);
+        created_by_list.push(created_by);
+    }
+    BulkOnCallInsert {
+        id: id_list,
+        user_id: user_id_list,
+        department_id: department_id_list,
+        start_time: start_time_list,
+        end_time: end_time_list,
+        created_on: created_on_list,
+        created_by: created_by_list,
+    }
+}
+
+#[tracing::instrument(name = "save on call", skip(transaction, data))]
+pub async fn save_on_call(
+    transaction: &mut Transaction<'_, Postgres>,
+    data: &[OnCallData],
+    created_by: Uuid,
+) -> Result<bool, anyhow::Error> {
+    if data.is_empty() {
+        return Ok(false);
+    }
+    let data = prepare_bulk_on_call_data(data, created_by);
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO on_call (id, user_id, department_id, start_time, end_time, created_on, created_by)
+        SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::uuid[], $4::timestamptz[], $5::timestamptz[], $6::timestamptz[], $7::uuid[])
+        "#,
+        &data.id[..] as &[Uuid],
+        &data.user_id[..] as &[Uuid],
+        &data.department_id[..] as &[Uuid],
+        &data.start_time[..] as &[DateTime<Utc>],
+        &data.end_time[..] as &[DateTime<Utc>],
+        &data.created_on[..] as &[DateTime<Utc>],
+        &data.created_by[..] as &[Uuid],
+    );
+    let result = transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while saving on call")
+    })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+fn build_on_call_query<'a>(query: &FetchOnCallQuery<'a>) -> QueryBuilder<'a, Postgres> {
+    let mut query_builder = QueryBuilder::new(
+        r#"
+        SELECT id, user_id, department_id, start_time, end_time
+        FROM on_call
+        WHERE is_deleted = false"#,
+    );
+    if let Some(department_id) = query.department_id {
+        query_builder.push(" AND department_id = ");
+        query_builder.push_bind(department_id);
+    }
+    if let Some(id_list) = query.id_list
+        && !id_list.is_empty()
+    {
+        query_builder.push(" AND id = ANY(");
+        query_builder.push_bind(id_list);
+        query_builder.push(")");
+    }
+    if let Some(user_id_list) = query.user_id_list
+        && !user_id_list.is_empty()
+    {
+        query_builder.push(" AND user_id = ANY(");
+        query_builder.push_bind(user_id_list);
+        query_builder.push(")");
+    }
+    if let Some(start_time) = query.start_time {
+        query_builder.push(" AND end_time > ");
+        query_builder.push_bind(start_time);
+    }
+    if let Some(end_time) = query.end_time {
+        query_builder.push(" AND start_time < ");
+        query_builder.push_bind(end_time);
+    }
+    if let Some(active_at) = query.active_at {
+        query_builder.push(" AND start_time <= ");
+        query_builder.push_bind(active_at);
+        query_builder.push(" AND end_time > ");
+        query_builder.push_bind(active_at);
+    }
+    if let Some(start_after) = query.start_after {
+        query_builder.push(" AND start_time > ");
+        query_builder.push_bind(start_after);
+    }
+    query_builder.push(" ORDER BY start_time");
+    if let Some(limit) = query.limit {
+        query_builder.push(" LIMIT ");
+        query_builder.push_bind(limit);
+    }
+    if let Some(offset) = query.offset {
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(offset);
+    }
+    query_builder
+}
+
+#[tracing::instrument(name = "Fetch on call models", skip(pool))]
+async fn fetch_on_call_models<'a>(
+    pool: &PgPool,
+    query: &FetchOnCallQuery<'a>,
+) -> Result<Vec<OnCallModel>, anyhow::Error> {
+    let mut query_builder = build_on_call_query(query);
+    let query = query_builder.build_query_as::<OnCallModel>();
+    let rows = query.fetch_all(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching on call")
+    })?;
+
+    Ok(rows)
+}
+
+#[tracing::instrument(name = "Fetch on call with lock", skip(transaction))]
+async fn get_on_call_by_lock<'a>(
+    transaction: &mut Transaction<'_, Postgres>,
+    query: &FetchOnCallQuery<'a>,
+) -> Result<Vec<OnCallData>, anyhow::Error> {
+    let mut query_builder = build_on_call_query(query);
+    query_builder.push(" FOR UPDATE");
+    let query = query_builder.build_query_as::<OnCallModel>();
+    let rows = query.fetch_all(&mut **transaction).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching on call with lock")
+    })?;
+
+    Ok(rows.into_iter().map(|a| a.into_schema()).collect())
+}
+
+pub async fn get_on_call<'a>(
+    pool: &PgPool,
+    query: &FetchOnCallQuery<'a>,
+) -> Result<Vec<OnCallData>, anyhow::Error> {
+    let data_models = fetch_on_call_models(pool, query).await?;
+    let data = data_models.into_iter().map(|a| a.into_schema()).collect();
+    Ok(data)
+}
+
+#[tracing::instrument(name = "update on call", skip(pool))]
+pub async fn update_on_call(
+    pool: &PgPool,
+    data: &UpdateOnCallRequest,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE on_call
+        SET
+        user_id = $1,
+        start_time = $2,
+        end_time = $3,
+        updated_on = $4,
+        updated_by = $5,
+        start_alert_status = 'pending'::alert_status,
+        end_alert_status = 'pending'::alert_status
+        WHERE id = $6
+        "#,
+        data.user_id,
+        data.start_time,
+        data.end_time,
+        Utc::now(),
+        updated_by,
+        data.id
+    );
+
+    pool.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while updating on call")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "delete on call", skip(pool))]
+pub async fn delete_on_call(
+    pool: &PgPool,
+    id: Uuid,
+    deleted_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let _ = sqlx::query!(
+        r#"
+        UPDATE on_call
+        SET is_deleted = true,
+        deleted_on = $2,
+        deleted_by = $3
+        WHERE id = $1
+        "#,
+        id,
+        Utc::now(),
+        deleted_by
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while deleting on call")
+    })?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "fetch department user id list", skip(pool))]
+pub async fn fetch_department_user_id_list(
+    pool: &PgPool,
+    business_id: Uuid,
+    department_id: Uuid,
+    user_id_list: &[Uuid],
+) -> Result<Vec<Uuid>, anyhow::Error> {
+    let rows = sqlx::query_scalar!(
+        r#"
+        SELECT user_id
+        FROM business_user_department_relationship
+        WHERE business_id = $1
+          AND department_id = $2
+          AND user_id = ANY($3::uuid[])
+        "#,
+        business_id,
+        department_id,
+        user_id_list
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while fetching department users")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn on_call_create_validation(
+    pool: &PgPool,
+    shifts: &[OnCallData],
+    business_id: Uuid,
+    department_id: Uuid,
+) -> Result<(), GenericError> {
+    if shifts.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Incoming Data is empty".to_string(),
+        ));
+    }
+    let mut user_id_list: Vec<Uuid> = shifts.iter().map(|a| a.user_id).collect();
+    user_id_list.sort();
+    user_id_list.dedup();
+    let department_user_id_list =
+        fetch_department_user_id_list(pool, business_id, department_id, &user_id_list)
+            .await
+            .map_err(|e| {
+                GenericError::DatabaseError(
+                    "Something went wrong while fetching department users".to_string(),
+                    e,
+                )
+            })?;
+    let missing_ids: Vec<&Uuid> = user_id_list
+        .iter()
+        .filter(|id| !department_user_id_list.contains(id))
+        .collect();
+    if !missing_ids.is_empty() {
+        return Err(GenericError::ValidationError(format!(
+            "User/s not associated with the department: {:?}",
+            missing_ids
+        )));
+    }
+
+    let start_time = shifts.iter().map(|a| a.start_time).min();
+    let end_time = shifts.iter().map(|a| a.end_time).max();
+    let filter_query = FetchOnCallQuery::builder()
+        .with_user_id_list(Some(&user_id_list))
+        .with_start_time(start_time)
+        .with_end_time(end_time);
+    let existing_shifts = get_on_call(pool, &filter_query).await.map_err(|e| {
+        GenericError::DatabaseError("Something went wrong while fetching on call".to_string(), e)
+    })?;
+    validate_on_call_shifts(shifts, &existing_shifts)
+}
+
+pub fn validate_on_call_rotation_request(
+    data: &GenerateOnCallRotationRequest,
+) -> Result<(), GenericError> {
+    if data.user_id_list.is_empty() {
+        return Err(GenericError::ValidationError(
+            "Member list cannot be empty".to_string(),
+        ));
+    }
+    if data.period_count <= 0 {
+        return Err(GenericError::ValidationError(
+            "Period count should be greater than zero".to_string(),
+        ));
+    }
+    if data.period_count > MAX_ROTATION_PERIOD_COUNT {
+        return Err(GenericError::ValidationError(format!(
+            "Period count cannot be more than {}",
+            MAX_ROTATION_PERIOD_COUNT
+        )));
+    }
+    get_on_call_rotation_end_date(data)?;
+    let mut user_id_list = data.user_id_list.clone();
+    user_id_list.sort();
+    user_id_list.dedup();
+    if user_id_list.len() != data.user_id_list.len() {
+        return Err(GenericError::ValidationError(
+            "Member list contains duplicate users".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn is_user_on_leave(
+    leave_map: &HashMap<Uuid, Vec<NaiveDate>>,
+    user_id: Uuid,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+) -> bool {
+    let start_date = start_time.date_naive();
+    let end_date = (end_time - Duration::seconds(1)).date_naive();
+    leave_map.get(&user_id).is_some_and(|dates| {
+        dates
+            .iter()
+            .any(|date| *date >= start_date && *date <= end_date)
+    })
+}
+
+/// Returns the end of the last slot of a rotation.
+pub fn get_on_call_rotation_end_date(
+    data: &GenerateOnCallRotationRequest,
+) -> Result<DateTime<Utc>, GenericError> {
+    data.start_date
+        .checked_add_signed(data.rotation_type.duration() * data.period_count)
+        .ok_or_else(|| {
+            GenericError::ValidationError("Rotation end date is out of range".to_string())
+        })
+}
+
+/// Assigns slot `n` to the n-th member in the given order. A member on approved
+/// leave during their slot is skipped and the slot goes to the next available member.
+pub fn generate_on_call_rotation(
+    data: &GenerateOnCallRotationRequest,
+    department_id: Uuid,
+    leave_map: &HashMap<Uuid, Vec<NaiveDate>>,
+) -> Result<Vec<OnCallData>, GenericError> {
+    let duration = data.rotation_type.duration();
+    let member_count = data.user_id_list.len();
+    let mut shifts = vec![];
+    for period in 0..data.period_count as usize {
+        let start_time = data
+            .start_date
+            .checked_add_signed(duration * period as i32)
+            .ok_or_else(|| {
+                GenericError::ValidationError("Rotation slot is out of range".to_string())
+            })?;
+        let end_time = start_time + duration;
+        let user_id = (0..member_count)
+            .map(|offset| data.user_id_list[(period + offset) % member_count])
+            .find(|user_id| !is_user_on_leave(leave_map, *user_id, start_time, end_time))
+            .ok_or_else(|| {
+                GenericError::ValidationError(format!(
+                    "No member is available for the slot starting at {}",
+                    start_time
+                ))
+            })?;
+        shifts.push(OnCallData {
+            id: Uuid::new_v4(),
+            user_id,
+            department_id,
+            start_time,
+            end_time,
+        });
+    }
+    Ok(shifts)
+}
+
+pub async fn get_current_on_call(
+    pool: &PgPool,
+    department_id: Uuid,
+    time: DateTime<Utc>,
+) -> Result<CurrentOnCallData, anyhow::Error> {
+    let current_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_id))
+        .with_active_at(Some(time));
+    let next_query = FetchOnCallQuery::builder()
+        .with_department_id(Some(department_id))
+        .with_start_after(Some(time))
+        .with_limit(Some(1));
+    let (current_res, next_res) = tokio::join!(
+        get_on_call(pool, &current_query),
+        get_on_call(pool, &next_query)
+    );
+    Ok(CurrentOnCallData {
+        current: current_res?,
+        next: next_res?.into_iter().next(),
+    })
+}
+
+/// Publishes one handoff message per department and shift boundary. Boundaries in the
+/// past are skipped as the shift has already started or ended.
+#[tracing::instrument(name = "schedule on call handoff alert", skip(producer_client))]
+pub async fn schedule_on_call_handoff_alert(
+    producer_client: &PulsarClient,
+    shifts: &[OnCallData],
+) -> Result<(), anyhow::Error> {
+    let current_utc = Utc::now();
+    let boundary_set: BTreeSet<(Uuid, DateTime<Utc>)> = shifts
+        .iter()
+        .flat_map(|a| {
+            [
+                (a.department_id, a.start_time),
+                (a.department_id, a.end_time),
+            ]
+        })
+        .filter(|(_, date)| *date > current_utc)
+        .collect();
+    if boundary_set.is_empty() {
+        return Ok(());
+    }
+    let mut producer = producer_client
+        .get_producer(producer_client.get_product_topic(PulsarTopic::Scheduler))
+        .await;
+    for (department_id, date) in boundary_set {
+        let msg = SchedulerMessageData {
+            partition_key: None,
+            date,
+            message_type: SchedulerMessageType::OnCallHandoff,
+            department_id: Some(department_id),
+            leave_id_list: vec![],
+        };
+        let msg = producer
+            .create_message()
+            .with_content(msg)
+            .deliver_at(date.into())?;
+        msg.send_non_blocking().await?;
+    }
+    Ok(())
+}
+
+async fn get_on_call_for_alert_by_lock(
+    transaction: &mut Transaction<'_, Postgres>,
+    department_id: Uuid,
+    date: DateTime<Utc>,
+) -> Result<Vec<OnCallAlertModel>, anyhow::Error> {
+    let rows = sqlx::query_as!(
+        OnCallAlertModel,
+        r#"
+        SELECT
+            oc.id,
+            oc.user_id,
+            da.display_name AS department_name,
+            budr.business_id AS "business_id?",
+            oc.start_time = $2 AS "is_incoming!"
+        FROM
+            on_call AS oc
+        INNER JOIN
+            department_account AS da
+            ON oc.department_id = da.id
+        LEFT JOIN
+            business_user_department_relationship AS budr
+            ON budr.user_id = oc.user_id AND budr.department_id = oc.department_id
+        WHERE
+            oc.is_deleted = false
+            AND oc.department_id = $1
+            AND (
+                (oc.start_time = $2 AND oc.start_alert_status = $3)
+                OR (oc.end_time = $2 AND oc.end_alert_status = $3)
+            )
+        FOR UPDATE OF oc
+        "#,
+        department_id,
+        date,
+        &AlertStatus::Pending as &AlertStatus,
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e)
+            .context("A database failure occurred while fetching on call with lock")
+    })?;
+
+    Ok(rows)
+}
+
+#[tracing::instrument(name = "update on call alert status", skip(transaction))]
+async fn update_on_call_alert_status(
+    transaction: &mut Transaction<'_, Postgres>,
+    incoming_id_list: &Vec<Uuid>,
+    outgoing_id_list: &Vec<Uuid>,
+    alert_status: &AlertStatus,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE on_call
+        SET
+        start_alert_status = CASE WHEN id = ANY($2) THEN $1 ELSE start_alert_status END,
+        end_alert_status = CASE WHEN id = ANY($3) THEN $1 ELSE end_alert_status END
+        WHERE id = ANY($2) OR id = ANY($3)
+        "#,
+        alert_status as &AlertStatus,
+        incoming_id_list,
+        outgoing_id_list
+    );
+
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e)
+            .context("A database failure occurred while updating on call alert status")
+    })?;
+    Ok(())
+}
+
+fn get_display_name_list(
+    alert_list: &[&OnCallAlertModel],
+    user_map: &HashMap<Uuid, MinimalUserAccount>,
+) -> String {
+    alert_list
+        .iter()
+        .filter_map(|a| user_map.get(&a.user_id))
+        .map(|a| a.display_name.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+pub async fn send_on_call_handoff_notification(
+    pool: &PgPool,
+    slack_client: &SlackClient,
+    websocket_srv: &Addr<Server>,
+    producer_client: &PulsarClient,
+    department_id: Uuid,
+    date: DateTime<Utc>,
+) -> Result<(), anyhow::Error> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection from the pool")?;
+
+    let alert_data = get_on_call_for_alert_by_lock(&mut transaction, department_id, date).await?;
+    if !alert_data.is_empty() {
+        let (incoming_list, outgoing_list): (Vec<&OnCallAlertModel>, Vec<&OnCallAlertModel>) =
+            alert_data.iter().partition(|a| a.is_incoming);
+        let user_id_list: Vec<Uuid> = alert_data.iter().map(|a| a.user_id).collect();
+        let users = get_minimal_user_list(pool, None, 1000, 0, Some(&user_id_list)).await?;
+        let user_map: HashMap<Uuid, MinimalUserAccount> =
+            users.into_iter().map(|x| (x.id, x)).collect();
+        let department_name = &alert_data[0].department_name;
+        let business_id = alert_data.iter().find_map(|a| a.business_id);
+
+        let mut notification = SlackNotificationPayload::new("On Call Handoff".to_string())
+            .add_section(
+                format!(
+                    "📟 {} On Call Handoff at {}",
+                    department_name,
+                    date.format("%Y-%m-%d %H:%M UTC")
+                ),
+                SlackBlockType::Header,
+                SlackTextType::PlainText,
+            );
+        if !outgoing_list.is_empty() {
+            notification = notification.add_section(
+                format!(
+                    "*Outgoing*: {}",
+                    get_display_name_list(&outgoing_list, &user_map)
+                ),
+                SlackBlockType::Section,
+                SlackTextType::Mrkdwn,
+            );
+        }
+        if !incoming_list.is_empty() {
+            notification = notification.add_section(
+                format!(
+                    "*Incoming*: {}",
+                    get_display_name_list(&incoming_list, &user_map)
+                ),
+                SlackBlockType::Section,
+                SlackTextType::Mrkdwn,
+            );
+        }
+        let result = slack_client
+            .send_notification(notification.build(), &slack_client.channel.on_call)
+            .await;
+
+        if !incoming_list.is_empty() {
+            let _ = send_notification(
+                pool,
+                websocket_srv,
+                WebSocketActionType::OnCallHandoff,
+                ProcessType::Deferred,
+                incoming_list.iter().map(|a| a.user_id).collect(),
+                format!("Your on-call shift for {} has started", department_name),
+                business_id,
+                producer_client,
+            )
+            .await;
+        }
+        if !outgoing_list.is_empty() {
+            let _ = send_notification(
+                pool,
+                websocket_srv,
+                WebSocketActionType::OnCallHandoff,
+                ProcessType::Deferred,
+                outgoing_list.iter().map(|a| a.user_id).collect(),
+                format!("Your on-call shift for {} has ended", department_name),
+                business_id,
+                producer_client,
+            )
+            .await;
+        }
+
+        let status = if result.is_ok() {
+            AlertStatus::Success
+        } else {
+            AlertStatus::Failed
+        };
+        let incoming_id_list = incoming_list.iter().map(|a| a.id).collect();
+        let outgoing_id_list = outgoing_list.iter().map(|a| a.id).collect();
+        update_on_call_alert_status(
+            &mut transaction,
+            &incoming_id_list,
+            &outgoing_id_list,
+            &status,
+        )
+        .await?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update on call alert status.")?;
+    Ok(())
+}
+
+pub fn validate_on_call_swap_creation(
+    shift: &OnCallData,
+    target_shift: &OnCallData,
+    user_id: Uuid,
+) -> Result<(), GenericError> {
+    if shift.user_id != user_id {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You can only swap your own on-call shift".to_string(),
+        ));
+    }
+    if target_shift.user_id == user_id {
+        return Err(GenericError::ValidationError(
+            "Target on-call shift should belong to another user".to_string(),
+        ));
+    }
+    if shift.department_id != target_shift.department_id {
+        return Err(GenericError::ValidationError(
+            "On-call shifts should belong to the same department".to_string(),
+        ));
+    }
+    let current_utc = Utc::now();
+    if shift.start_time <= current_utc || target_shift.start_time <= current_utc {
+        return Err(GenericError::ValidationError(
+            "Only upcoming on-call shifts can be swapped".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_on_call_swap_status_update(
+    incoming_status: &OnCallSwapStatus,
+    swap: &OnCallSwapData,
+    user_id: Uuid,
+    permissions: &AllowedPermission,
+) -> Result<(), GenericError> {
+    let is_approver = permissions
+        .permission_list
+        .contains(&PermissionType::ApproveOnCallSwap.to_string());
+    let is_target_user = swap.target_user_id == user_id;
+    let is_requester = swap.requested_by == user_id;
+    let is_allowed = match (incoming_status, &swap.status) {
+        (OnCallSwapStatus::Accepted, OnCallSwapStatus::Requested) => is_target_user,
+        (OnCallSwapStatus::Rejected, OnCallSwapStatus::Requested) => is_target_user,
+        (OnCallSwapStatus::Approved, OnCallSwapStatus::Accepted) => {
+            is_approver && !is_requester && !is_target_user
+        }
+        (OnCallSwapStatus::Rejected, OnCallSwapStatus::Accepted) => is_approver,
+        (OnCallSwapStatus::Cancelled, OnCallSwapStatus::Requested | OnCallSwapStatus::Accepted) => {
+            is_requester
+        }
+        _ => {
+            return Err(GenericError::ValidationError(format!(
+                "Swap request cannot be moved from {:?} to {:?}",
+                swap.status, incoming_status
+            )));
+        }
+    };
+    if !is_allowed {
+        return Err(GenericError::InsufficientPrevilegeError(
+            "You don't have previlege to update this swap request".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[tracing::instrument(name = "save on call swap", skip(pool))]
+pub async fn save_on_call_swap(
+    pool: &PgPool,
+    data: &CreateOnCallSwapRequest,
+    department_id: Uuid,
+    target_user_id: Uuid,
+    created_by: Uuid,
+) -> Result<Uuid, anyhow::Error> {
+    let id = Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO on_call_swap_request (id, on_call_id, target_on_call_id, department_id, requested_by, target_user_id, status, reason, created_by, created_on)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+        id,
+        data.on_call_id,
+        data.target_on_call_id,
+        department_id,
+        created_by,
+        target_user_id,
+        &OnCallSwapStatus::Requested as &OnCallSwapStatus,
+        data.reason,
+        created_by,
+        Utc::now()
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while saving on call swap")
+    })?;
+    Ok(id)
+}
+
+#[tracing::instrument(name = "Fetch on call swap models", skip(pool))]
+async fn fetch_on_call_swap_models<'a>(
+    pool: &PgPool,
+    query: &FetchOnCallSwapQuery<'a>,
+) -> Result<Vec<OnCallSwapModel>, anyhow::Error> {
+    let mut query_builder = QueryBuilder::new(
+        r#"
+        SELECT id, on_call_id, target_on_call_id, department_id, requested_by, target_user_id,
+            status, reason, accepted_on, approved_by, approved_on, rejected_by, rejected_on, created_on
+        FROM on_call_swap_request
+        WHERE 1 = 1"#,
+    );
+    if let Some(department_id) = query.department_id {
+        query_builder.push(" AND department_id = ");
+        query_builder.push_bind(department_id);
+    }
+    if let Some(id) = query.id {
+        query_builder.push(" AND id = ");
+        query_builder.push_bind(id);
+    }
+    if let Some(user_id) = query.user_id {
+        query_builder.push(" AND (requested_by = ");
+        query_builder.push_bind(user_id);
+        query_builder.push(" OR target_user_id = ");
+        query_builder.push_bind(user_id);
+        query_builder.push(")");
+    }
+    if let Some(status) = query.status {
+        query_builder.push(" AND status = ");
+        query_builder.push_bind(status);
+    }
+    query_builder.push(" ORDER BY created_on DESC");
+    if let Some(limit) = query.limit {
+        query_builder.push(" LIMIT ");
+        query_builder.push_bind(limit);
+    }
+    if let Some(offset) = query.offset {
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(offset);
+    }
+
+    let query = query_builder.build_query_as::<OnCallSwapModel>();
+    let rows = query.fetch_all(pool).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow!(e).context("A database failure occurred while fetching on call swap")
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_on_call_swap<'a>(
+    pool: &PgPool,
+    query: &FetchOnCallSwapQuery<'a>,
+) -> Result<Vec<OnCallSwapData>, anyhow::Error> {
+    let data_models = fetch_on_call_swap_models(pool, query).await?;
+    let data = data_models.into_iter().map(|a| a.into_schema()).collect();
+    Ok(data)
+}
+
+#[tracing::instrument(name = "update on call swap status", skip(transaction))]
+pub async fn update_on_call_swap_status(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    status: &OnCallSwapStatus,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE on_call_swap_request
+        SET
+        status = $1,
+        updated_on = $2,
+        updated_by = $3,
+        accepted_on = CASE WHEN $1 = 'accepted'::on_call_swap_status THEN $2 ELSE accepted_on END,
+        approved_on = CASE WHEN $1 = 'approved'::on_call_swap_status THEN $2 ELSE approved_on END,
+        approved_by = CASE WHEN $1 = 'approved'::on_call_swap_status THEN $3 ELSE approved_by END,
+        rejected_on = CASE WHEN $1 = 'rejected'::on_call_swap_status THEN $2 ELSE rejected_on END,
+        rejected_by = CASE WHEN $1 = 'rejected'::on_call_swap_status THEN $3 ELSE rejected_by END
+        WHERE id = $4
+        "#,
+        status as &OnCallSwapStatus,
+        Utc::now(),
+        updated_by,
+        id
+    );
+
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e)
+            .context("A database failure occurred while updating on call swap status")
+    })?;
+    Ok(())
+}
+
+/// Hands the requester's shift to the target user and the target's shift to the requester.
+#[tracing::instrument(name = "swap on call user", skip(transaction))]
+pub async fn swap_on_call_user(
+    transaction: &mut Transaction<'_, Postgres>,
+    swap: &OnCallSwapData,
+    updated_by: Uuid,
+) -> Result<(), anyhow::Error> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE on_call
+        SET
+        user_id = CASE WHEN id = $1 THEN $3::uuid ELSE $4::uuid END,
+        updated_on = $5,
+        updated_by = $6
+        WHERE id = $1 OR id = $2
+        "#,
+        swap.on_call_id,
+        swap.target_on_call_id,
+        swap.target_user_id,
+        swap.requested_by,
+        Utc::now(),
+        updated_by
+    );
+
+    transaction.execute(query).await.map_err(|e| {
+        tracing::error!("Failed to execute query: {:?}", e);
+        anyhow::Error::new(e).context("A database failure occurred while swapping on call")
+    })?;
+    Ok(())
+}
+
+/// Makes sure both shifts are still owned by the users of the swap request and that
+/// neither user ends up with overlapping shifts once the swap is applied. The shifts are
+/// locked until the transaction ends.
+pub async fn validate_on_call_swap_approval(
+    transaction: &mut Transaction<'_, Postgres>,
+    swap: &OnCallSwapData,
+) -> Result<(), GenericError> {
+    let id_list = vec![swap.on_call_id, swap.target_on_call_id];
+    let filter_query = FetchOnCallQuery::builder().with_id_list(Some(&id_list));
+    let shifts = get_on_call_by_lock(transaction, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching on call".to_string(),
+                e,
+            )
+        })?;
+    let shift = shifts.iter().find(|a| a.id == swap.on_call_id);
+    let target_shift = shifts.iter().find(|a| a.id == swap.target_on_call_id);
+    let (shift, target_shift) = match (shift, target_shift) {
+        (Some(shift), Some(target_shift))
+            if shift.user_id == swap.requested_by
+                && target_shift.user_id == swap.target_user_id =>
+        {
+            (shift, target_shift)
+        }
+        _ => {
+            return Err(GenericError::ValidationError(
+                "On-call shifts have changed since the swap was requested".to_string(),
+            ));
+        }
+    };
+    let swapped_shifts = vec![
+        OnCallData {
+            user_id: swap.target_user_id,
+            ..shift.clone()
+        },
+        OnCallData {
+            user_id: swap.requested_by,
+            ..target_shift.clone()
+        },
+    ];
+    let user_id_list = vec![swap.requested_by, swap.target_user_id];
+    let filter_query = FetchOnCallQuery::builder()
+        .with_user_id_list(Some(&user_id_list))
+        .with_start_time(swapped_shifts.iter().map(|a| a.start_time).min())
+        .with_end_time(swapped_shifts.iter().map(|a| a.end_time).max());
+    let existing_shifts: Vec<OnCallData> = get_on_call_by_lock(transaction, &filter_query)
+        .await
+        .map_err(|e| {
+            GenericError::DatabaseError(
+                "Something went wrong while fetching on call".to_string(),
+                e,
+            )
+        })?
+        .into_iter()
+        .filter(|a| !id_list.contains(&a.id))
+        .collect();
+    validate_on_call_shifts(&swapped_shifts, &existing_shifts)
+}
@@ -171,6 +171,9 @@ pub enum SettingKey {
     TimeZone,
     EmailOTPTemplate,
     BusinessInviteRequestTemplate,
+    LeaveFallbackApprover,
+    LeaveReminderHours,
+    LeaveEscalationHours,
 }
 
 impl fmt::Display for SettingKey {
@@ -182,6 +185,9 @@ impl fmt::Display for SettingKey {
             SettingKey::TimeZone => "time_zone",
             SettingKey::EmailOTPTemplate => "email_otp_template",
             SettingKey::BusinessInviteRequestTemplate => "business_invite_request_template",
+            SettingKey::LeaveFallbackApprover => "leave_fallback_approver",
+            SettingKey::LeaveReminderHours => "leave_reminder_hours",
+            SettingKey::LeaveEscalationHours => "leave_escalation_hours",
         };
         write!(f, "{}", display_str)
     }
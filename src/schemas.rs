@@ -189,6 +189,24 @@ pub enum PermissionType {
     UpdateDepartment,
     #[serde(rename = "create:department-role")]
     CreateDepartmentRole,
+    #[serde(rename = "create:on-call")]
+    CreateOnCall,
+    #[serde(rename = "update:on-call")]
+    UpdateOnCall,
+    #[serde(rename = "delete:on-call")]
+    DeleteOnCall,
+    #[serde(rename = "list:on-call")]
+    ListOnCall,
+    #[serde(rename = "request:on-call-swap")]
+    RequestOnCallSwap,
+    #[serde(rename = "approve:on-call-swap")]
+    ApproveOnCallSwap,
+    #[serde(rename = "override:leave-approver")]
+    OverrideLeaveApprover,
+    #[serde(rename = "adjust:leave-balance")]
+    AdjustLeaveBalance,
+    #[serde(rename = "override:leave-blackout")]
+    OverrideLeaveBlackout,
 }
 
 impl fmt::Display for PermissionType {
@@ -228,6 +246,15 @@ impl fmt::Display for PermissionType {
             PermissionType::DeleteDepartment => "delete:department",
             PermissionType::UpdateDepartment => "update:department",
             PermissionType::CreateDepartmentRole => "create:department-role",
+            PermissionType::CreateOnCall => "create:on-call",
+            PermissionType::UpdateOnCall => "update:on-call",
+            PermissionType::DeleteOnCall => "delete:on-call",
+            PermissionType::ListOnCall => "list:on-call",
+            PermissionType::RequestOnCallSwap => "request:on-call-swap",
+            PermissionType::ApproveOnCallSwap => "approve:on-call-swap",
+            PermissionType::OverrideLeaveApprover => "override:leave-approver",
+            PermissionType::AdjustLeaveBalance => "adjust:leave-balance",
+            PermissionType::OverrideLeaveBlackout => "override:leave-blackout",
         };
 
         write!(f, "{}", display_str)
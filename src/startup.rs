@@ -61,7 +61,7 @@ async fn run(
     let email_client = web::Data::new(configuration.email.client());
     let email_config = web::Data::new(configuration.email);
     let slack_client = web::Data::new(configuration.slack.client());
-    let pulsar_client = configuration.pulsar.client().await?;
+    let pulsar_client = web::Data::new(configuration.pulsar.client().await?);
     let whatsapp_client = web::Data::new(configuration.whatsapp.client());
     pulsar_client
         .start_ws_consumer(
@@ -80,10 +80,11 @@ async fn run(
             db_pool.clone(),
             &pulsar_client.get_topic_name(PulsarTopic::Scheduler),
             slack_client.clone(),
+            ws_server.clone(),
+            pulsar_client.clone(),
         )
         .await;
 
-    let pulsar_client_data = web::Data::new(pulsar_client);
     let governor_config = GovernorConfigBuilder::default()
         .seconds_per_request(60)
         .burst_size(1000)
@@ -107,7 +108,7 @@ async fn run(
             .app_data(email_config.clone())
             .app_data(whatsapp_client.clone())
             .app_data(slack_client.clone())
-            .app_data(pulsar_client_data.clone())
+            .app_data(pulsar_client.clone())
             .configure(routes)
     })
     .workers(workers)
@@ -23,6 +23,11 @@ pub enum WebSocketActionType {
     LeaveRequest,
     LeaveRequestStatusUpdation,
     UpdateBusinessAccount,
+    OnCallHandoff,
+    OnCallSwapRequest,
+    OnCallSwapStatusUpdation,
+    LeaveBalanceAdjustment,
+    LeaveRequestComment,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]